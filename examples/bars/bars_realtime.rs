@@ -0,0 +1,55 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use futures_util::StreamExt;
+use korea_investment_rs::{
+    bars::{BarAggregator, BarEvent, BarInterval, SessionProfile},
+    domestic::quotations::Domestic,
+    provider::KISProvider,
+    types::{MarketDivision, MarketType},
+    websocket::{
+        RealtimeClient, SubscriptionOptions,
+        domestic::{DomesticRealtimeInfoType, models::StockTradeData},
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 생성 실패");
+
+    // 1) 당일 분봉으로 5분봉 초기화 (입력 시각 이전 30건)
+    let mut aggregator = BarAggregator::new(BarInterval::Minutes(5), SessionProfile::Krx);
+    let candles = provider
+        .get_today_minutes(MarketDivision::Krx, "005930", "153000")
+        .await
+        .expect("당일 분봉 조회 실패");
+    aggregator.seed_domestic(&candles.body);
+    println!("초기화된 봉: {}개", aggregator.bars().len());
+
+    // 2) 실시간 체결로 이어서 집계
+    let client = RealtimeClient::from_provider(&provider)
+        .await
+        .expect("웹소켓 클라이언트 생성 실패");
+    let subscription = client
+        .subscribe::<StockTradeData>(
+            "005930",
+            DomesticRealtimeInfoType::StockTradeKrX,
+            SubscriptionOptions::default(),
+        )
+        .await
+        .expect("실시간 구독 실패");
+    let mut stream = subscription.stream();
+
+    while let Some(Ok(trade)) = stream.next().await {
+        for event in aggregator.push_tick(&trade) {
+            match event {
+                BarEvent::Update(bar) => println!("[갱신] {} {:?}", bar.time, bar),
+                BarEvent::Close(bar) => println!("[마감] {} {:?}", bar.time, bar),
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    calendar::{Date, Market, MarketCalendar},
+    provider::KISProvider,
+    types::MarketType,
+};
+use std::time::SystemTime;
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    let mut calendar = MarketCalendar::new();
+    let now = SystemTime::now();
+    let (today, _) = calendar.local_time(Market::Krx, now);
+
+    // 국내 휴장일은 chk-holiday로 보완 (설/추석 등 음력 공휴일)
+    let loaded = calendar
+        .load_domestic_holidays(&provider, today, today.add_days(60))
+        .await
+        .expect("휴장일 조회 실패");
+    println!("국내 개장일 정보 {}일 반영", loaded);
+
+    for market in [Market::Krx, Market::Nxt, Market::Us] {
+        println!(
+            "{:?}: 현재 세션 = {:?}, 정규장 = {}",
+            market,
+            calendar
+                .session_at(market, now)
+                .map(|window| window.session),
+            calendar.is_open(market, now)
+        );
+        println!("  다음 개장 = {:?}", calendar.next_open(market, now));
+        println!("  다음 마감 = {:?}", calendar.next_close(market, now));
+    }
+
+    let (us_today, _) = calendar.local_time(Market::Us, now);
+    for window in calendar.sessions(Market::Us, calendar.next_business_day(Market::Us, us_today)) {
+        println!("{:?}", window);
+    }
+
+    let christmas = Date::new(today.year(), 12, 25).expect("날짜 오류");
+    println!(
+        "{} KRX 거래일 = {}, 5거래일 후 = {}",
+        christmas,
+        calendar.is_business_day(Market::Krx, christmas),
+        calendar.add_business_days(Market::Krx, christmas, 5)
+    );
+}
@@ -0,0 +1,50 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    domestic::quotations::DomesticExtendedQuotations,
+    master::{ListedMarket, SymbolMaster},
+    provider::KISProvider,
+    types::{MarketDivision, MarketType},
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    // ⚠ 모의투자 미지원 API
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    // 코스피 마스터파일에서 ETF/ETN/스팩/우선주를 뺀 앞쪽 100종목
+    let mut master = SymbolMaster::new();
+    master
+        .download(ListedMarket::Kospi)
+        .await
+        .expect("마스터파일 다운로드 실패");
+    let codes: Vec<&str> = master
+        .by_market(ListedMarket::Kospi)
+        .filter(|symbol| {
+            !symbol.is_etf && !symbol.is_etn && !symbol.is_spac && !symbol.is_preferred
+        })
+        .take(100)
+        .map(|symbol| symbol.code.as_str())
+        .collect();
+
+    // 30종목씩 4번 나눠 동시에 조회합니다
+    let quotes = provider
+        .batch_quotes(MarketDivision::Krx, &codes)
+        .await
+        .expect("관심종목 시세 조회 실패");
+
+    for code in &codes {
+        match &quotes[*code] {
+            Ok(row) => println!(
+                "{code} {:<16} 현재가 {:>10} ({}%)",
+                row.inter_kor_isnm, row.inter2_prpr, row.prdy_ctrt
+            ),
+            Err(error) => println!("{code} 오류: {error}"),
+        }
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use futures_util::StreamExt;
+use korea_investment_rs::{
+    domestic::analysis::{ConditionEvent, ConditionWatcher, DomesticConditionSearch},
+    provider::KISProvider,
+    types::MarketType,
+    websocket::{
+        OverflowPolicy, RawRealtimeData, RealtimeClient, RealtimeUniverse, SubscriptionOptions,
+        domestic::DomesticRealtimeInfoType,
+    },
+};
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let user_id = std::env::var("KIS_HTS_ID").expect("KIS_HTS_ID not set in environment");
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    let conditions = provider
+        .list_conditions(&user_id)
+        .await
+        .expect("조건검색 목록조회 실패");
+    for condition in &conditions.body {
+        println!(
+            "[{}] {} / {}",
+            condition.seq, condition.grp_nm, condition.condition_nm
+        );
+    }
+    let Some(condition) = conditions.body.first() else {
+        println!("HTS에 저장된 조건검색식이 없습니다");
+        return;
+    };
+
+    let client = RealtimeClient::from_provider(&provider)
+        .await
+        .expect("웹소켓 클라이언트 생성 실패");
+    // 조건 만족 종목이 모두 연결 하나를 공유하며, 버퍼가 차면 오래된 체결부터 버립니다
+    let (mut universe, mut stream) = RealtimeUniverse::<RawRealtimeData>::new(
+        DomesticRealtimeInfoType::StockTradeKrX,
+        SubscriptionOptions::new(1000, OverflowPolicy::DropOldest),
+    );
+    let mut watcher =
+        ConditionWatcher::new(&user_id, &condition.seq).with_interval(Duration::from_secs(60));
+
+    println!(
+        "'{}' 감시 시작, Ctrl+C를 누르면 종료됩니다.",
+        condition.condition_nm
+    );
+    loop {
+        tokio::select! {
+            events = watcher.next_events(&provider) => {
+                for event in events.expect("조건검색 실행 실패") {
+                    match event {
+                        ConditionEvent::Entered(row) => println!(
+                            "+ 편입 {} {} {}원 ({}%)",
+                            row.code, row.name, row.price, row.chgrate
+                        ),
+                        ConditionEvent::Exited(row) => println!("- 이탈 {} {}", row.code, row.name),
+                    }
+                }
+                // 등록 한도(41건)를 넘는 종목은 구독하지 않고 알려줍니다
+                let sync = watcher
+                    .sync_realtime(&client, &mut universe)
+                    .await
+                    .expect("실시간 구독 동기화 실패");
+                if !sync.skipped.is_empty() {
+                    println!("등록 한도 초과로 {}종목은 구독하지 않음", sync.skipped.len());
+                }
+                println!("구독 중: {}종목, 버린 데이터 {}건", universe.len(), stream.dropped());
+            }
+            Some(received) = stream.next() => match received {
+                Ok((symbol, data)) => println!("  {symbol} 체결: {}", data.payload),
+                Err(error) => println!("수신 오류: {}", error),
+            },
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    universe.clear().await.expect("구독 해제 실패");
+}
@@ -0,0 +1,130 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    domestic::analysis::{
+        DisparityRankRequest, DomesticRankings, FluctuationRankRequest, MarketCapRankRequest,
+        NearNewHighLowRankRequest, RankingExclusions, RankingFilter, RankingMarket,
+        ShortSalePeriod, ShortSaleRankRequest, VolumePowerRankRequest, VolumeRankRequest,
+        VolumeRankSort,
+    },
+    provider::KISProvider,
+    types::MarketType,
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    // 코스닥, 1,000~50,000원, 거래량 10만주 이상
+    let filter = RankingFilter {
+        market: RankingMarket::Kosdaq,
+        price_min: Some(1_000),
+        price_max: Some(50_000),
+        min_volume: Some(100_000),
+    };
+
+    let volume = provider
+        .get_volume_rank(&VolumeRankRequest {
+            filter,
+            sort: VolumeRankSort::VolumeGrowth,
+            exclusions: RankingExclusions::common_stocks(),
+            ..Default::default()
+        })
+        .await
+        .expect("거래량순위 조회 실패");
+    println!("[거래량 증가율]");
+    for row in volume.body.iter().take(10) {
+        println!(
+            "{:>3} {} {:<12} {:>8} {:>6}% 증가율 {}%",
+            row.quote.data_rank,
+            row.quote.mksc_shrn_iscd,
+            row.quote.hts_kor_isnm,
+            row.quote.stck_prpr,
+            row.quote.prdy_ctrt,
+            row.vol_inrt
+        );
+    }
+
+    let gainers = provider
+        .get_fluctuation_rank(&FluctuationRankRequest {
+            filter,
+            rate_min: Some(5.0),
+            ..Default::default()
+        })
+        .await
+        .expect("등락률순위 조회 실패");
+    println!("\n[+5% 이상 상승] {}종목", gainers.body.len());
+    for row in gainers.body.iter().take(10) {
+        println!(
+            "{} {:<12} {:>6}% 연속상승 {}일",
+            row.quote.mksc_shrn_iscd,
+            row.quote.hts_kor_isnm,
+            row.quote.prdy_ctrt,
+            row.cnnt_ascn_dynu
+        );
+    }
+
+    let power = provider
+        .get_volume_power_rank(&VolumePowerRankRequest {
+            filter,
+            ..Default::default()
+        })
+        .await
+        .expect("체결강도 상위 조회 실패");
+    println!("\n[체결강도 상위]");
+    for row in power.body.iter().take(5) {
+        println!("{:<12} 체결강도 {}", row.quote.hts_kor_isnm, row.tday_rltv);
+    }
+
+    let near_high = provider
+        .get_near_new_high_low_rank(&NearNewHighLowRankRequest {
+            filter,
+            gap_max: Some(3),
+            ..Default::default()
+        })
+        .await
+        .expect("신고가 근접 조회 실패");
+    println!("\n[신고가 3% 이내] {}종목", near_high.body.len());
+
+    let disparity = provider
+        .get_disparity_rank(&DisparityRankRequest {
+            filter,
+            lowest: true,
+            ..Default::default()
+        })
+        .await
+        .expect("이격도 순위 조회 실패");
+    println!("\n[20일 이격도 하위]");
+    for row in disparity.body.iter().take(5) {
+        println!("{:<12} 이격도 {}", row.quote.hts_kor_isnm, row.d20_dsrt);
+    }
+
+    let short = provider
+        .get_short_sale_rank(&ShortSaleRankRequest {
+            filter: RankingFilter::default(),
+            period: ShortSalePeriod::Week1,
+        })
+        .await
+        .expect("공매도 상위 조회 실패");
+    println!("\n[1주 공매도 비중 상위]");
+    for row in short.body.iter().take(5) {
+        println!(
+            "{:<12} 공매도 비중 {}%",
+            row.quote.hts_kor_isnm, row.ssts_vol_rlim
+        );
+    }
+
+    let caps = provider
+        .get_market_cap_rank(&MarketCapRankRequest::default())
+        .await
+        .expect("시가총액 상위 조회 실패");
+    println!("\n[시가총액 상위]");
+    for row in caps.body.iter().take(5) {
+        println!("{:<12} {}억원", row.quote.hts_kor_isnm, row.stck_avls);
+    }
+}
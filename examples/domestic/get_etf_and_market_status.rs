@@ -0,0 +1,81 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    domestic::{
+        analysis::RankingMarket,
+        quotations::market::{
+            DomesticMarketQuotations, ExpectedClosingSort, ViDirection, ViKind, ViMarket,
+        },
+    },
+    provider::KISProvider,
+    types::{MarketDivision, MarketType},
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    // ⚠ 모의투자 미지원 API 포함
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    // KODEX 200
+    let etf = provider
+        .get_etf_price(MarketDivision::Krx, "069500")
+        .await
+        .expect("ETF 현재가 조회 실패");
+    println!(
+        "KODEX 200 현재가 {} / NAV {} / 괴리율 {}",
+        etf.body.stck_prpr, etf.body.nav, etf.body.dprt
+    );
+
+    let components = provider
+        .get_etf_components(MarketDivision::Krx, "069500")
+        .await
+        .expect("구성종목 조회 실패");
+    for component in components.body.components.iter().take(5) {
+        println!(
+            "{} {} 비중 {}",
+            component.stck_shrn_iscd, component.hts_kor_isnm, component.etf_cnfg_issu_rlim
+        );
+    }
+
+    let kospi = provider
+        .get_index_daily_chart(MarketDivision::Index, "0001", "20240101", "20241231", "M")
+        .await
+        .expect("업종 기간별 시세 조회 실패");
+    println!("코스피 월봉 {}개", kospi.body.len());
+
+    let vi = provider
+        .get_vi_status("20241231", ViMarket::All, ViDirection::All, ViKind::All, "")
+        .await
+        .expect("VI 현황 조회 실패");
+    for row in vi.body.iter().take(5) {
+        println!(
+            "VI {} {} 발동 {} 해제 {}",
+            row.mksc_shrn_iscd, row.hts_kor_isnm, row.cntg_vi_hour, row.vi_cncl_hour
+        );
+    }
+
+    let closing = provider
+        .get_expected_closing_prices(
+            MarketDivision::Krx,
+            RankingMarket::All,
+            ExpectedClosingSort::All,
+            false,
+        )
+        .await
+        .expect("장마감 예상체결가 조회 실패");
+    println!("장마감 예상체결 {}건", closing.body.len());
+
+    let after_hours = provider
+        .get_after_hours_orderbook(MarketDivision::Krx, "005930")
+        .await
+        .expect("시간외 호가 조회 실패");
+    println!(
+        "시간외 최우선 매도 {:?} / 매수 {:?}",
+        after_hours.body.asks[0], after_hours.body.bids[0]
+    );
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    domestic::analysis::{DomesticFundamentals, FinancialPeriod},
+    provider::KISProvider,
+    types::MarketType,
+};
+
+fn show(value: Option<f64>) -> String {
+    value
+        .map(|v| format!("{v:.2}"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    let fundamentals = provider
+        .fundamentals("005930", FinancialPeriod::Annual)
+        .await
+        .expect("펀더멘털 조회 실패");
+
+    println!(
+        "{} 현재가 {} 시가총액 {}억원 PER {} PBR {} EPS {} BPS {}",
+        fundamentals.code,
+        show(fundamentals.price),
+        show(fundamentals.market_cap),
+        show(fundamentals.per),
+        show(fundamentals.pbr),
+        show(fundamentals.eps),
+        show(fundamentals.bps),
+    );
+
+    println!("결산년월 | 매출액 | 영업이익 | 영업이익률 | ROE | 부채비율 | 매출증가율");
+    for period in fundamentals.periods.iter().take(5) {
+        println!(
+            "{} | {} | {} | {} | {} | {} | {}",
+            period.stac_yymm,
+            show(period.revenue()),
+            show(period.operating_income()),
+            show(period.operating_margin()),
+            show(period.roe()),
+            show(period.debt_ratio()),
+            show(period.revenue_growth()),
+        );
+    }
+
+    // 간단한 스크리닝 조건 예시
+    if let Some(latest) = fundamentals.latest() {
+        let cheap = fundamentals.per.is_some_and(|per| per > 0.0 && per < 15.0);
+        let profitable = latest.roe().is_some_and(|roe| roe >= 10.0);
+        let sound = latest.debt_ratio().is_some_and(|ratio| ratio < 100.0);
+        println!(
+            "PER<15: {cheap}, ROE>=10%: {profitable}, 부채비율<100%: {sound} -> {}",
+            cheap && profitable && sound
+        );
+    }
+}
@@ -3,7 +3,7 @@ use dotenv::dotenv;
 use korea_investment_rs::{
     domestic::quotations::Domestic,
     provider::KISProvider,
-    types::MarketType,
+    types::{MarketDivision, MarketType},
 };
 
 #[tokio::main]
@@ -16,9 +16,9 @@ async fn main() {
         .expect("Provider 초기화 실패");
 
     let result = provider
-        .get_inquire_period_price("005930", "20240101", "20241231", "D")
+        .get_inquire_period_price(MarketDivision::Krx, "005930", "20240101", "20241231", "D")
         .await
         .expect("조회 실패");
 
-    println!("{:#?}", result.output2);
+    println!("{:#?}", result.body.1);
 }
@@ -3,7 +3,7 @@ use dotenv::dotenv;
 use korea_investment_rs::{
     domestic::quotations::DomesticExtendedQuotations,
     provider::KISProvider,
-    types::MarketType,
+    types::{MarketDivision, MarketType},
 };
 
 #[tokio::main]
@@ -16,9 +16,39 @@ async fn main() {
         .expect("Provider 초기화 실패");
 
     let response = provider
-        .get_orderbook("005930")
+        .get_orderbook(MarketDivision::Krx, "005930")
         .await
         .expect("호가 조회 실패");
 
-    println!("body = {:#?}", response.body);
+    let book = response.body;
+    println!("호가 시각 = {}", book.time);
+    for (ask, bid) in book.asks.iter().zip(book.bids.iter()) {
+        println!(
+            "매도 {:>10} ({:>8}) | 매수 {:>10} ({:>8})",
+            ask.price, ask.quantity, bid.price, bid.quantity
+        );
+    }
+    println!("총잔량 = {:?}", book.totals);
+    if let Some(expected) = book.expected {
+        println!("예상체결 = {:?}", expected);
+    }
+
+    let investors = provider
+        .get_investor_trend(MarketDivision::Krx, "005930")
+        .await
+        .expect("투자자 조회 실패");
+    if let Some(row) = investors.body.first() {
+        println!(
+            "{} 개인 {} / 외국인 {} / 기관 {}",
+            row.stck_bsop_date, row.prsn_ntby_qty, row.frgn_ntby_qty, row.orgn_ntby_qty
+        );
+    }
+
+    let members = provider
+        .get_member_trend(MarketDivision::Krx, "005930")
+        .await
+        .expect("회원사 조회 실패");
+    for row in &members.body.buys {
+        println!("매수 상위 {} {}", row.member_name, row.quantity);
+    }
 }
@@ -1,7 +1,9 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
 use korea_investment_rs::{
-    domestic::quotations::Domestic, provider::KISProvider, types::MarketType,
+    domestic::quotations::Domestic,
+    provider::KISProvider,
+    types::{MarketDivision, MarketType},
 };
 
 #[tokio::main]
@@ -15,13 +17,13 @@ async fn main() {
 
     // get_today_minutes는 당일 기준 시간 이전의 최근 분봉을 조회합니다.
     let result = provider
-        .get_today_minutes("005930", "093000")
+        .get_today_minutes(MarketDivision::Krx, "005930", "093000")
         .await
         .expect("조회 실패");
 
     println!("국내주식 당일 분봉 조회 결과:");
-    println!("{}건", result.len());
-    for item in result.iter().take(10) {
+    println!("{}건", result.body.len());
+    for item in result.body.iter().take(10) {
         println!("{:#?}", item);
     }
 }
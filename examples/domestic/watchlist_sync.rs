@@ -0,0 +1,80 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use futures_util::StreamExt;
+use korea_investment_rs::{
+    domestic::analysis::{DomesticWatchlist, WatchlistTracker},
+    provider::KISProvider,
+    types::MarketType,
+    websocket::{
+        OverflowPolicy, RawRealtimeData, RealtimeClient, RealtimeUniverse, SubscriptionOptions,
+        domestic::DomesticRealtimeInfoType,
+    },
+};
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let user_id = std::env::var("KIS_HTS_ID").expect("KIS_HTS_ID not set in environment");
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    let groups = provider
+        .list_watchlist_groups(&user_id)
+        .await
+        .expect("관심종목 그룹 조회 실패");
+    for group in &groups.body {
+        println!(
+            "[{}] {} ({}종목)",
+            group.inter_grp_code, group.inter_grp_name, group.ask_cnt
+        );
+    }
+    let Some(group) = groups.body.first() else {
+        println!("HTS 관심종목 그룹이 없습니다");
+        return;
+    };
+
+    let client = RealtimeClient::from_provider(&provider)
+        .await
+        .expect("웹소켓 클라이언트 생성 실패");
+    // 모든 종목이 연결 하나를 공유하며, 버퍼가 차면 오래된 체결부터 버립니다
+    let (mut universe, mut stream) = RealtimeUniverse::<RawRealtimeData>::new(
+        DomesticRealtimeInfoType::StockTradeKrX,
+        SubscriptionOptions::new(1000, OverflowPolicy::DropOldest),
+    );
+    let mut tracker = WatchlistTracker::new(&user_id, &group.inter_grp_code);
+
+    // HTS에서 그룹 종목을 편집하면 1분 안에 구독이 따라갑니다
+    let mut refresh = tokio::time::interval(Duration::from_secs(60));
+    println!(
+        "'{}' 동기화 시작, Ctrl+C를 누르면 종료됩니다.",
+        group.inter_grp_name
+    );
+    loop {
+        tokio::select! {
+            _ = refresh.tick() => {
+                let diff = tracker
+                    .sync_realtime(&provider, &client, &mut universe)
+                    .await
+                    .expect("관심종목 동기화 실패");
+                for member in &diff.added {
+                    println!("+ {} {}", member.jong_code, member.hts_kor_isnm);
+                }
+                for member in &diff.removed {
+                    println!("- {} {}", member.jong_code, member.hts_kor_isnm);
+                }
+                println!("구독 중: {}종목, 버린 데이터 {}건", universe.len(), stream.dropped());
+            }
+            Some(received) = stream.next() => match received {
+                Ok((symbol, data)) => println!("{symbol}: {}", data.payload),
+                Err(error) => println!("수신 오류: {}", error),
+            },
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    universe.clear().await.expect("구독 해제 실패");
+}
@@ -0,0 +1,71 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    history::{CandleHistory, HistoryInterval, Instrument, PriceAdjustment},
+    overseas::Exchange,
+    provider::KISProvider,
+    types::{MarketDivision, MarketType},
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    // 삼성전자 2020년~2024년 일봉 (100건 제한을 넘어 구간을 나눠 조회)
+    let daily = provider
+        .fetch_history(
+            &Instrument::domestic("005930"),
+            HistoryInterval::Day,
+            "20200101",
+            "20241231",
+        )
+        .await
+        .expect("일봉 조회 실패");
+    println!("국내 일봉 {}개", daily.len());
+    println!("처음 = {:?}", daily.first());
+    println!("마지막 = {:?}", daily.last());
+
+    // 원주가 기준 월봉
+    let monthly = provider
+        .fetch_history_with(
+            &Instrument::domestic("005930"),
+            HistoryInterval::Month,
+            "20150101",
+            "20241231",
+            PriceAdjustment::Unadjusted,
+        )
+        .await
+        .expect("월봉 조회 실패");
+    println!("국내 월봉(원주가) {}개", monthly.len());
+
+    // 넥스트레이드 1분봉 (휴장일은 건너뛰고 20:00부터 거슬러 조회)
+    let nxt_minutes = provider
+        .fetch_history(
+            &Instrument::domestic_market(MarketDivision::Nxt, "005930"),
+            HistoryInterval::Minute(1),
+            "20250929",
+            "20251010",
+        )
+        .await
+        .expect("NXT 분봉 조회 실패");
+    println!("NXT 1분봉 {}개", nxt_minutes.len());
+
+    // 애플 5분봉 (최근 영업일)
+    let minutes = provider
+        .fetch_history(
+            &Instrument::overseas(Exchange::Nasdaq, "AAPL"),
+            HistoryInterval::Minute(5),
+            "20241001",
+            "20241231",
+        )
+        .await
+        .expect("해외 분봉 조회 실패");
+    for candle in minutes.iter().rev().take(5) {
+        println!("{:?}", candle);
+    }
+}
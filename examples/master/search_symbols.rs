@@ -0,0 +1,44 @@
+use korea_investment_rs::{
+    master::{ListedMarket, SymbolMaster, download_overseas_master},
+    overseas::Exchange,
+};
+
+#[tokio::main]
+async fn main() {
+    // 코스피/코스닥/코넥스 마스터파일 다운로드 (로컬 파일은 `load_file` 사용)
+    let master = SymbolMaster::download_all()
+        .await
+        .expect("마스터파일 다운로드 실패");
+    println!("전체 종목 {}개", master.len());
+    for market in ListedMarket::ALL {
+        println!("{} {}개", market, master.by_market(market).count());
+    }
+
+    println!("005930 = {:?}", master.get("005930"));
+    println!(
+        "삼성전자 = {:?}",
+        master.find_by_name("삼성전자").map(|s| &s.code)
+    );
+
+    for symbol in master.search_prefix("KODEX 200").iter().take(5) {
+        println!(
+            "[prefix] {} {} ETF={}",
+            symbol.code, symbol.name, symbol.is_etf
+        );
+    }
+    for symbol in master.search("삼전", 5) {
+        println!("[search] {} {} {}", symbol.code, symbol.name, symbol.market);
+    }
+
+    let spacs = master.symbols().iter().filter(|s| s.is_spac).count();
+    println!("SPAC {}개", spacs);
+
+    // 해외 마스터파일 (나스닥)
+    let nasdaq = download_overseas_master(Exchange::Nasdaq)
+        .await
+        .expect("해외 마스터파일 다운로드 실패");
+    println!("나스닥 종목 {}개", nasdaq.len());
+    for symbol in nasdaq.iter().filter(|s| s.symbol == "AAPL") {
+        println!("{:?}", symbol);
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use futures_util::StreamExt;
+use korea_investment_rs::{
+    orderbook::LiveOrderBook,
+    provider::KISProvider,
+    types::{MarketDivision, MarketType},
+    websocket::{
+        RealtimeClient, SubscriptionOptions,
+        domestic::{DomesticRealtimeInfoType, models::StockQuoteData},
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Domestic, false)
+        .await
+        .expect("Provider 생성 실패");
+
+    // 1) REST 호가로 초기 호가창 구성
+    let mut book = LiveOrderBook::fetch_domestic(&provider, MarketDivision::Krx, "005930")
+        .await
+        .expect("호가 조회 실패");
+    println!(
+        "초기 호가: 매도 {:?} / 매수 {:?} / 스프레드 {:?}",
+        book.best_ask(),
+        book.best_bid(),
+        book.spread()
+    );
+
+    // 2) 실시간 호가로 갱신
+    let client = RealtimeClient::from_provider(&provider)
+        .await
+        .expect("웹소켓 클라이언트 생성 실패");
+    let subscription = client
+        .subscribe::<StockQuoteData>(
+            "005930",
+            DomesticRealtimeInfoType::StockQuoteKrX,
+            SubscriptionOptions::default(),
+        )
+        .await
+        .expect("실시간 구독 실패");
+    let mut stream = subscription.stream();
+
+    for _ in 0..10 {
+        let Some(Ok(quote)) = stream.next().await else {
+            break;
+        };
+        let event = book.apply(&quote);
+        if event.is_unchanged() {
+            continue;
+        }
+        println!(
+            "[{}] 매도 {:?} 매수 {:?} 스프레드 {:?} 5단계 불균형 {:?} 총잔량 증감 매도 {} / 매수 {}",
+            event.book.time,
+            event.book.best_ask(),
+            event.book.best_bid(),
+            event.book.spread(),
+            event.book.imbalance(5),
+            event.ask_change,
+            event.bid_change
+        );
+        for level in event.changed_asks.iter().chain(&event.changed_bids) {
+            println!(
+                "  {} 잔량 {} ({:+})",
+                level.price, level.quantity, level.change
+            );
+        }
+    }
+
+    subscription.stop().await.expect("구독 해제 실패");
+}
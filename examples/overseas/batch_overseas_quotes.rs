@@ -0,0 +1,49 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    overseas::{Exchange, OverseasQuotations},
+    provider::KISProvider,
+    types::MarketType,
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    // ⚠ 모의투자 미지원 API
+    let provider = KISProvider::new(MarketType::Overseas, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    // 10종목을 넘으면 10종목씩 나눠 동시에 조회합니다
+    let symbols = [
+        (Exchange::Nasdaq, "AAPL"),
+        (Exchange::Nasdaq, "MSFT"),
+        (Exchange::Nasdaq, "NVDA"),
+        (Exchange::Nasdaq, "AMZN"),
+        (Exchange::Nasdaq, "GOOGL"),
+        (Exchange::Nasdaq, "META"),
+        (Exchange::Nasdaq, "TSLA"),
+        (Exchange::Nasdaq, "TQQQ"),
+        (Exchange::Nyse, "BRK.B"),
+        (Exchange::Nyse, "JPM"),
+        (Exchange::Nyse, "V"),
+        (Exchange::Amex, "SPY"),
+        (Exchange::HongKong, "00700"),
+        (Exchange::Tokyo, "7203"),
+        (Exchange::Nasdaq, "NOSUCHSYMBOL"),
+    ];
+
+    let quotes = provider
+        .batch_overseas_quotes(&symbols)
+        .await
+        .expect("복수종목 시세 조회 실패");
+
+    for (exchange, symbol) in symbols {
+        match &quotes[&(exchange, symbol.to_string())] {
+            Ok(row) => println!("{symbol:>12} 현재가 {} ({}%)", row.last, row.rate),
+            Err(error) => println!("{symbol:>12} 오류: {error}"),
+        }
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    calendar::{Market, MarketCalendar},
+    money::{
+        Currency, FxRates, Money, OverseasPosition, shares_for_notional, total_market_value_krw,
+    },
+    overseas::{
+        Exchange, OverseasPresentBalanceRequest, OverseasPriceQuery, OverseasQuotations,
+        OverseasTrading,
+    },
+    provider::KISProvider,
+    types::{AccountInfo, MarketType},
+};
+use std::time::SystemTime;
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    // ⚠ 해외주식 현재가는 모의투자 미지원 API
+    let provider = KISProvider::new(MarketType::Overseas, false)
+        .await
+        .expect("Provider 초기화 실패");
+    let account = AccountInfo::from_env().expect("계좌 환경변수 로드 실패");
+
+    // 1) 체결기준현재잔고에서 보유 종목과 통화별 환율
+    let balance = provider
+        .inquire_overseas_present_balance(OverseasPresentBalanceRequest {
+            account: &account,
+            wcrc_frcr_dvsn_cd: "02",
+            natn_cd: "000",
+            tr_mket_cd: "00",
+            inqr_dvsn_cd: "00",
+        })
+        .await
+        .expect("해외 체결기준현재잔고 조회 실패");
+    let mut rates = FxRates::from_present_balance(&balance.body);
+
+    // 2) 달러 환율은 환율 기간별시세의 최근 값으로 갱신
+    let (today, _) = MarketCalendar::new().local_time(Market::Krx, SystemTime::now());
+    let from = today.add_days(-7).to_string();
+    let symbol = Currency::Usd
+        .fx_chart_symbol()
+        .expect("달러 환율 코드 없음");
+    let usd = rates
+        .load_fx_chart(&provider, Currency::Usd, symbol, &from, &today.to_string())
+        .await
+        .expect("환율 조회 실패");
+    println!("USD/KRW = {usd}");
+
+    // 3) 보유 종목 원화 평가
+    let positions = OverseasPosition::from_present_balance(&balance.body);
+    for position in &positions {
+        println!(
+            "{:<8} {:>6}주 평가 {} / 원화 {} / 손익 {}",
+            position.symbol,
+            position.quantity,
+            position.market_value().round(),
+            position
+                .market_value_krw(&rates)
+                .map(|money| money.round().to_string())
+                .unwrap_or_else(|| "환율 없음".to_string()),
+            position
+                .profit_krw(&rates)
+                .map(|money| money.round().to_string())
+                .unwrap_or_else(|| "환율 없음".to_string()),
+        );
+    }
+    match total_market_value_krw(&positions, &rates) {
+        Ok(total) => println!("원화 평가금액 합계 = {}", total.round()),
+        Err(error) => println!("원화 평가금액 합계 계산 실패: {error}"),
+    }
+
+    // 4) 100만원어치 AAPL 주문 수량
+    let price = provider
+        .get_overseas_price(OverseasPriceQuery {
+            auth: "",
+            exchange: Exchange::Nasdaq,
+            symbol: "AAPL",
+        })
+        .await
+        .expect("현재가 조회 실패");
+    let price = Money::parse(&price.body.last, Exchange::Nasdaq.currency());
+    let notional = Money::krw(1_000_000.0);
+    match shares_for_notional(notional, price, 1, &rates) {
+        Ok(quantity) => println!("{notional}으로 AAPL({price}) {quantity}주 매수 가능"),
+        Err(error) => println!("수량 계산 실패: {error}"),
+    }
+}
@@ -1,6 +1,10 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
-use korea_investment_rs::{overseas::OverseasTrading, provider::KISProvider, types::MarketType};
+use korea_investment_rs::{
+    overseas::{Exchange, OverseasQuotations},
+    provider::KISProvider,
+    types::MarketType,
+};
 
 #[tokio::main]
 async fn main() {
@@ -12,9 +16,13 @@ async fn main() {
         .expect("Provider 초기화 실패");
 
     let response = provider
-        .get_overseas_asking_price("NAS", "TQQQ")
+        .get_overseas_asking_price(Exchange::Nasdaq, "TQQQ")
         .await
         .expect("해외 호가 조회 실패");
 
-    println!("body = {:#?}", response.body);
+    let book = response.body;
+    println!("호가 시각 = {}", book.time);
+    println!("최우선 매도 = {:?}", book.asks[0]);
+    println!("최우선 매수 = {:?}", book.bids[0]);
+    println!("총잔량 = {:?}", book.totals);
 }
@@ -1,10 +1,9 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
 use korea_investment_rs::{
-    oauth::Oauth,
-    overseas::{OverseasDailyChartQuery, get_overseas_daily_chartprice},
-    types::CustType,
-    utils::ApiHeader,
+    overseas::{OverseasDailyChartQuery, OverseasQuotations},
+    provider::KISProvider,
+    types::MarketType,
 };
 
 #[tokio::main]
@@ -12,23 +11,17 @@ async fn main() {
     #[cfg(feature = "ex")]
     dotenv().ok();
 
-    // ⚡ 해외 시세 API는 실전 도메인 기준으로 조회합니다.
-    let practice = false;
-
-    // ✅ 토큰 발급 (캐싱 지원)
-    let token = Oauth::from_env_with_cache(CustType::P, practice)
+    // ⚡ true = 모의투자 / false = 실전계좌
+    let provider = KISProvider::new(MarketType::Overseas, false)
         .await
-        .expect("토큰 발급 실패");
-
-    // ✅ 개인용 기본 헤더 생성
-    let header = ApiHeader::personal();
+        .expect("Provider 초기화 실패");
 
     // ✅ 조회 파라미터 (필요에 맞게 변경)
     let div = "N"; // 시장 분류 코드 (N: 해외지수, X: 환율, I: 국채, S: 금선물)
     let itm_no = "NDX"; // 해외지수 코드 (예: NDX = 나스닥 100)
     let st_dt = "20240101"; // 시작일자(YYYYMMDD)
     let ed_dt = "20240131"; // 종료일자(YYYYMMDD)
-    let period = "D";       // 기간분류코드 (D/W/M/Y)
+    let period = "D"; // 기간분류코드 (D/W/M/Y)
 
     let query = OverseasDailyChartQuery {
         market_div_code: div,
@@ -38,9 +31,11 @@ async fn main() {
         period_div_code: period,
     };
 
-    let (output1, output2) = get_overseas_daily_chartprice(&token, &header, query)
+    let (output1, output2) = provider
+        .get_overseas_daily_chartprice(query)
         .await
-        .expect("조회 실패");
+        .expect("조회 실패")
+        .body;
 
     println!("📈 해외지수/환율 기간별 시세 조회 결과:");
     println!("output1: {:#?}", output1);
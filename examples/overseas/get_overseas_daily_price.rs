@@ -1,10 +1,9 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
 use korea_investment_rs::{
-    oauth::Oauth,
-    overseas::{OverseasDailyPriceQuery, get_overseas_daily_price},
-    types::CustType,
-    utils::ApiHeader,
+    overseas::{Exchange, OverseasDailyPriceQuery, OverseasQuotations},
+    provider::KISProvider,
+    types::MarketType,
 };
 
 #[tokio::main]
@@ -12,28 +11,26 @@ async fn main() {
     #[cfg(feature = "ex")]
     dotenv().ok();
 
-    // 해외 시세 API는 실전 도메인 기준으로 조회합니다.
-    let practice = false;
-
-    let token = Oauth::from_env_with_cache(CustType::P, practice)
+    // ⚡ true = 모의투자 / false = 실전계좌
+    let provider = KISProvider::new(MarketType::Overseas, false)
         .await
-        .expect("토큰 발급 실패");
-
-    let header = ApiHeader::personal();
+        .expect("Provider 초기화 실패");
 
     let query = OverseasDailyPriceQuery {
         auth: "",
-        exchg_code: "NAS",
+        exchange: Exchange::Nasdaq,
         symbol: "TQQQ",
-        gubn: "0",       // 0: 일, 1: 주, 2: 월
+        gubn: "0",        // 0: 일, 1: 주, 2: 월
         bymd: "20240131", // 조회 기준일자(YYYYMMDD)
-        modp: "0",       // 0: 수정주가 미반영, 1: 반영
+        modp: "0",        // 0: 수정주가 미반영, 1: 반영
         keyb: None,
     };
 
-    let (output1, output2) = get_overseas_daily_price(&token, &header, query)
+    let (output1, output2) = provider
+        .get_overseas_daily_price(query)
         .await
-        .expect("조회 실패");
+        .expect("조회 실패")
+        .body;
 
     println!("📈 해외주식 기간별 시세 조회 결과:");
     println!("output1: {:#?}", output1);
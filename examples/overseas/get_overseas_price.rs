@@ -1,10 +1,9 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
 use korea_investment_rs::{
-    oauth::Oauth,
-    overseas::{OverseasPriceQuery, get_overseas_price},
-    types::CustType,
-    utils::ApiHeader,
+    overseas::{Exchange, OverseasPriceQuery, OverseasQuotations},
+    provider::KISProvider,
+    types::MarketType,
 };
 
 #[tokio::main]
@@ -12,30 +11,21 @@ async fn main() {
     #[cfg(feature = "ex")]
     dotenv().ok();
 
-    // ⚡ true = 모의투자 / false = 실전계좌
-    // 해외 시세 API는 실전 도메인 기준으로 조회합니다.
-    let practice = false;
-
-    // ✅ 토큰 발급 (캐싱 지원)
-    let token = Oauth::from_env_with_cache(CustType::P, practice)
+    // ⚡ true = 모의투자 / false = 실전계좌 (현재가상세는 모의투자 미지원)
+    let provider = KISProvider::new(MarketType::Overseas, false)
         .await
-        .expect("토큰 발급 실패");
+        .expect("Provider 초기화 실패");
 
     // ✅ ProShares UltraPro QQQ (TQQQ), 나스닥(NAS)
     let query = OverseasPriceQuery {
-        auth: "P",         // 사용자 권한정보 (개인)
-        exchg_code: "NAS", // 거래소 코드 (NYSE, NAS, AMS 등)
-        symbol: "TQQQ",    // 종목코드(티커)
+        auth: "P",                  // 사용자 권한정보 (개인)
+        exchange: Exchange::Nasdaq, // 거래소 (시세 코드 NAS로 변환)
+        symbol: "TQQQ",             // 종목코드(티커)
     };
 
-    // ✅ 개인용 기본 헤더 생성
-    let header = ApiHeader::personal();
-
     // ✅ 해외 현재가 조회 실행
-    let result = get_overseas_price(&token, &header, query)
-        .await
-        .expect("조회 실패");
+    let result = provider.get_overseas_price(query).await.expect("조회 실패");
 
     println!("📈 해외주식 현재가 조회 결과:");
-    println!("{:#?}", result);
+    println!("{:#?}", result.body);
 }
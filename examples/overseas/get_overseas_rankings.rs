@@ -0,0 +1,92 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    overseas::{
+        Exchange, MarketCapRankRequest, NewHighLowRankRequest, OverseasRankings, RankDirection,
+        RankingPeriod, RankingWindow, TradeVolumeRankRequest, UpDownRateRankRequest, VolumeRange,
+        VolumeSurgeRankRequest,
+    },
+    provider::KISProvider,
+    types::MarketType,
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Overseas, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    let volume = provider
+        .get_overseas_trade_volume_rank(&TradeVolumeRankRequest {
+            exchange: Exchange::Nasdaq,
+            price_min: Some(5.0),
+            ..Default::default()
+        })
+        .await
+        .expect("거래량순위 조회 실패");
+    println!("[거래량순위] {}종목", volume.body.summary.crec);
+    for row in volume.body.rows.iter().take(10) {
+        let quote = &row.quote;
+        println!(
+            "{:>3} {:<6} {:>10} {:>7}% 거래량 {} (평균 {})",
+            quote.rank, quote.symb, quote.last, quote.rate, quote.tvol, row.a_tvol
+        );
+    }
+
+    let gainers = provider
+        .get_overseas_updown_rate_rank(&UpDownRateRankRequest {
+            exchange: Exchange::Nyse,
+            period: RankingPeriod::Days5,
+            direction: RankDirection::Up,
+            volume_range: VolumeRange::Over100K,
+        })
+        .await
+        .expect("상승률순위 조회 실패");
+    println!("\n[NYSE 5일 상승률]");
+    for row in gainers.body.rows.iter().take(10) {
+        println!(
+            "{:<6} {:>10} 기준가 {} 대비 {}%",
+            row.quote.symb, row.quote.last, row.n_base, row.n_rate
+        );
+    }
+
+    let surge = provider
+        .get_overseas_volume_surge_rank(&VolumeSurgeRankRequest {
+            window: RankingWindow::Minutes5,
+            ..Default::default()
+        })
+        .await
+        .expect("거래량급증 조회 실패");
+    println!("\n[나스닥 5분 거래량 급증]");
+    for row in surge.body.rows.iter().take(10) {
+        println!(
+            "{:<6} {} -> {} ({}%)",
+            row.quote.symb, row.n_tvol, row.quote.tvol, row.n_rate
+        );
+    }
+
+    let highs = provider
+        .get_overseas_new_high_low_rank(&NewHighLowRankRequest {
+            direction: RankDirection::Up,
+            sustained: true,
+            ..Default::default()
+        })
+        .await
+        .expect("신고가 조회 실패");
+    println!("\n[나스닥 신고가 돌파 유지] {}종목", highs.body.rows.len());
+
+    let caps = provider
+        .get_overseas_market_cap_rank(&MarketCapRankRequest::default())
+        .await
+        .expect("시가총액순위 조회 실패");
+    println!("\n[나스닥 시가총액]");
+    for row in caps.body.rows.iter().take(10) {
+        println!(
+            "{:<6} 시가총액 {} (비중 {}%)",
+            row.quote.symb, row.tomv, row.grav
+        );
+    }
+}
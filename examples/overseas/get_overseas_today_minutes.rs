@@ -1,10 +1,9 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
 use korea_investment_rs::{
-    oauth::Oauth,
-    overseas::{OverseasTodayMinuteQuery, get_overseas_today_minutes},
-    types::CustType,
-    utils::ApiHeader,
+    overseas::{Exchange, OverseasQuotations, OverseasTodayMinuteQuery},
+    provider::KISProvider,
+    types::MarketType,
 };
 
 #[tokio::main]
@@ -12,30 +11,28 @@ async fn main() {
     #[cfg(feature = "ex")]
     dotenv().ok();
 
-    // 해외 시세 API는 실전 도메인 기준으로 조회합니다.
-    let practice = false;
-
-    let token = Oauth::from_env_with_cache(CustType::P, practice)
+    // ⚠ 해외주식 분봉조회는 모의투자 미지원
+    let provider = KISProvider::new(MarketType::Overseas, false)
         .await
-        .expect("토큰 발급 실패");
-
-    let header = ApiHeader::personal();
+        .expect("Provider 초기화 실패");
 
     let query = OverseasTodayMinuteQuery {
         auth: "",
-        exchg_code: "NAS",
+        exchange: Exchange::Nasdaq,
         symbol: "TQQQ",
-        nmin: "5",           // 1, 2, 5 등
+        nmin: "5",             // 1, 2, 5 등
         include_prev_day: "1", // 0: 당일, 1: 전일 포함
-        next: "",            // 처음 조회 "", 다음 조회 "1"
-        record_count: "120", // 최대 120
+        next: "",              // 처음 조회 "", 다음 조회 "1"
+        record_count: "120",   // 최대 120
         fill: "",
         next_key: "",
     };
 
-    let (output1, output2) = get_overseas_today_minutes(&token, &header, query)
+    let (output1, output2) = provider
+        .get_overseas_today_minutes(query)
         .await
-        .expect("조회 실패");
+        .expect("조회 실패")
+        .body;
 
     println!("📈 해외주식 분봉 조회 결과:");
     println!("output1: {:#?}", output1);
@@ -1,7 +1,7 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
 use korea_investment_rs::{
-    overseas::{OverseasDaytimeOrderRequest, OverseasTrading},
+    overseas::{Exchange, OverseasDaytimeOrderRequest, OverseasTrading},
     provider::KISProvider,
     types::{AccountInfo, MarketType},
 };
@@ -20,7 +20,7 @@ async fn main() {
         .place_overseas_daytime_buy_order(OverseasDaytimeOrderRequest {
             cano: &account.cano,
             acnt_prdt_cd: &account.acnt_prdt_cd,
-            ovrs_excg_cd: "NASD",
+            exchange: Exchange::Nasdaq,
             pdno: "AAPL",
             ord_qty: "1",
             ovrs_ord_unpr: "0",
@@ -0,0 +1,60 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    calendar::MarketCalendar,
+    overseas::{
+        Exchange, OrderSide, OverseasOrderRequest, OverseasOrderRoute, OverseasOrderRouting,
+    },
+    provider::KISProvider,
+    types::{AccountInfo, MarketType},
+};
+use std::time::SystemTime;
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    // ⚠ 주간거래 주문은 실전 계좌 전용
+    let provider = KISProvider::new(MarketType::Overseas, false)
+        .await
+        .expect("Provider 초기화 실패");
+    let account = AccountInfo::from_env().expect("계좌 환경변수 로드 실패");
+    let calendar = MarketCalendar::new();
+
+    // 지금 주문하면 어느 경로로 나가는지 먼저 확인
+    match OverseasOrderRoute::at(&calendar, Exchange::Nasdaq, SystemTime::now()) {
+        Ok((route, session)) => println!("현재 경로 = {route} ({session:?})"),
+        Err(error) => {
+            println!("주문 불가: {error}");
+            return;
+        }
+    }
+
+    let order = provider
+        .place_overseas_order(
+            &calendar,
+            OrderSide::Buy,
+            OverseasOrderRequest {
+                cano: &account.cano,
+                acnt_prdt_cd: &account.acnt_prdt_cd,
+                exchange: Exchange::Nasdaq,
+                pdno: "AAPL",
+                ord_qty: "1",
+                ovrs_ord_unpr: "150.00",
+                ctac_tlno: "",
+                mgco_aptm_odno: "",
+                sll_type: "",
+                ord_svr_dvsn_cd: "0",
+                ord_dvsn: "00",
+                start_time: None,
+                end_time: None,
+                algo_ord_tmd_dvsn_cd: None,
+            },
+        )
+        .await
+        .expect("해외주식 주문 실패");
+
+    println!("경로 = {}", order.route);
+    println!("body = {:#?}", order.response.body);
+}
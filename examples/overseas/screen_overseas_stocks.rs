@@ -0,0 +1,43 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    overseas::{Exchange, OverseasRankings, OverseasScreenerRequest},
+    provider::KISProvider,
+    types::MarketType,
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Overseas, false)
+        .await
+        .expect("Provider 초기화 실패");
+
+    // 나스닥에서 20~200달러, 당일 +2% 이상, PER 5~25배, 거래량 100만주 이상
+    let request = OverseasScreenerRequest {
+        exchange: Exchange::Nasdaq,
+        price: Some(20.0..=200.0),
+        change_rate: Some(2.0..=30.0),
+        per: Some(5.0..=25.0),
+        volume: Some(1_000_000.0..=10_000_000_000.0),
+        ..Default::default()
+    };
+
+    let response = provider
+        .screen_overseas_stocks(&request)
+        .await
+        .expect("해외주식 조건검색 실패");
+
+    println!(
+        "조건 충족 {}종목 (전체 {})",
+        response.body.summary.crec, response.body.summary.trec
+    );
+    for row in &response.body.rows {
+        println!(
+            "{:<6} {:<30} {:>10} {:>7}% PER {:>7} EPS {:>7} 거래량 {}",
+            row.symb, row.name, row.last, row.rate, row.per, row.eps, row.tvol
+        );
+    }
+}
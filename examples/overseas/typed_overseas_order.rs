@@ -0,0 +1,108 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    calendar::MarketCalendar,
+    overseas::{
+        AlgoWindow, Exchange, OverseasOrder, OverseasOrderKind, OverseasOrderRoute,
+        OverseasOrderRouting, OverseasRevision, OverseasTrading,
+    },
+    provider::KISProvider,
+    types::{AccountInfo, MarketType},
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let provider = KISProvider::new(MarketType::Overseas, false)
+        .await
+        .expect("Provider 초기화 실패");
+    let account = AccountInfo::from_env().expect("계좌 환경변수 로드 실패");
+
+    // 1) 거래소별 주문 유형 검증 (주문 전송 없음)
+    let orders = [
+        OverseasOrder::sell(
+            Exchange::Nasdaq,
+            "AAPL",
+            1,
+            OverseasOrderKind::MarketOnClose,
+        ),
+        OverseasOrder::buy(Exchange::Nasdaq, "AAPL", 1, OverseasOrderKind::MarketOnOpen),
+        OverseasOrder::buy(
+            Exchange::Nasdaq,
+            "AAPL",
+            10,
+            OverseasOrderKind::Vwap {
+                price: 200.0,
+                window: AlgoWindow::Between {
+                    start: "100000".to_string(),
+                    end: "153000".to_string(),
+                },
+            },
+        ),
+        OverseasOrder::buy(
+            Exchange::Tokyo,
+            "7203",
+            100,
+            OverseasOrderKind::LimitOnClose { price: 2500.0 },
+        ),
+        OverseasOrder::sell(
+            Exchange::HongKong,
+            "00700",
+            30,
+            OverseasOrderKind::OddLotLimit { price: 380.0 },
+        ),
+    ];
+    for order in &orders {
+        match order.prepare(provider.practice) {
+            Ok(prepared) => println!(
+                "{} {:?} {:?} → ORD_DVSN {} / SLL_TYPE {:?}",
+                order.exchange,
+                order.side,
+                order.kind,
+                prepared.ord_dvsn,
+                prepared.request(&account).sll_type
+            ),
+            Err(error) => println!(
+                "{} {:?} {:?} → 거부: {error}",
+                order.exchange, order.side, order.kind
+            ),
+        }
+    }
+
+    // 2) 지정가 매수를 현재 세션 경로로 전송
+    let order = OverseasOrder::buy(
+        Exchange::Nasdaq,
+        "AAPL",
+        1,
+        OverseasOrderKind::Limit { price: 150.0 },
+    );
+    let routed = provider
+        .submit_overseas_order(&MarketCalendar::new(), &account, &order)
+        .await
+        .expect("해외주식 주문 실패");
+    println!("경로 = {}", routed.route);
+    println!("body = {:#?}", routed.response.body);
+
+    // 3) 같은 주문 취소 (주간거래 주문은 미국주간 정정/취소를 사용해야 하므로 건너뜀)
+    let order_no = routed.response.body.output("output").map(|output| {
+        output
+            .get("ODNO")
+            .and_then(|value| value.as_str())
+            .unwrap_or_default()
+            .to_string()
+    });
+    if let Some(order_no) =
+        order_no.filter(|no| !no.is_empty() && routed.route != OverseasOrderRoute::Daytime)
+    {
+        let cancel = OverseasRevision::cancel(Exchange::Nasdaq, "AAPL", &order_no, 1)
+            .prepare()
+            .expect("취소 요청 검증 실패");
+        let response = provider
+            .revise_or_cancel_overseas_order(cancel.request(&account))
+            .await
+            .expect("해외주식 취소 실패");
+        println!("취소 body = {:#?}", response.body);
+    }
+}
@@ -2,7 +2,10 @@
 use dotenv::dotenv;
 use korea_investment_rs::{
     types::CustType,
-    websocket::domestic::{DomesticRealtimeClient, DomesticRealtimeInfoType},
+    websocket::domestic::{
+        DomesticRealtimeClient, DomesticRealtimeInfoType,
+        models::{IndexFuturesQuoteData, IndexFuturesTradeData},
+    },
 };
 
 #[tokio::main]
@@ -14,23 +17,49 @@ async fn main() {
         .await
         .expect("웹소켓 클라이언트 생성 실패");
 
-    let (mut rx, controller) = client
-        .start_raw_channel("101S03", DomesticRealtimeInfoType::IndexFuturesTrade)
+    // ✅ 코스피200 선물 체결 / 호가
+    let (mut trade_rx, trade_controller) = client
+        .start_stream_channel::<IndexFuturesTradeData>(
+            "101S03",
+            DomesticRealtimeInfoType::IndexFuturesTrade,
+        )
         .await
-        .expect("선물 실시간 구독 실패");
+        .expect("선물 체결 구독 실패");
+    let (mut quote_rx, quote_controller) = client
+        .start_stream_channel::<IndexFuturesQuoteData>(
+            "101S03",
+            DomesticRealtimeInfoType::IndexFuturesQuote,
+        )
+        .await
+        .expect("선물 호가 구독 실패");
 
     println!("Ctrl+C를 누르면 종료됩니다.");
     loop {
         tokio::select! {
-            message = rx.recv() => {
-                if let Some(message) = message {
-                    println!("{}", message.payload);
-                } else {
-                    break;
-                }
+            Some(trade) = trade_rx.recv() => {
+                println!(
+                    "[체결] {} 현재가 {} 거래량 {} 미결제 {} 이론가 {} 베이시스 {}",
+                    trade.bsop_hour,
+                    trade.futs_prpr,
+                    trade.acml_vol,
+                    trade.hts_otst_stpl_qty,
+                    trade.hts_thpr,
+                    trade.mrkt_basis,
+                );
+            }
+            Some(quote) = quote_rx.recv() => {
+                println!(
+                    "[호가] {} 매도1 {}({}) 매수1 {}({})",
+                    quote.bsop_hour,
+                    quote.askp1,
+                    quote.askp_rsqn1,
+                    quote.bidp1,
+                    quote.bidp_rsqn1,
+                );
             }
             _ = tokio::signal::ctrl_c() => {
-                controller.stop().await.expect("웹소켓 중지 실패");
+                trade_controller.stop().await.expect("웹소켓 중지 실패");
+                quote_controller.stop().await.expect("웹소켓 중지 실패");
                 break;
             }
         }
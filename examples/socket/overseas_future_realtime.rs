@@ -1,9 +1,6 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
-use korea_investment_rs::{
-    types::CustType,
-    websocket::overseas::{OverseasRealtimeClient, types::OverseasRealtimeInfoType},
-};
+use korea_investment_rs::{types::CustType, websocket::overseas::OverseasRealtimeClient};
 
 #[tokio::main]
 async fn main() {
@@ -14,23 +11,42 @@ async fn main() {
         .await
         .expect("웹소켓 클라이언트 생성 실패");
 
-    let (mut rx, controller) = client
-        .start_raw_channel("ESM26", OverseasRealtimeInfoType::FutureOptionTrade)
+    // ✅ CME E-mini S&P500 체결 / 호가
+    let (mut trade_rx, trade_controller) = client
+        .start_future_option_trade_channel("ESM26")
         .await
-        .expect("해외선물옵션 실시간 구독 실패");
+        .expect("해외선물옵션 체결 구독 실패");
+    let (mut quote_rx, quote_controller) = client
+        .start_future_option_quote_channel("ESM26")
+        .await
+        .expect("해외선물옵션 호가 구독 실패");
 
     println!("Ctrl+C를 누르면 종료됩니다.");
     loop {
         tokio::select! {
-            message = rx.recv() => {
-                if let Some(message) = message {
-                    println!("{}", message.payload);
-                } else {
-                    break;
-                }
+            Some(trade) = trade_rx.recv() => {
+                println!(
+                    "[체결] {} {} 체결가 {} 체결량 {} 누적 {}",
+                    trade.series_cd,
+                    trade.recv_time,
+                    trade.last_price,
+                    trade.last_qntt,
+                    trade.vol,
+                );
+            }
+            Some(quote) = quote_rx.recv() => {
+                println!(
+                    "[호가] {} 매도1 {}({}) 매수1 {}({})",
+                    quote.series_cd,
+                    quote.ask_price_1,
+                    quote.ask_qntt_1,
+                    quote.bid_price_1,
+                    quote.bid_qntt_1,
+                );
             }
             _ = tokio::signal::ctrl_c() => {
-                controller.stop().await.expect("웹소켓 중지 실패");
+                trade_controller.stop().await.expect("웹소켓 중지 실패");
+                quote_controller.stop().await.expect("웹소켓 중지 실패");
                 break;
             }
         }
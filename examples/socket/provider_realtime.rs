@@ -0,0 +1,36 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use korea_investment_rs::{
+    provider::KISProvider,
+    types::MarketType,
+    websocket::{RealtimeClient, domestic::DomesticRealtimeInfoType},
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    // ⚡ true = 모의투자 (ws 31000 포트, 모의 승인키) / false = 실전계좌
+    let provider = KISProvider::new(MarketType::Domestic, true)
+        .await
+        .expect("Provider 생성 실패");
+
+    let client = RealtimeClient::from_provider(&provider)
+        .await
+        .expect("웹소켓 클라이언트 생성 실패");
+
+    let (mut rx, controller) = client
+        .start_raw_channel("005930", DomesticRealtimeInfoType::StockTradeKrX)
+        .await
+        .expect("실시간 구독 실패");
+
+    for _ in 0..5 {
+        match rx.recv().await {
+            Some(message) => println!("{}", message.payload),
+            None => break,
+        }
+    }
+
+    controller.stop().await.expect("구독 해제 실패");
+}
@@ -0,0 +1,51 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use futures_util::StreamExt;
+use korea_investment_rs::{
+    types::CustType,
+    websocket::{
+        RawRealtimeData, RealtimeClient, RealtimeRecorder, RealtimeReplay, ReplaySpeed,
+        SubscriptionOptions, domestic::DomesticRealtimeInfoType, read_recording,
+    },
+};
+use std::time::Duration;
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let path = "realtime_005930.rec";
+
+    // 1) 30초 동안 삼성전자 체결 기록
+    let recorder = RealtimeRecorder::open(path).expect("기록 파일 열기 실패");
+    let client = RealtimeClient::from_env(CustType::P)
+        .await
+        .expect("웹소켓 클라이언트 생성 실패")
+        .with_recorder(recorder.clone());
+    let controller = client
+        .start_raw("005930", DomesticRealtimeInfoType::StockTradeKrX, |_| {})
+        .await
+        .expect("실시간 구독 실패");
+    tokio::time::sleep(Duration::from_secs(30)).await;
+    controller.stop().await.expect("구독 해제 실패");
+    recorder.flush().expect("기록 파일 flush 실패");
+
+    let frames = read_recording(path).expect("기록 파일 읽기 실패");
+    println!("기록된 레코드: {}건", frames.len());
+
+    // 2) 같은 구독 API로 10배속 재생
+    let speed = ReplaySpeed::multiplier(10.0).expect("재생 배속 오류");
+    let mut replay = RealtimeReplay::new(path, speed);
+    let subscription = replay.subscribe::<RawRealtimeData>(
+        "005930",
+        DomesticRealtimeInfoType::StockTradeKrX,
+        SubscriptionOptions::default(),
+    );
+    let mut stream = subscription.stream();
+    replay.start().await.expect("재생 시작 실패");
+
+    while let Some(Ok(data)) = stream.next().await {
+        println!("[replay] {}", data.payload);
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use futures_util::StreamExt;
+use korea_investment_rs::{
+    types::CustType,
+    websocket::{
+        OverflowPolicy, RawRealtimeData, RealtimeClient, SubscriptionOptions,
+        domestic::DomesticRealtimeInfoType,
+    },
+};
+
+#[tokio::main]
+async fn main() {
+    #[cfg(feature = "ex")]
+    dotenv().ok();
+
+    let client = RealtimeClient::from_env(CustType::P)
+        .await
+        .expect("웹소켓 클라이언트 생성 실패");
+
+    // 하나의 구독을 두 task가 나눠 받음
+    let subscription = client
+        .subscribe::<RawRealtimeData>(
+            "005930",
+            DomesticRealtimeInfoType::StockTradeKrX,
+            SubscriptionOptions::new(100, OverflowPolicy::Block),
+        )
+        .await
+        .expect("실시간 구독 실패");
+
+    let mut logger = subscription.stream();
+    let mut sampler =
+        subscription.stream_with(SubscriptionOptions::new(10, OverflowPolicy::DropOldest));
+
+    let logger_task = tokio::spawn(async move {
+        while let Some(message) = logger.next().await {
+            match message {
+                Ok(data) => println!("[logger] {}", data.payload),
+                Err(error) => {
+                    println!("[logger] {}", error);
+                    break;
+                }
+            }
+        }
+    });
+
+    let sampler_task = tokio::spawn(async move {
+        for _ in 0..5 {
+            tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+            match sampler.next().await {
+                Some(Ok(data)) => println!(
+                    "[sampler] {} (버려진 데이터: {})",
+                    data.payload,
+                    sampler.dropped()
+                ),
+                _ => break,
+            }
+        }
+    });
+
+    let _ = sampler_task.await;
+    subscription.stop().await.expect("구독 해제 실패");
+    let _ = logger_task.await;
+}
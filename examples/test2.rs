@@ -1,10 +1,9 @@
 #[cfg(feature = "ex")]
 use dotenv::dotenv;
 use korea_investment_rs::{
-    oauth::Oauth,
-    overseas::{get_overseas_index_minutes, OverseasIndexMinuteQuery},
-    types::CustType,
-    utils::ApiHeader,
+    overseas::{OverseasIndexMinuteQuery, OverseasQuotations},
+    provider::KISProvider,
+    types::MarketType,
 };
 
 #[tokio::main]
@@ -13,13 +12,9 @@ async fn main() {
     dotenv().ok();
 
     // ⚠ 모의투자 미지원 API
-    let practice = false;
-
-    let token = Oauth::from_env_with_cache(CustType::P, practice)
+    let provider = KISProvider::new(MarketType::Overseas, false)
         .await
-        .expect("토큰 발급 실패");
-
-    let header = ApiHeader::personal();
+        .expect("Provider 초기화 실패");
 
     let query = OverseasIndexMinuteQuery {
         market_div_code: "N", // 해외지수
@@ -28,11 +23,12 @@ async fn main() {
         include_past: "Y",    // 과거 데이터 포함
     };
 
-    let result = get_overseas_index_minutes(&token, &header, query)
+    let result = provider
+        .get_overseas_index_minutes(query)
         .await
         .expect("조회 실패");
 
     println!("📈 해외지수 분봉 조회 결과:");
-    println!("output1: {:#?}", result.0);
-    println!("output2: {:#?}", result.1);
+    println!("output1: {:#?}", result.body.0);
+    println!("output2: {:#?}", result.body.1);
 }
@@ -64,6 +64,8 @@ async fn main() {
     if response.rt_cd == "0" && !response.msg1.contains("조회할 자료가 없습니다") {
         println!("📈 해외주식 기간별 시세 조회 결과 (output1):");
         println!("{:#?}", response.output1);
+        println!("📈 해외주식 기간별 시세 조회 결과 (output2):");
+        println!("{:#?}", response.output2);
     } else {
         println!("{} , {}", response.msg_cd, response.msg1);
     }
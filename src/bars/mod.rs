@@ -0,0 +1,551 @@
+use crate::calendar::{Market, Session};
+use crate::domestic::quotations::TodayMinuteCandle;
+use crate::overseas::OverseasTodayMinuteOutput2;
+use crate::websocket::domestic::models::StockTradeData;
+use crate::websocket::overseas::models::OverseasDelayedTransactionPriceData;
+use serde::{Deserialize, Serialize};
+
+// ======================================================
+// 실시간 체결 → 봉(OHLCV) 집계
+// ======================================================
+//
+// 시간봉은 세션 시작 시각을 기준으로 구간을 나누고, 세션이 바뀌면 진행 중인 봉을 닫습니다.
+// 시간은 모두 거래소 현지 시각(국내 KST, 미국 ET) 기준입니다.
+
+/// 봉 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BarInterval {
+    /// N초봉
+    Seconds(u32),
+    /// N분봉
+    Minutes(u32),
+    /// N틱봉
+    Ticks(u32),
+    /// 거래량 N주마다 닫히는 봉
+    Volume(u64),
+}
+
+impl BarInterval {
+    fn seconds(&self) -> Option<u32> {
+        match self {
+            Self::Seconds(n) => Some((*n).max(1)),
+            Self::Minutes(n) => Some((*n).max(1) * 60),
+            Self::Ticks(_) | Self::Volume(_) => None,
+        }
+    }
+}
+
+/// 장 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarketSession {
+    PreMarket,
+    Regular,
+    AfterMarket,
+}
+
+/// 시장별 세션 시간표 (현지 시각)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionProfile {
+    /// KRX: 장전 시간외 08:30~09:00, 정규장 09:00~15:30, 장후 시간외/시간외 단일가 15:40~18:00
+    Krx,
+    /// NXT: 프리마켓 08:00~08:50, 메인마켓 09:00:30~15:20, 애프터마켓 15:30~20:00
+    Nxt,
+    /// KRX+NXT 통합: 08:00~09:00, 09:00~15:30, 15:30~20:00
+    Unified,
+    /// 미국(ET): 프리마켓 04:00~09:30, 정규장 09:30~16:00, 애프터마켓 16:00~20:00
+    Us,
+    /// 세션 구분 없이 하루 전체를 정규장으로 취급
+    Always,
+}
+
+impl SessionProfile {
+    /// 세션 시간표 `(장 구분, 시작 초, 끝 초)`
+    ///
+    /// 달력(`calendar`)의 세션 시간표를 장 구분별로 묶어 만들며, 통합 시세는 KRX/NXT를 합칩니다.
+    fn sessions(&self) -> Vec<(MarketSession, u32, u32)> {
+        let markets: &[Market] = match self {
+            Self::Krx => &[Market::Krx],
+            Self::Nxt => &[Market::Nxt],
+            Self::Unified => &[Market::Krx, Market::Nxt],
+            Self::Us => &[Market::Us],
+            Self::Always => return vec![(MarketSession::Regular, 0, hms(24, 0, 0))],
+        };
+        let mut sessions: Vec<(MarketSession, u32, u32)> = Vec::new();
+        // 미국 주간거래는 전일 20:00부터라 현지 하루 단위 봉 집계에서 제외
+        let windows = markets
+            .iter()
+            .flat_map(|market| market.table())
+            .filter(|(session, _, _)| *session != Session::Daytime);
+        for (session, start, end) in windows {
+            let (session, start, end) = (session.market_session(), *start as u32, *end as u32);
+            match sessions.iter_mut().find(|(known, _, _)| *known == session) {
+                Some(entry) => {
+                    entry.1 = entry.1.min(start);
+                    entry.2 = entry.2.max(end);
+                }
+                None => sessions.push((session, start, end)),
+            }
+        }
+        sessions.sort_by_key(|(_, start, _)| *start);
+        sessions
+    }
+
+    /// 시각(HHMMSS)이 속한 세션
+    pub fn session_at(&self, time: &str) -> Option<MarketSession> {
+        locate(&self.sessions(), parse_hms(time)?).map(|(session, _)| session)
+    }
+}
+
+/// 시각(초)이 속한 세션과 세션 시작 시각(초)
+///
+/// 세션 구간은 `[시작, 끝)`이라 경계 시각은 다음 세션(09:00:00 시가 등)에 속합니다.
+/// 정규장과 마지막 세션은 끝 시각도 포함해 종가 단일가(15:30:00 등) 체결이 빠지지 않게 합니다.
+fn locate(sessions: &[(MarketSession, u32, u32)], seconds: u32) -> Option<(MarketSession, u32)> {
+    sessions
+        .iter()
+        .enumerate()
+        .find(|(index, (session, start, end))| {
+            let closing = *session == MarketSession::Regular || *index == sessions.len() - 1;
+            *start <= seconds && (seconds < *end || (closing && seconds == *end))
+        })
+        .map(|(_, (session, start, _))| (*session, *start))
+}
+
+/// 봉 집계 입력용 체결 1건
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Trade {
+    /// 체결일자 (YYYYMMDD)
+    pub date: String,
+    /// 체결시각 (HHMMSS)
+    pub time: String,
+    pub price: f64,
+    pub volume: u64,
+}
+
+/// 체결 데이터로 변환 가능한 실시간 모델
+pub trait TradeTick {
+    fn to_trade(&self) -> Option<Trade>;
+}
+
+impl TradeTick for StockTradeData {
+    fn to_trade(&self) -> Option<Trade> {
+        Some(Trade {
+            date: self.bsop_date.clone(),
+            time: self.stck_cntg_hour.clone(),
+            price: self.stck_prpr.trim().parse().ok()?,
+            volume: self.cntg_vol.trim().parse().ok()?,
+        })
+    }
+}
+
+impl TradeTick for OverseasDelayedTransactionPriceData {
+    fn to_trade(&self) -> Option<Trade> {
+        Some(Trade {
+            date: self.xymd.clone(),
+            time: self.xhms.clone(),
+            price: self.last.trim().parse().ok()?,
+            volume: self.evol.trim().parse().ok()?,
+        })
+    }
+}
+
+/// OHLCV 봉
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Bar {
+    /// 일자 (YYYYMMDD)
+    pub date: String,
+    /// 봉 시작 시각 (HHMMSS, 틱/거래량봉은 첫 체결 시각)
+    pub time: String,
+    /// 마지막 체결 시각 (HHMMSS)
+    pub last_time: String,
+    pub session: MarketSession,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: u64,
+    /// 체결 건수 (분봉으로 초기화한 봉은 분봉 개수)
+    pub trades: u64,
+}
+
+/// 봉 갱신/마감 이벤트
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BarEvent {
+    /// 진행 중인 봉 갱신
+    Update(Bar),
+    /// 봉 마감
+    Close(Bar),
+}
+
+/// 실시간 체결을 봉으로 묶는 집계기
+#[derive(Debug, Clone)]
+pub struct BarAggregator {
+    interval: BarInterval,
+    /// 세션 시간표 (`SessionProfile`에서 한 번만 만듦)
+    sessions: Vec<(MarketSession, u32, u32)>,
+    extended: bool,
+    bars: Vec<Bar>,
+    current: Option<Bar>,
+    /// 진행 중인 시간봉의 구간 끝 (초)
+    current_end: u32,
+}
+
+impl BarAggregator {
+    pub fn new(interval: BarInterval, profile: SessionProfile) -> Self {
+        Self {
+            interval,
+            sessions: profile.sessions(),
+            extended: false,
+            bars: Vec::new(),
+            current: None,
+            current_end: 0,
+        }
+    }
+
+    /// 장전/장후 체결도 봉에 포함 (기본값: 정규장만)
+    pub fn with_extended_hours(mut self, extended: bool) -> Self {
+        self.extended = extended;
+        self
+    }
+
+    /// 마감된 봉 목록 (오래된 순)
+    pub fn bars(&self) -> &[Bar] {
+        &self.bars
+    }
+
+    /// 진행 중인 봉
+    pub fn current(&self) -> Option<&Bar> {
+        self.current.as_ref()
+    }
+
+    /// 국내 당일 분봉(get_today_minutes)으로 초기화
+    ///
+    /// 분 단위 이상의 시간봉에서만 반영하며, 초/틱/거래량봉에서는 무시합니다.
+    pub fn seed_domestic(&mut self, candles: &[TodayMinuteCandle]) {
+        let trades = candles.iter().filter_map(|candle| {
+            Some(SeedCandle {
+                date: candle.stck_bsop_date.clone(),
+                time: candle.stck_cntg_hour.clone(),
+                open: candle.stck_oprc.trim().parse().ok()?,
+                high: candle.stck_hgpr.trim().parse().ok()?,
+                low: candle.stck_lwpr.trim().parse().ok()?,
+                close: candle.stck_prpr.trim().parse().ok()?,
+                volume: candle.acml_vol.trim().parse().unwrap_or(0),
+            })
+        });
+        self.seed(trades.collect());
+    }
+
+    /// 해외 당일 분봉(get_overseas_today_minutes)으로 초기화
+    ///
+    /// 분 단위 이상의 시간봉에서만 반영하며, 초/틱/거래량봉에서는 무시합니다.
+    pub fn seed_overseas(&mut self, candles: &[OverseasTodayMinuteOutput2]) {
+        let trades = candles.iter().filter_map(|candle| {
+            Some(SeedCandle {
+                date: candle.xymd.clone(),
+                time: candle.xhms.clone(),
+                open: candle.open.trim().parse().ok()?,
+                high: candle.high.trim().parse().ok()?,
+                low: candle.low.trim().parse().ok()?,
+                close: candle.last.trim().parse().ok()?,
+                volume: candle.evol.trim().parse().unwrap_or(0),
+            })
+        });
+        self.seed(trades.collect());
+    }
+
+    fn seed(&mut self, mut candles: Vec<SeedCandle>) {
+        if !matches!(self.interval, BarInterval::Minutes(_)) {
+            return;
+        }
+        // 분봉 응답은 최신순이므로 시간순으로 정렬
+        candles.sort_by(|a, b| (&a.date, &a.time).cmp(&(&b.date, &b.time)));
+        for candle in candles {
+            let trade = Trade {
+                date: candle.date.clone(),
+                time: candle.time.clone(),
+                price: candle.open,
+                volume: 0,
+            };
+            // 봉 경계 처리는 체결과 같게 하고, 값은 분봉 OHLCV로 덮어씁니다
+            if self.push(trade).is_empty() {
+                continue;
+            }
+            if let Some(bar) = self.current.as_mut() {
+                bar.high = bar.high.max(candle.high);
+                bar.low = bar.low.min(candle.low);
+                bar.close = candle.close;
+                bar.volume += candle.volume;
+            }
+        }
+    }
+
+    /// 실시간 모델 반영
+    pub fn push_tick<T: TradeTick>(&mut self, tick: &T) -> Vec<BarEvent> {
+        match tick.to_trade() {
+            Some(trade) => self.push(trade),
+            None => Vec::new(),
+        }
+    }
+
+    /// 체결 1건 반영 후 발생한 이벤트 반환 (마감 → 갱신 순)
+    pub fn push(&mut self, trade: Trade) -> Vec<BarEvent> {
+        let mut events = Vec::new();
+        let Some(seconds) = parse_hms(&trade.time) else {
+            return events;
+        };
+        let Some((session, session_start)) = locate(&self.sessions, seconds) else {
+            return events;
+        };
+        if session != MarketSession::Regular && !self.extended {
+            return events;
+        }
+
+        let boundary = match self.current.as_ref() {
+            Some(bar) => {
+                bar.date != trade.date
+                    || bar.session != session
+                    || (self.interval.seconds().is_some() && seconds >= self.current_end)
+            }
+            None => false,
+        };
+        if boundary && let Some(event) = self.close_current() {
+            events.push(event);
+        }
+
+        match self.current.as_mut() {
+            Some(bar) => {
+                bar.high = bar.high.max(trade.price);
+                bar.low = bar.low.min(trade.price);
+                bar.close = trade.price;
+                bar.volume += trade.volume;
+                bar.trades += 1;
+                bar.last_time = trade.time.clone();
+            }
+            None => {
+                let start = match self.interval.seconds() {
+                    Some(length) => {
+                        let start = session_start + (seconds - session_start) / length * length;
+                        self.current_end = start + length;
+                        start
+                    }
+                    None => seconds,
+                };
+                self.current = Some(Bar {
+                    date: trade.date.clone(),
+                    time: format_hms(start),
+                    last_time: trade.time.clone(),
+                    session,
+                    open: trade.price,
+                    high: trade.price,
+                    low: trade.price,
+                    close: trade.price,
+                    volume: trade.volume,
+                    trades: 1,
+                });
+            }
+        }
+
+        let complete = match (self.interval, self.current.as_ref()) {
+            (BarInterval::Ticks(n), Some(bar)) => bar.trades >= u64::from(n.max(1)),
+            (BarInterval::Volume(n), Some(bar)) => bar.volume >= n.max(1),
+            _ => false,
+        };
+        if complete {
+            events.extend(self.close_current());
+        } else if let Some(bar) = self.current.as_ref() {
+            events.push(BarEvent::Update(bar.clone()));
+        }
+        events
+    }
+
+    /// 체결이 없어도 시각이 지나면 시간봉 마감 (일자 YYYYMMDD, 시각 HHMMSS)
+    pub fn advance_to(&mut self, date: &str, time: &str) -> Option<BarEvent> {
+        let seconds = parse_hms(time)?;
+        let bar = self.current.as_ref()?;
+        let expired = bar.date.as_str() < date
+            || (self.interval.seconds().is_some() && seconds >= self.current_end)
+            || locate(&self.sessions, seconds).map(|(session, _)| session) != Some(bar.session);
+        if expired { self.close_current() } else { None }
+    }
+
+    /// 진행 중인 봉 강제 마감
+    pub fn flush(&mut self) -> Option<BarEvent> {
+        self.close_current()
+    }
+
+    fn close_current(&mut self) -> Option<BarEvent> {
+        let bar = self.current.take()?;
+        self.bars.push(bar.clone());
+        Some(BarEvent::Close(bar))
+    }
+}
+
+struct SeedCandle {
+    date: String,
+    time: String,
+    open: f64,
+    high: f64,
+    low: f64,
+    close: f64,
+    volume: u64,
+}
+
+const fn hms(hour: u32, minute: u32, second: u32) -> u32 {
+    hour * 3600 + minute * 60 + second
+}
+
+fn parse_hms(time: &str) -> Option<u32> {
+    let time = time.trim();
+    if time.len() < 6 || !time.is_char_boundary(6) {
+        return None;
+    }
+    let hour: u32 = time[0..2].parse().ok()?;
+    let minute: u32 = time[2..4].parse().ok()?;
+    let second: u32 = time[4..6].parse().ok()?;
+    Some(hms(hour, minute, second))
+}
+
+fn format_hms(seconds: u32) -> String {
+    format!(
+        "{:02}{:02}{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(time: &str, price: f64) -> Trade {
+        Trade {
+            date: "20251020".to_string(),
+            time: time.to_string(),
+            price,
+            volume: 100,
+        }
+    }
+
+    fn minute_bars(profile: SessionProfile, extended: bool, ticks: &[(&str, f64)]) -> Vec<Bar> {
+        let mut aggregator =
+            BarAggregator::new(BarInterval::Minutes(1), profile).with_extended_hours(extended);
+        for (time, price) in ticks {
+            aggregator.push(trade(time, *price));
+        }
+        aggregator.flush();
+        aggregator.bars().to_vec()
+    }
+
+    fn summary(bars: &[Bar]) -> Vec<(&str, MarketSession, f64, f64)> {
+        bars.iter()
+            .map(|bar| (bar.time.as_str(), bar.session, bar.open, bar.close))
+            .collect()
+    }
+
+    #[test]
+    fn krx_open_and_close_prints_are_regular() {
+        let ticks = [
+            ("085959", 99.0),
+            ("090000", 100.0),
+            ("152959", 104.0),
+            ("153000", 105.0),
+        ];
+        for profile in [SessionProfile::Krx, SessionProfile::Unified] {
+            assert_eq!(profile.session_at("085959"), Some(MarketSession::PreMarket));
+            assert_eq!(profile.session_at("090000"), Some(MarketSession::Regular));
+            assert_eq!(profile.session_at("153000"), Some(MarketSession::Regular));
+
+            let bars = minute_bars(profile, false, &ticks);
+            assert_eq!(
+                summary(&bars),
+                [
+                    ("090000", MarketSession::Regular, 100.0, 100.0),
+                    ("152900", MarketSession::Regular, 104.0, 104.0),
+                    ("153000", MarketSession::Regular, 105.0, 105.0),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn session_tables_follow_the_calendar() {
+        use MarketSession::*;
+        assert_eq!(
+            SessionProfile::Krx.sessions(),
+            [
+                (PreMarket, hms(8, 30, 0), hms(9, 0, 0)),
+                (Regular, hms(9, 0, 0), hms(15, 30, 0)),
+                (AfterMarket, hms(15, 40, 0), hms(18, 0, 0)),
+            ]
+        );
+        assert_eq!(
+            SessionProfile::Nxt.sessions(),
+            [
+                (PreMarket, hms(8, 0, 0), hms(8, 50, 0)),
+                (Regular, hms(9, 0, 30), hms(15, 20, 0)),
+                (AfterMarket, hms(15, 30, 0), hms(20, 0, 0)),
+            ]
+        );
+        assert_eq!(
+            SessionProfile::Unified.sessions(),
+            [
+                (PreMarket, hms(8, 0, 0), hms(9, 0, 0)),
+                (Regular, hms(9, 0, 0), hms(15, 30, 0)),
+                (AfterMarket, hms(15, 30, 0), hms(20, 0, 0)),
+            ]
+        );
+        assert_eq!(
+            SessionProfile::Us.sessions(),
+            [
+                (PreMarket, hms(4, 0, 0), hms(9, 30, 0)),
+                (Regular, hms(9, 30, 0), hms(16, 0, 0)),
+                (AfterMarket, hms(16, 0, 0), hms(20, 0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn extended_hours_keep_pre_market_separate() {
+        let bars = minute_bars(
+            SessionProfile::Krx,
+            true,
+            &[("085959", 99.0), ("090000", 100.0), ("090010", 101.0)],
+        );
+        assert_eq!(
+            summary(&bars),
+            [
+                ("085900", MarketSession::PreMarket, 99.0, 99.0),
+                ("090000", MarketSession::Regular, 100.0, 101.0),
+            ]
+        );
+    }
+
+    #[test]
+    fn us_open_print_is_regular() {
+        assert_eq!(
+            SessionProfile::Us.session_at("092959"),
+            Some(MarketSession::PreMarket)
+        );
+        assert_eq!(
+            SessionProfile::Us.session_at("160000"),
+            Some(MarketSession::Regular)
+        );
+        assert_eq!(
+            SessionProfile::Us.session_at("200000"),
+            Some(MarketSession::AfterMarket)
+        );
+        assert_eq!(SessionProfile::Us.session_at("200001"), None);
+
+        let bars = minute_bars(
+            SessionProfile::Us,
+            false,
+            &[("092959", 250.0), ("093000", 251.0), ("093059", 252.0)],
+        );
+        assert_eq!(
+            summary(&bars),
+            [("093000", MarketSession::Regular, 251.0, 252.0)]
+        );
+    }
+}
@@ -41,10 +41,7 @@ impl DomesticBondApi for KISProvider {
         request: BondBalanceRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
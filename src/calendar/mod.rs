@@ -0,0 +1,858 @@
+use crate::bars::MarketSession;
+use crate::domestic::quotations::market::DomesticMarketQuotations;
+use crate::provider::KISProvider;
+use crate::utils::request_interval;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+// ======================================================
+// 시장 달력 / 세션 시계
+// ======================================================
+//
+// 휴장일은 내장 규칙(주말, 양력 고정 공휴일, 미국 NYSE 휴장 규칙)에
+// 국내휴장일조회(chk-holiday) 결과나 직접 등록한 날짜를 덮어써서 판단합니다.
+// 세션 시간표는 거래소 현지 시각 기준이며, 시각 입력/출력은 모두 `SystemTime`(UTC)입니다.
+
+/// 달력 대상 시장
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Market {
+    /// 한국거래소 (KST)
+    Krx,
+    /// 넥스트레이드 (KST)
+    Nxt,
+    /// 미국 (ET, 서머타임 적용)
+    Us,
+    /// 홍콩 (HKT)
+    HongKong,
+    /// 일본 (JST)
+    Japan,
+    /// 중국 상해/심천 (CST)
+    China,
+}
+
+impl Market {
+    /// `instant`(UNIX 초) 시점의 UTC 대비 시차(초)
+    fn utc_offset_at(&self, instant: i64) -> i64 {
+        match self {
+            Self::Krx | Self::Nxt | Self::Japan => 9 * HOUR,
+            Self::HongKong | Self::China => 8 * HOUR,
+            Self::Us if is_us_dst(instant) => -4 * HOUR,
+            Self::Us => -5 * HOUR,
+        }
+    }
+
+    /// 현지 시각(날짜 자정 기준 초)을 UNIX 초로 변환
+    fn instant_at(&self, date: Date, seconds: i64) -> i64 {
+        let local = date.days() * DAY + seconds;
+        let standard = match self {
+            Self::Us => -5 * HOUR,
+            other => other.utc_offset_at(0),
+        };
+        local - self.utc_offset_at(local - standard)
+    }
+
+    /// 세션 시간표 `(세션, 시작 초, 끝 초)` (현지 자정 기준, 봉 집계 시간표도 여기서 만듦)
+    pub(crate) fn table(&self) -> &'static [(Session, i64, i64)] {
+        match self {
+            Self::Krx => KRX_SESSIONS,
+            Self::Nxt => NXT_SESSIONS,
+            Self::Us => US_SESSIONS,
+            Self::HongKong => HK_SESSIONS,
+            Self::Japan => JP_SESSIONS,
+            Self::China => CN_SESSIONS,
+        }
+    }
+}
+
+/// 거래 세션 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Session {
+    /// 장전 동시호가 / 개장 전 단일가 (KRX 08:30~09:00, 홍콩 09:00~09:30, 중국 09:15~09:25)
+    PreOpenAuction,
+    /// 프리마켓 (NXT 08:00~08:50, 미국 04:00~09:30)
+    PreMarket,
+    /// 정규장 (점심 휴장이 있는 시장은 오전/오후로 나뉨)
+    Regular,
+    /// 장마감 동시호가 (KRX 15:20~15:30, 홍콩 16:00~16:10)
+    ClosingAuction,
+    /// 장후 시간외 종가 (KRX 15:40~16:00)
+    AfterHoursClose,
+    /// 시간외 단일가 (KRX 16:00~18:00)
+    AfterHoursSingle,
+    /// 애프터마켓 (NXT 15:30~20:00, 미국 16:00~20:00)
+    AfterMarket,
+    /// 미국 주간거래 (전일 20:00~당일 04:00 ET, 한국시간 10:00~18:00 / 서머타임 09:00~17:00)
+    Daytime,
+}
+
+impl Session {
+    /// 정규 거래시간 여부 (정규장 + 장마감 동시호가)
+    pub fn is_regular(&self) -> bool {
+        matches!(self, Self::Regular | Self::ClosingAuction)
+    }
+
+    /// 봉 집계(`bars`)용 장 구분
+    pub fn market_session(&self) -> MarketSession {
+        match self {
+            Self::PreOpenAuction | Self::PreMarket | Self::Daytime => MarketSession::PreMarket,
+            Self::Regular | Self::ClosingAuction => MarketSession::Regular,
+            Self::AfterHoursClose | Self::AfterHoursSingle | Self::AfterMarket => {
+                MarketSession::AfterMarket
+            }
+        }
+    }
+}
+
+/// 특정 거래일의 세션 1구간
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionWindow {
+    pub market: Market,
+    pub session: Session,
+    /// 거래일 (현지 날짜, 주간거래는 다음 정규장 날짜)
+    pub date: Date,
+    pub start: SystemTime,
+    pub end: SystemTime,
+}
+
+impl SessionWindow {
+    pub fn contains(&self, t: SystemTime) -> bool {
+        self.start <= t && t < self.end
+    }
+}
+
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+const fn hm(hour: i64, minute: i64) -> i64 {
+    hour * HOUR + minute * MINUTE
+}
+
+const KRX_SESSIONS: &[(Session, i64, i64)] = &[
+    (Session::PreOpenAuction, hm(8, 30), hm(9, 0)),
+    (Session::Regular, hm(9, 0), hm(15, 20)),
+    (Session::ClosingAuction, hm(15, 20), hm(15, 30)),
+    (Session::AfterHoursClose, hm(15, 40), hm(16, 0)),
+    (Session::AfterHoursSingle, hm(16, 0), hm(18, 0)),
+];
+const NXT_SESSIONS: &[(Session, i64, i64)] = &[
+    (Session::PreMarket, hm(8, 0), hm(8, 50)),
+    (Session::Regular, hm(9, 0) + 30, hm(15, 20)),
+    (Session::AfterMarket, hm(15, 30), hm(20, 0)),
+];
+const US_SESSIONS: &[(Session, i64, i64)] = &[
+    (Session::Daytime, hm(-4, 0), hm(4, 0)),
+    (Session::PreMarket, hm(4, 0), hm(9, 30)),
+    (Session::Regular, hm(9, 30), hm(16, 0)),
+    (Session::AfterMarket, hm(16, 0), hm(20, 0)),
+];
+const HK_SESSIONS: &[(Session, i64, i64)] = &[
+    (Session::PreOpenAuction, hm(9, 0), hm(9, 30)),
+    (Session::Regular, hm(9, 30), hm(12, 0)),
+    (Session::Regular, hm(13, 0), hm(16, 0)),
+    (Session::ClosingAuction, hm(16, 0), hm(16, 10)),
+];
+const JP_SESSIONS: &[(Session, i64, i64)] = &[
+    (Session::Regular, hm(9, 0), hm(11, 30)),
+    (Session::Regular, hm(12, 30), hm(15, 30)),
+];
+const CN_SESSIONS: &[(Session, i64, i64)] = &[
+    (Session::PreOpenAuction, hm(9, 15), hm(9, 25)),
+    (Session::Regular, hm(9, 30), hm(11, 30)),
+    (Session::Regular, hm(13, 0), hm(15, 0)),
+];
+
+/// 시장 달력
+///
+/// 내장 휴장 규칙은 다음과 같습니다.
+/// - 국내(KRX/NXT): 주말, 양력 고정 공휴일, 근로자의 날, 연말 휴장일, 연초 개장일 10시 개장.
+///   설/추석/대체공휴일/선거일 등은 [`MarketCalendar::load_domestic_holidays`]로 불러와야 합니다.
+/// - 미국: NYSE 휴장일(부활절 계산 포함)과 독립기념일 전날/추수감사절 다음날/성탄 전날 13시 조기폐장.
+/// - 홍콩/일본/중국: 주말만 내장되어 있으며 휴장일은 [`MarketCalendar::add_holiday`]로 등록합니다.
+#[derive(Debug, Clone, Default)]
+pub struct MarketCalendar {
+    /// 명시적으로 등록된 개장(true)/휴장(false) 여부
+    overrides: HashMap<Market, BTreeMap<Date, bool>>,
+    /// 정규장 종료 시각 변경 (현지 자정 기준 초)
+    early_closes: HashMap<Market, BTreeMap<Date, i64>>,
+    /// 정규장 시작 시각 변경 (현지 자정 기준 초)
+    late_opens: HashMap<Market, BTreeMap<Date, i64>>,
+}
+
+impl MarketCalendar {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 국내휴장일조회(chk-holiday)로 `from`~`to` 구간의 국내 개장일 정보를 불러와 KRX/NXT에 반영
+    ///
+    /// 반영한 날짜 수를 반환합니다.
+    pub async fn load_domestic_holidays(
+        &mut self,
+        provider: &KISProvider,
+        from: Date,
+        to: Date,
+    ) -> Result<usize, Box<dyn Error>> {
+        let mut loaded = 0;
+        let mut cursor = from;
+        while cursor <= to {
+            let response = provider.get_holidays(&cursor.to_string()).await?;
+            let mut last = None;
+            for row in &response.body {
+                let Ok(date) = row.bass_dt.parse::<Date>() else {
+                    continue;
+                };
+                if date >= from && date <= to {
+                    let open = row.opnd_yn == "Y";
+                    self.set_open(Market::Krx, date, open);
+                    self.set_open(Market::Nxt, date, open);
+                    loaded += 1;
+                }
+                last = last.max(Some(date));
+            }
+            match last {
+                Some(last) if last >= cursor => cursor = last.add_days(1),
+                _ => break,
+            }
+            if cursor <= to {
+                tokio::time::sleep(request_interval(provider.practice)).await;
+            }
+        }
+        Ok(loaded)
+    }
+
+    /// 휴장일 등록
+    pub fn add_holiday(&mut self, market: Market, date: Date) {
+        self.set_open(market, date, false);
+    }
+
+    /// 내장 규칙상 휴장이지만 개장하는 날 등록 (임시 개장 등)
+    pub fn add_trading_day(&mut self, market: Market, date: Date) {
+        self.set_open(market, date, true);
+    }
+
+    /// 정규장 조기 종료 (이후 세션도 같은 만큼 당겨짐), `time`은 HHMMSS
+    pub fn set_early_close(
+        &mut self,
+        market: Market,
+        date: Date,
+        time: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let seconds = parse_hms(time)?;
+        self.early_closes
+            .entry(market)
+            .or_default()
+            .insert(date, seconds);
+        Ok(())
+    }
+
+    /// 정규장 지연 개장 (이전 세션도 같은 만큼 밀림, 수능일 등), `time`은 HHMMSS
+    pub fn set_late_open(
+        &mut self,
+        market: Market,
+        date: Date,
+        time: &str,
+    ) -> Result<(), Box<dyn Error>> {
+        let seconds = parse_hms(time)?;
+        self.late_opens
+            .entry(market)
+            .or_default()
+            .insert(date, seconds);
+        Ok(())
+    }
+
+    fn set_open(&mut self, market: Market, date: Date, open: bool) {
+        self.overrides.entry(market).or_default().insert(date, open);
+    }
+
+    /// 현지 날짜가 거래일인지
+    pub fn is_business_day(&self, market: Market, date: Date) -> bool {
+        if let Some(open) = self.overrides.get(&market).and_then(|days| days.get(&date)) {
+            return *open;
+        }
+        !date.weekday().is_weekend() && !builtin_holiday(market, date)
+    }
+
+    /// `date` 이후(미포함) 첫 거래일
+    pub fn next_business_day(&self, market: Market, date: Date) -> Date {
+        self.add_business_days(market, date, 1)
+    }
+
+    /// `date` 이전(미포함) 마지막 거래일
+    pub fn previous_business_day(&self, market: Market, date: Date) -> Date {
+        self.add_business_days(market, date, -1)
+    }
+
+    /// 거래일 기준으로 `days`일 이동 (음수면 과거 방향)
+    pub fn add_business_days(&self, market: Market, date: Date, days: i64) -> Date {
+        let step = if days < 0 { -1 } else { 1 };
+        let mut remaining = days.abs();
+        let mut cursor = date;
+        while remaining > 0 {
+            cursor = cursor.add_days(step);
+            if self.is_business_day(market, cursor) {
+                remaining -= 1;
+            }
+        }
+        cursor
+    }
+
+    /// `from` 다음날부터 `to`까지(포함)의 거래일 수 (`to`가 앞서면 음수)
+    pub fn business_days_between(&self, market: Market, from: Date, to: Date) -> i64 {
+        let (start, end, sign) = if from <= to {
+            (from, to, 1)
+        } else {
+            (to, from, -1)
+        };
+        let count = (start.days() + 1..=end.days())
+            .filter(|days| self.is_business_day(market, Date::from_days(*days)))
+            .count() as i64;
+        count * sign
+    }
+
+    /// 거래일의 세션 목록 (휴장일이면 빈 목록)
+    pub fn sessions(&self, market: Market, date: Date) -> Vec<SessionWindow> {
+        if !self.is_business_day(market, date) {
+            return Vec::new();
+        }
+        let table = market.table();
+        let regular_start = table
+            .iter()
+            .find(|(session, _, _)| *session == Session::Regular)
+            .map(|(_, start, _)| *start);
+        let regular_end = table
+            .iter()
+            .rev()
+            .find(|(session, _, _)| *session == Session::Regular)
+            .map(|(_, _, end)| *end);
+        let late_open = self.late_open(market, date);
+        let early_close = self.early_close(market, date);
+
+        table
+            .iter()
+            .filter_map(|(session, start, end)| {
+                let (mut start, mut end) = (*start, *end);
+                // 지연 개장: 첫 정규장 이전 세션은 같이 밀림
+                if let (Some(open), Some(first)) = (late_open, regular_start) {
+                    if end <= first {
+                        start += open - first;
+                        end += open - first;
+                    } else if start == first {
+                        start = open;
+                    }
+                }
+                // 조기 폐장: 마지막 정규장 이후 세션은 같이 당겨짐
+                if let (Some(close), Some(last)) = (early_close, regular_end) {
+                    if start >= last {
+                        start += close - last;
+                        end += close - last;
+                    } else if end == last {
+                        end = close;
+                    } else if start >= close {
+                        return None;
+                    }
+                }
+                (start < end).then(|| SessionWindow {
+                    market,
+                    session: *session,
+                    date,
+                    start: system_time(market.instant_at(date, start)),
+                    end: system_time(market.instant_at(date, end)),
+                })
+            })
+            .collect()
+    }
+
+    /// 시각 `t`가 속한 세션
+    pub fn session_at(&self, market: Market, t: SystemTime) -> Option<SessionWindow> {
+        let (today, _) = self.local_time(market, t);
+        (-1..=1)
+            .flat_map(|offset| self.sessions(market, today.add_days(offset)))
+            .find(|window| window.contains(t))
+    }
+
+    /// 정규 거래시간(정규장/장마감 동시호가) 여부
+    pub fn is_open(&self, market: Market, t: SystemTime) -> bool {
+        self.session_at(market, t)
+            .is_some_and(|window| window.session.is_regular())
+    }
+
+    /// 정규장 외 세션을 포함해 주문 가능한 세션 중인지
+    pub fn is_trading(&self, market: Market, t: SystemTime) -> bool {
+        self.session_at(market, t).is_some()
+    }
+
+    /// `t` 이후 다음 정규장 시작 시각 (점심 휴장 후 재개 포함)
+    pub fn next_open(&self, market: Market, t: SystemTime) -> Option<SystemTime> {
+        self.regular_windows_from(market, t)
+            .find(|(start, _)| *start > t)
+            .map(|(start, _)| start)
+    }
+
+    /// `t` 이후 다음 정규장 종료 시각 (장중이면 현재 정규장 종료 시각)
+    pub fn next_close(&self, market: Market, t: SystemTime) -> Option<SystemTime> {
+        self.regular_windows_from(market, t)
+            .find(|(_, end)| *end > t)
+            .map(|(_, end)| end)
+    }
+
+    /// 시장 현지 날짜와 자정 이후 경과 초
+    pub fn local_time(&self, market: Market, t: SystemTime) -> (Date, u32) {
+        let instant = unix_seconds(t);
+        let local = instant + market.utc_offset_at(instant);
+        (
+            Date::from_days(local.div_euclid(DAY)),
+            local.rem_euclid(DAY) as u32,
+        )
+    }
+
+    /// `t` 전날부터 약 1년간의 정규 거래시간 구간 (이어지는 정규장/동시호가는 하나로 합침)
+    fn regular_windows_from(
+        &self,
+        market: Market,
+        t: SystemTime,
+    ) -> impl Iterator<Item = (SystemTime, SystemTime)> + '_ {
+        let (today, _) = self.local_time(market, t);
+        (-1..=370).flat_map(move |offset| {
+            let mut merged: Vec<(SystemTime, SystemTime)> = Vec::new();
+            for window in self.sessions(market, today.add_days(offset)) {
+                if !window.session.is_regular() {
+                    continue;
+                }
+                match merged.last_mut() {
+                    Some(last) if last.1 == window.start => last.1 = window.end,
+                    _ => merged.push((window.start, window.end)),
+                }
+            }
+            merged
+        })
+    }
+
+    fn late_open(&self, market: Market, date: Date) -> Option<i64> {
+        if let Some(seconds) = self.late_opens.get(&market).and_then(|d| d.get(&date)) {
+            return Some(*seconds);
+        }
+        // 연초 개장일은 1시간 늦게 개장
+        (market == Market::Krx && self.is_first_business_day_of_year(market, date))
+            .then_some(hm(10, 0))
+    }
+
+    fn early_close(&self, market: Market, date: Date) -> Option<i64> {
+        if let Some(seconds) = self.early_closes.get(&market).and_then(|d| d.get(&date)) {
+            return Some(*seconds);
+        }
+        (market == Market::Us && is_us_half_day(date)).then_some(hm(13, 0))
+    }
+
+    fn is_first_business_day_of_year(&self, market: Market, date: Date) -> bool {
+        (1..date.ordinal())
+            .all(|day| !self.is_business_day(market, Date::from_days(date.days() - i64::from(day))))
+    }
+}
+
+// ------------------------------------------------------
+// 내장 휴장 규칙
+// ------------------------------------------------------
+
+fn builtin_holiday(market: Market, date: Date) -> bool {
+    match market {
+        Market::Krx | Market::Nxt => is_korea_fixed_holiday(date),
+        Market::Us => is_us_holiday(date),
+        Market::HongKong | Market::Japan | Market::China => false,
+    }
+}
+
+/// 국내 양력 고정 휴장일 (설/추석/부처님오신날/대체공휴일은 chk-holiday로 보완)
+fn is_korea_fixed_holiday(date: Date) -> bool {
+    let fixed = matches!(
+        (date.month(), date.day()),
+        (1, 1) | (3, 1) | (5, 1) | (5, 5) | (6, 6) | (8, 15) | (10, 3) | (10, 9) | (12, 25)
+    );
+    fixed || date == korea_year_end_closing(date.year())
+}
+
+/// 연말 휴장일: 12월 31일 (주말이면 직전 평일)
+fn korea_year_end_closing(year: i32) -> Date {
+    let mut date = Date::ymd(year, 12, 31);
+    while date.weekday().is_weekend() {
+        date = date.add_days(-1);
+    }
+    date
+}
+
+fn is_us_holiday(date: Date) -> bool {
+    let year = date.year();
+    // 토요일 공휴일은 금요일, 일요일 공휴일은 월요일에 휴장 (신정은 전년도 12/31 대체 없음)
+    let observed = |month, day| {
+        let holiday = Date::ymd(year, month, day);
+        match holiday.weekday() {
+            Weekday::Saturday => holiday.add_days(-1),
+            Weekday::Sunday => holiday.add_days(1),
+            _ => holiday,
+        }
+    };
+    let new_year = Date::ymd(year, 1, 1);
+    let new_year_observed = match new_year.weekday() {
+        Weekday::Sunday => new_year.add_days(1),
+        _ => new_year,
+    };
+    let mut holidays = vec![
+        new_year_observed,
+        nth_weekday(year, 1, Weekday::Monday, 3),
+        nth_weekday(year, 2, Weekday::Monday, 3),
+        easter(year).add_days(-2),
+        last_weekday(year, 5, Weekday::Monday),
+        observed(7, 4),
+        nth_weekday(year, 9, Weekday::Monday, 1),
+        nth_weekday(year, 11, Weekday::Thursday, 4),
+        observed(12, 25),
+    ];
+    if year >= 2022 {
+        holidays.push(observed(6, 19));
+    }
+    holidays.contains(&date)
+}
+
+/// 미국 13시 조기폐장일: 독립기념일 전날, 추수감사절 다음날, 성탄 전날
+fn is_us_half_day(date: Date) -> bool {
+    let year = date.year();
+    let weekday_before_holiday = |month, day| {
+        let candidate = Date::ymd(year, month, day);
+        // 다음날 공휴일이 금요일 이후로 밀리면 당일이 대체 휴장일이 되므로 월~목만 해당
+        date == candidate
+            && matches!(
+                candidate.weekday(),
+                Weekday::Monday | Weekday::Tuesday | Weekday::Wednesday | Weekday::Thursday
+            )
+    };
+    weekday_before_holiday(7, 3)
+        || weekday_before_holiday(12, 24)
+        || date == nth_weekday(year, 11, Weekday::Thursday, 4).add_days(1)
+}
+
+/// 미국 서머타임: 3월 둘째 일요일 02:00 EST ~ 11월 첫째 일요일 02:00 EDT
+fn is_us_dst(instant: i64) -> bool {
+    let year = Date::from_days(instant.div_euclid(DAY)).year();
+    let start = nth_weekday(year, 3, Weekday::Sunday, 2).days() * DAY + 7 * HOUR;
+    let end = nth_weekday(year, 11, Weekday::Sunday, 1).days() * DAY + 6 * HOUR;
+    (start..end).contains(&instant)
+}
+
+fn nth_weekday(year: i32, month: u32, weekday: Weekday, n: u32) -> Date {
+    let first = Date::ymd(year, month, 1);
+    let shift = (weekday as i64 - first.weekday() as i64).rem_euclid(7);
+    first.add_days(shift + 7 * (i64::from(n) - 1))
+}
+
+fn last_weekday(year: i32, month: u32, weekday: Weekday) -> Date {
+    let last = Date::ymd(year, month, days_in_month(year, month));
+    let shift = (last.weekday() as i64 - weekday as i64).rem_euclid(7);
+    last.add_days(-shift)
+}
+
+/// 부활절 (그레고리력, Meeus/Jones/Butcher)
+fn easter(year: i32) -> Date {
+    let a = year % 19;
+    let b = year / 100;
+    let c = year % 100;
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15) % 30;
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k) % 7;
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    Date::ymd(year, month as u32, day as u32)
+}
+
+// ------------------------------------------------------
+// 날짜 계산
+// ------------------------------------------------------
+
+/// 요일 (월요일 = 0)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    pub fn is_weekend(&self) -> bool {
+        matches!(self, Self::Saturday | Self::Sunday)
+    }
+}
+
+/// 그레고리력 날짜 (문자열 표기는 YYYYMMDD)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Self> {
+        ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+            .then_some(Self { year, month, day })
+    }
+
+    /// 규칙 계산용 (항상 유효한 날짜만 넘김)
+    fn ymd(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// 1970-01-01 기준 경과 일수
+    pub fn days(&self) -> i64 {
+        let (month, day) = (i64::from(self.month), i64::from(self.day));
+        let year = i64::from(self.year) - i64::from(month <= 2);
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146097 + doe - 719468
+    }
+
+    pub fn from_days(days: i64) -> Self {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + i64::from(month <= 2);
+        Self::ymd(year as i32, month as u32, day as u32)
+    }
+
+    pub fn add_days(&self, days: i64) -> Self {
+        Self::from_days(self.days() + days)
+    }
+
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01은 목요일
+        match (self.days() + 3).rem_euclid(7) {
+            0 => Weekday::Monday,
+            1 => Weekday::Tuesday,
+            2 => Weekday::Wednesday,
+            3 => Weekday::Thursday,
+            4 => Weekday::Friday,
+            5 => Weekday::Saturday,
+            _ => Weekday::Sunday,
+        }
+    }
+
+    /// 연중 일수 (1월 1일 = 1)
+    pub fn ordinal(&self) -> u32 {
+        (self.days() - Self::ymd(self.year, 1, 1).days()) as u32 + 1
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}{:02}{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("날짜 형식이 올바르지 않습니다 (YYYYMMDD): {text}");
+        if text.len() != 8 || !text.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let year = text[..4].parse().map_err(|_| invalid())?;
+        let month = text[4..6].parse().map_err(|_| invalid())?;
+        let day = text[6..].parse().map_err(|_| invalid())?;
+        Self::new(year, month, day).ok_or_else(invalid)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn parse_hms(time: &str) -> Result<i64, Box<dyn Error>> {
+    let invalid = || format!("시각 형식이 올바르지 않습니다 (HHMMSS): {time}");
+    if time.len() != 6 || !time.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid().into());
+    }
+    let hour: i64 = time[..2].parse()?;
+    let minute: i64 = time[2..4].parse()?;
+    let second: i64 = time[4..].parse()?;
+    if hour > 24 || minute > 59 || second > 59 {
+        return Err(invalid().into());
+    }
+    Ok(hour * HOUR + minute * MINUTE + second)
+}
+
+fn unix_seconds(t: SystemTime) -> i64 {
+    match t.duration_since(UNIX_EPOCH) {
+        Ok(elapsed) => elapsed.as_secs() as i64,
+        Err(before) => -(before.duration().as_secs() as i64),
+    }
+}
+
+fn system_time(seconds: i64) -> SystemTime {
+    if seconds >= 0 {
+        UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(year: i32, month: u32, day: u32, hour: i64, minute: i64) -> SystemTime {
+        system_time(Date::ymd(year, month, day).days() * DAY + hm(hour, minute))
+    }
+
+    /// 정규장(첫 시작 ~ 마지막 끝) 구간
+    fn regular(market: Market, date: Date) -> (SystemTime, SystemTime) {
+        let windows: Vec<SessionWindow> = MarketCalendar::new()
+            .sessions(market, date)
+            .into_iter()
+            .filter(|window| window.session == Session::Regular)
+            .collect();
+        (windows[0].start, windows[windows.len() - 1].end)
+    }
+
+    #[test]
+    fn us_sessions_follow_dst_switch_days() {
+        let calendar = MarketCalendar::new();
+        // 2025-03-09 02:00 EST → 03:00 EDT
+        assert_eq!(
+            calendar.local_time(Market::Us, utc(2025, 3, 9, 6, 59)),
+            (Date::ymd(2025, 3, 9), hm(1, 59) as u32)
+        );
+        assert_eq!(
+            calendar.local_time(Market::Us, utc(2025, 3, 9, 7, 0)),
+            (Date::ymd(2025, 3, 9), hm(3, 0) as u32)
+        );
+        assert_eq!(
+            regular(Market::Us, Date::ymd(2025, 3, 7)).0,
+            utc(2025, 3, 7, 14, 30)
+        );
+        assert_eq!(
+            regular(Market::Us, Date::ymd(2025, 3, 10)).0,
+            utc(2025, 3, 10, 13, 30)
+        );
+
+        // 2025-11-02 02:00 EDT → 01:00 EST
+        assert_eq!(
+            calendar.local_time(Market::Us, utc(2025, 11, 2, 5, 59)),
+            (Date::ymd(2025, 11, 2), hm(1, 59) as u32)
+        );
+        assert_eq!(
+            calendar.local_time(Market::Us, utc(2025, 11, 2, 6, 0)),
+            (Date::ymd(2025, 11, 2), hm(1, 0) as u32)
+        );
+        assert_eq!(
+            regular(Market::Us, Date::ymd(2025, 10, 31)).0,
+            utc(2025, 10, 31, 13, 30)
+        );
+        assert_eq!(
+            regular(Market::Us, Date::ymd(2025, 11, 3)).0,
+            utc(2025, 11, 3, 14, 30)
+        );
+    }
+
+    #[test]
+    fn good_friday_is_a_us_holiday() {
+        let calendar = MarketCalendar::new();
+        assert_eq!(easter(2025), Date::ymd(2025, 4, 20));
+        assert_eq!(easter(2024), Date::ymd(2024, 3, 31));
+        for date in [Date::ymd(2025, 4, 18), Date::ymd(2024, 3, 29)] {
+            assert!(!calendar.is_business_day(Market::Us, date), "{date}");
+            assert!(calendar.sessions(Market::Us, date).is_empty());
+            assert!(calendar.is_business_day(Market::Us, date.add_days(3)));
+        }
+    }
+
+    #[test]
+    fn us_half_days_close_at_one() {
+        let calendar = MarketCalendar::new();
+        // 13:00 ET 폐장, 애프터마켓도 3시간 당겨짐
+        for (date, close) in [
+            (Date::ymd(2025, 7, 3), utc(2025, 7, 3, 17, 0)),
+            (Date::ymd(2026, 11, 27), utc(2026, 11, 27, 18, 0)),
+            (Date::ymd(2025, 12, 24), utc(2025, 12, 24, 18, 0)),
+        ] {
+            assert_eq!(regular(Market::Us, date).1, close, "{date}");
+            let after = calendar
+                .sessions(Market::Us, date)
+                .into_iter()
+                .find(|window| window.session == Session::AfterMarket)
+                .unwrap();
+            assert_eq!(after.start, close);
+            assert_eq!(
+                unix_seconds(after.end) - unix_seconds(after.start),
+                4 * HOUR
+            );
+        }
+        // 독립기념일이 토요일이면 7/3은 대체 휴장일
+        assert!(!calendar.is_business_day(Market::Us, Date::ymd(2026, 7, 3)));
+        // 추수감사절 당일은 휴장
+        assert!(!calendar.is_business_day(Market::Us, Date::ymd(2025, 11, 27)));
+        assert_eq!(
+            regular(Market::Us, Date::ymd(2025, 11, 26)).1,
+            utc(2025, 11, 26, 21, 0)
+        );
+    }
+
+    #[test]
+    fn krx_first_business_day_opens_at_ten() {
+        let calendar = MarketCalendar::new();
+        let first = Date::ymd(2025, 1, 2);
+        assert!(!calendar.is_business_day(Market::Krx, Date::ymd(2025, 1, 1)));
+        assert_eq!(regular(Market::Krx, first).0, utc(2025, 1, 2, 1, 0));
+
+        // 장전 동시호가도 1시간 밀림
+        let auction = calendar.sessions(Market::Krx, first)[0];
+        assert_eq!(auction.session, Session::PreOpenAuction);
+        assert_eq!(
+            (auction.start, auction.end),
+            (utc(2025, 1, 2, 0, 30), utc(2025, 1, 2, 1, 0))
+        );
+        assert!(!calendar.is_open(Market::Krx, utc(2025, 1, 2, 0, 30)));
+        assert!(calendar.is_open(Market::Krx, utc(2025, 1, 2, 1, 0)));
+
+        assert_eq!(
+            regular(Market::Krx, first.add_days(1)).0,
+            utc(2025, 1, 3, 0, 0)
+        );
+    }
+}
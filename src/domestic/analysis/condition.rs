@@ -0,0 +1,369 @@
+use super::{DomesticAnalysis, DomesticAnalysisEndpoint};
+use crate::provider::KISProvider;
+use crate::utils::ApiResponse;
+use crate::websocket::{
+    REALTIME_REGISTRATION_LIMIT, RealtimeClient, RealtimeData, RealtimeError, RealtimeUniverse,
+    UniverseChange,
+};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// HTS에 저장된 조건검색식
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConditionTitle {
+    pub user_id: String,
+    /// 조건키값 (결과조회 `seq`)
+    pub seq: String,
+    pub grp_nm: String,
+    pub condition_nm: String,
+}
+
+/// 조건검색 결과 종목
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConditionMatch {
+    pub code: String,
+    pub name: String,
+    /// 전일대비부호
+    pub daebi: String,
+    pub price: String,
+    /// 등락율
+    pub chgrate: String,
+    pub acml_vol: String,
+    pub trade_amt: String,
+    /// 전일대비
+    pub change: String,
+    /// 체결강도
+    pub cttr: String,
+    pub open: String,
+    pub high: String,
+    pub low: String,
+    pub high52: String,
+    pub low52: String,
+    /// 예상체결가
+    pub expprice: String,
+    pub expchange: String,
+    pub expchggrate: String,
+    pub expcvol: String,
+    /// 전일거래량대비율
+    pub chgrate2: String,
+    pub expdaebi: String,
+    /// 기준가
+    pub recprice: String,
+    pub uplmtprice: String,
+    pub dnlmtprice: String,
+    /// 시가총액
+    pub stotprice: String,
+}
+
+#[async_trait]
+pub trait DomesticConditionSearch {
+    /// 조건검색 목록 (HTS ID 기준)
+    async fn list_conditions(
+        &self,
+        user_id: &str,
+    ) -> Result<ApiResponse<Vec<ConditionTitle>>, Box<dyn Error>>;
+    /// 조건검색 실행 (최대 100종목)
+    async fn run_condition(
+        &self,
+        user_id: &str,
+        seq: &str,
+    ) -> Result<ApiResponse<Vec<ConditionMatch>>, Box<dyn Error>>;
+}
+
+#[async_trait]
+impl DomesticConditionSearch for KISProvider {
+    async fn list_conditions(
+        &self,
+        user_id: &str,
+    ) -> Result<ApiResponse<Vec<ConditionTitle>>, Box<dyn Error>> {
+        let response = self
+            .get_analysis_raw(
+                DomesticAnalysisEndpoint::ConditionSearchTitles,
+                &[("user_id", user_id)],
+            )
+            .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn run_condition(
+        &self,
+        user_id: &str,
+        seq: &str,
+    ) -> Result<ApiResponse<Vec<ConditionMatch>>, Box<dyn Error>> {
+        let response = self
+            .get_analysis_raw(
+                DomesticAnalysisEndpoint::ConditionSearchResult,
+                &[("user_id", user_id), ("seq", seq)],
+            )
+            .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
+}
+
+/// 조건검색 편입/이탈 이벤트
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConditionEvent {
+    /// 새로 조건을 만족한 종목
+    Entered(ConditionMatch),
+    /// 조건에서 빠진 종목 (마지막으로 조회된 값)
+    Exited(ConditionMatch),
+}
+
+impl ConditionEvent {
+    pub fn code(&self) -> &str {
+        match self {
+            Self::Entered(row) | Self::Exited(row) => &row.code,
+        }
+    }
+}
+
+/// 조건검색 결과를 실시간 구독에 반영한 결과
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConditionRealtimeSync {
+    pub change: UniverseChange,
+    /// 등록 한도를 넘어 구독하지 않은 종목 (조회 결과 순)
+    pub skipped: Vec<String>,
+}
+
+/// 조건검색을 주기적으로 다시 실행해 편입/이탈을 알려주는 감시기
+///
+/// 첫 조회에서는 결과 전체가 `Entered`로 나오므로 실시간 구독 대상 초기화에 그대로 쓸 수 있습니다.
+/// 실시간 구독은 [`Self::sync_realtime`]으로 [`RealtimeUniverse`]에 맞춥니다.
+#[derive(Debug, Clone)]
+pub struct ConditionWatcher {
+    user_id: String,
+    seq: String,
+    interval: Duration,
+    members: BTreeMap<String, ConditionMatch>,
+    /// 마지막 조회 결과의 종목코드 (조회 결과 순)
+    order: Vec<String>,
+    next_poll: Option<Instant>,
+}
+
+impl ConditionWatcher {
+    /// 기본 조회 주기
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+    pub fn new(user_id: &str, seq: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            seq: seq.to_string(),
+            interval: Self::DEFAULT_INTERVAL,
+            members: BTreeMap::new(),
+            order: Vec::new(),
+            next_poll: None,
+        }
+    }
+
+    /// 조회 주기 지정
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// 현재 조건을 만족하는 종목 (종목코드 순)
+    pub fn members(&self) -> impl Iterator<Item = &ConditionMatch> {
+        self.members.values()
+    }
+
+    pub fn codes(&self) -> Vec<String> {
+        self.members.keys().cloned().collect()
+    }
+
+    pub fn contains(&self, code: &str) -> bool {
+        self.members.contains_key(code)
+    }
+
+    /// 새 조회 결과를 반영하고 편입/이탈 이벤트를 돌려줌
+    ///
+    /// 이탈 이벤트가 먼저, 편입 이벤트가 나중에 나옵니다. 남아 있는 종목은 시세만 갱신합니다.
+    pub fn apply(&mut self, matches: Vec<ConditionMatch>) -> Vec<ConditionEvent> {
+        let mut current = BTreeMap::new();
+        self.order.clear();
+        for row in matches.into_iter().filter(|row| !row.code.is_empty()) {
+            if !current.contains_key(&row.code) {
+                self.order.push(row.code.clone());
+            }
+            current.insert(row.code.clone(), row);
+        }
+        let previous = std::mem::replace(&mut self.members, current);
+        let mut events: Vec<ConditionEvent> = previous
+            .iter()
+            .filter(|(code, _)| !self.members.contains_key(*code))
+            .map(|(_, row)| ConditionEvent::Exited(row.clone()))
+            .collect();
+        events.extend(
+            self.members
+                .iter()
+                .filter(|(code, _)| !previous.contains_key(*code))
+                .map(|(_, row)| ConditionEvent::Entered(row.clone())),
+        );
+        events
+    }
+
+    /// 실시간 구독 대상과 등록 한도를 넘어 빠지는 종목
+    ///
+    /// 결과가 [`REALTIME_REGISTRATION_LIMIT`]건을 넘으면 이미 구독 중인 종목을 먼저 남기고,
+    /// 나머지는 조회 결과 순으로 채운 뒤 잘라냅니다.
+    fn realtime_targets(&self, subscribed: impl Fn(&str) -> bool) -> (Vec<String>, Vec<String>) {
+        let (mut targets, rest): (Vec<String>, Vec<String>) = self
+            .order
+            .iter()
+            .cloned()
+            .partition(|code| subscribed(code));
+        targets.extend(rest);
+        let skipped = targets.split_off(targets.len().min(REALTIME_REGISTRATION_LIMIT));
+        (targets, skipped)
+    }
+
+    /// 실시간 구독을 현재 조건 만족 종목과 같게 맞춤
+    ///
+    /// 조건검색은 최대 100종목을 돌려주지만 웹소켓 연결당 등록 한도는
+    /// [`REALTIME_REGISTRATION_LIMIT`]건이므로, 넘치는 종목은 오류 대신 잘라내고 `skipped`로 알려줍니다.
+    /// 이미 구독 중인 종목을 우선 유지해 결과 순서가 바뀌어도 구독이 흔들리지 않습니다.
+    pub async fn sync_realtime<T: RealtimeData + Clone + Send + Sync + 'static>(
+        &self,
+        client: &RealtimeClient,
+        universe: &mut RealtimeUniverse<T>,
+    ) -> Result<ConditionRealtimeSync, RealtimeError> {
+        let (targets, skipped) = self.realtime_targets(|code| universe.contains(code));
+        let change = universe.sync(client, targets).await?;
+        Ok(ConditionRealtimeSync { change, skipped })
+    }
+
+    /// 즉시 한 번 조회해 반영
+    pub async fn poll(
+        &mut self,
+        provider: &KISProvider,
+    ) -> Result<Vec<ConditionEvent>, Box<dyn Error>> {
+        let response = provider.run_condition(&self.user_id, &self.seq).await?;
+        self.next_poll = Some(Instant::now() + self.interval);
+        Ok(self.apply(response.body))
+    }
+
+    /// 다음 조회 시각까지 기다린 뒤 조회해 반영 (첫 호출은 바로 조회)
+    ///
+    /// 변화가 없으면 빈 목록을 돌려줍니다.
+    pub async fn next_events(
+        &mut self,
+        provider: &KISProvider,
+    ) -> Result<Vec<ConditionEvent>, Box<dyn Error>> {
+        if let Some(next_poll) = self.next_poll {
+            tokio::time::sleep_until(next_poll).await;
+        }
+        self.poll(provider).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(code: &str, price: &str) -> ConditionMatch {
+        ConditionMatch {
+            code: code.to_string(),
+            price: price.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn codes(events: &[ConditionEvent]) -> Vec<String> {
+        events
+            .iter()
+            .map(|event| match event {
+                ConditionEvent::Entered(row) => format!("+{}", row.code),
+                ConditionEvent::Exited(row) => format!("-{}", row.code),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn first_apply_enters_everything() {
+        let mut watcher = ConditionWatcher::new("user", "0");
+        let events = watcher.apply(vec![row("035720", "40000"), row("005930", "70000")]);
+        assert_eq!(codes(&events), ["+005930", "+035720"]);
+        assert_eq!(watcher.codes(), ["005930", "035720"]);
+    }
+
+    #[test]
+    fn exits_come_before_entries() {
+        let mut watcher = ConditionWatcher::new("user", "0");
+        watcher.apply(vec![row("005930", "70000"), row("000660", "180000")]);
+        let events = watcher.apply(vec![row("005930", "70100"), row("035420", "200000")]);
+        assert_eq!(codes(&events), ["-000660", "+035420"]);
+        assert!(matches!(&events[0], ConditionEvent::Exited(row) if row.price == "180000"));
+        assert!(!watcher.contains("000660"));
+        assert!(watcher.contains("035420"));
+
+        let events = watcher.apply(Vec::new());
+        assert_eq!(codes(&events), ["-005930", "-035420"]);
+        assert_eq!(watcher.members().count(), 0);
+    }
+
+    #[test]
+    fn staying_members_refresh_quietly() {
+        let mut watcher = ConditionWatcher::new("user", "0");
+        watcher.apply(vec![row("005930", "70000")]);
+        let events = watcher.apply(vec![row("005930", "71000")]);
+        assert!(events.is_empty());
+        assert_eq!(watcher.members().next().unwrap().price, "71000");
+    }
+
+    #[test]
+    fn empty_codes_are_dropped() {
+        let mut watcher = ConditionWatcher::new("user", "0");
+        let events = watcher.apply(vec![row("", "0"), row("005930", "70000")]);
+        assert_eq!(codes(&events), ["+005930"]);
+        assert!(!watcher.contains(""));
+    }
+
+    #[test]
+    fn realtime_targets_respect_registration_limit() {
+        let mut watcher = ConditionWatcher::new("user", "0");
+        let all: Vec<String> = (0..60).map(|i| format!("{:06}", 100 - i)).collect();
+        watcher.apply(all.iter().map(|code| row(code, "1000")).collect());
+
+        let (targets, skipped) = watcher.realtime_targets(|_| false);
+        assert_eq!(targets.len(), REALTIME_REGISTRATION_LIMIT);
+        // 조회 결과 순서를 따름
+        assert_eq!(targets[..], all[..REALTIME_REGISTRATION_LIMIT]);
+        assert_eq!(skipped[..], all[REALTIME_REGISTRATION_LIMIT..]);
+
+        // 이미 구독 중인 종목은 결과 뒤쪽에 있어도 유지
+        let tail = all[59].clone();
+        let (targets, skipped) = watcher.realtime_targets(|code| code == tail);
+        assert_eq!(targets[0], tail);
+        assert_eq!(targets.len(), REALTIME_REGISTRATION_LIMIT);
+        assert!(!skipped.contains(&tail));
+        assert_eq!(skipped.len(), 60 - REALTIME_REGISTRATION_LIMIT);
+    }
+
+    #[test]
+    fn realtime_targets_within_limit_skip_nothing() {
+        let mut watcher = ConditionWatcher::new("user", "0");
+        watcher.apply(vec![
+            row("035720", "40000"),
+            row("005930", "70000"),
+            row("035720", "40100"),
+        ]);
+        let (targets, skipped) = watcher.realtime_targets(|_| false);
+        assert_eq!(targets, ["035720", "005930"]);
+        assert!(skipped.is_empty());
+    }
+}
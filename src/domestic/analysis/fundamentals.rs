@@ -0,0 +1,402 @@
+use super::{DomesticAnalysis, DomesticAnalysisEndpoint};
+use crate::domestic::quotations::Domestic;
+use crate::provider::KISProvider;
+use crate::types::MarketDivision;
+use crate::utils::request_interval;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+
+/// 재무 조회 주기 (FID_DIV_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FinancialPeriod {
+    #[default]
+    Annual,
+    /// 분기 (손익계산서는 연초부터 누적 금액)
+    Quarterly,
+}
+
+impl FinancialPeriod {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Annual => "0",
+            Self::Quarterly => "1",
+        }
+    }
+}
+
+/// 대차대조표 (금액 단위: 억원)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BalanceSheetRow {
+    /// 결산년월 (YYYYMM)
+    pub stac_yymm: String,
+    /// 유동자산
+    pub cras: String,
+    /// 고정자산
+    pub fxas: String,
+    pub total_aset: String,
+    /// 유동부채
+    pub flow_lblt: String,
+    /// 고정부채
+    pub fix_lblt: String,
+    pub total_lblt: String,
+    /// 자본금
+    pub cpfn: String,
+    /// 자본잉여금
+    pub cfp_surp: String,
+    /// 이익잉여금
+    pub prfi_surp: String,
+    pub total_cptl: String,
+}
+
+/// 손익계산서 (금액 단위: 억원)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IncomeStatementRow {
+    pub stac_yymm: String,
+    /// 매출액
+    pub sale_account: String,
+    /// 매출원가
+    pub sale_cost: String,
+    /// 매출총이익
+    pub sale_totl_prfi: String,
+    /// 감가상각비
+    pub depr_cost: String,
+    /// 판매및관리비
+    pub sell_mang: String,
+    /// 영업이익
+    pub bsop_prti: String,
+    /// 영업외수익
+    pub bsop_non_ernn: String,
+    /// 영업외비용
+    pub bsop_non_expn: String,
+    /// 경상이익
+    pub op_prfi: String,
+    /// 특별이익
+    pub spec_prfi: String,
+    /// 특별손실
+    pub spec_loss: String,
+    /// 당기순이익
+    pub thtr_ntin: String,
+}
+
+/// 재무비율
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FinancialRatioRow {
+    pub stac_yymm: String,
+    /// 매출액증가율
+    pub grs: String,
+    /// 영업이익증가율
+    pub bsop_prfi_inrt: String,
+    /// 순이익증가율
+    pub ntin_inrt: String,
+    pub roe_val: String,
+    pub eps: String,
+    /// 주당매출액
+    pub sps: String,
+    pub bps: String,
+    /// 유보비율
+    pub rsrv_rate: String,
+    /// 부채비율
+    pub lblt_rate: String,
+}
+
+/// 수익성비율
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ProfitRatioRow {
+    pub stac_yymm: String,
+    /// 총자본순이익율
+    pub cptl_ntin_rate: String,
+    /// 자기자본순이익율
+    pub self_cptl_ntin_inrt: String,
+    /// 매출액순이익율
+    pub sale_ntin_rate: String,
+    /// 매출액총이익율
+    pub sale_totl_rate: String,
+}
+
+/// 안정성비율
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StabilityRatioRow {
+    pub stac_yymm: String,
+    /// 부채비율
+    pub lblt_rate: String,
+    /// 차입금의존도
+    pub bram_depn: String,
+    /// 유동비율
+    pub crnt_rate: String,
+    /// 당좌비율
+    pub quck_rate: String,
+}
+
+/// 성장성비율
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GrowthRatioRow {
+    pub stac_yymm: String,
+    /// 매출액증가율
+    pub grs: String,
+    /// 영업이익증가율
+    pub bsop_prfi_inrt: String,
+    /// 자기자본증가율
+    pub equt_inrt: String,
+    /// 총자산증가율
+    pub totl_aset_inrt: String,
+}
+
+/// 결산년월 하나의 재무제표와 비율
+///
+/// 응답마다 제공 기간이 달라 해당 결산년월이 없는 항목은 `None`입니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FiscalPeriodFundamentals {
+    /// 결산년월 (YYYYMM)
+    pub stac_yymm: String,
+    pub balance_sheet: Option<BalanceSheetRow>,
+    pub income_statement: Option<IncomeStatementRow>,
+    pub financial_ratio: Option<FinancialRatioRow>,
+    pub profit_ratio: Option<ProfitRatioRow>,
+    pub stability_ratio: Option<StabilityRatioRow>,
+    pub growth_ratio: Option<GrowthRatioRow>,
+}
+
+impl FiscalPeriodFundamentals {
+    /// 매출액 (억원)
+    pub fn revenue(&self) -> Option<f64> {
+        number(&self.income_statement.as_ref()?.sale_account)
+    }
+
+    /// 영업이익 (억원)
+    pub fn operating_income(&self) -> Option<f64> {
+        number(&self.income_statement.as_ref()?.bsop_prti)
+    }
+
+    /// 당기순이익 (억원)
+    pub fn net_income(&self) -> Option<f64> {
+        number(&self.income_statement.as_ref()?.thtr_ntin)
+    }
+
+    /// 자본총계 (억원)
+    pub fn total_equity(&self) -> Option<f64> {
+        number(&self.balance_sheet.as_ref()?.total_cptl)
+    }
+
+    /// 영업이익률 (%)
+    pub fn operating_margin(&self) -> Option<f64> {
+        let revenue = self.revenue().filter(|revenue| *revenue != 0.0)?;
+        Some(self.operating_income()? / revenue * 100.0)
+    }
+
+    /// ROE (%)
+    pub fn roe(&self) -> Option<f64> {
+        number(&self.financial_ratio.as_ref()?.roe_val)
+    }
+
+    /// 부채비율 (%)
+    pub fn debt_ratio(&self) -> Option<f64> {
+        self.stability_ratio
+            .as_ref()
+            .and_then(|row| number(&row.lblt_rate))
+            .or_else(|| number(&self.financial_ratio.as_ref()?.lblt_rate))
+    }
+
+    /// 유동비율 (%)
+    pub fn current_ratio(&self) -> Option<f64> {
+        number(&self.stability_ratio.as_ref()?.crnt_rate)
+    }
+
+    /// 매출액증가율 (%)
+    pub fn revenue_growth(&self) -> Option<f64> {
+        self.growth_ratio
+            .as_ref()
+            .and_then(|row| number(&row.grs))
+            .or_else(|| number(&self.financial_ratio.as_ref()?.grs))
+    }
+
+    /// 영업이익증가율 (%)
+    pub fn operating_income_growth(&self) -> Option<f64> {
+        self.growth_ratio
+            .as_ref()
+            .and_then(|row| number(&row.bsop_prfi_inrt))
+            .or_else(|| number(&self.financial_ratio.as_ref()?.bsop_prfi_inrt))
+    }
+}
+
+/// 종목 펀더멘털 스냅샷
+///
+/// 현재가 시세의 PER/PBR/EPS/BPS와 결산기별 재무제표·비율을 묶은 값입니다.
+/// 값이 비어 있거나 숫자가 아니면 `None`입니다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Fundamentals {
+    pub code: String,
+    pub period: FinancialPeriod,
+    pub price: Option<f64>,
+    /// 시가총액 (억원)
+    pub market_cap: Option<f64>,
+    pub per: Option<f64>,
+    pub pbr: Option<f64>,
+    pub eps: Option<f64>,
+    pub bps: Option<f64>,
+    /// 결산기별 재무 (최근 결산기부터)
+    pub periods: Vec<FiscalPeriodFundamentals>,
+}
+
+impl Fundamentals {
+    /// 가장 최근 결산기
+    pub fn latest(&self) -> Option<&FiscalPeriodFundamentals> {
+        self.periods.first()
+    }
+
+    /// 결산년월(YYYYMM)로 조회
+    pub fn fiscal_period(&self, stac_yymm: &str) -> Option<&FiscalPeriodFundamentals> {
+        self.periods
+            .iter()
+            .find(|period| period.stac_yymm == stac_yymm)
+    }
+}
+
+fn number(value: &str) -> Option<f64> {
+    value.trim().replace(',', "").parse().ok()
+}
+
+#[async_trait]
+pub trait DomesticFundamentals {
+    /// 재무제표/재무비율 6종과 현재가 밸류에이션을 결산기별로 맞춰 조회 (실전 전용)
+    async fn fundamentals(
+        &self,
+        code: &str,
+        period: FinancialPeriod,
+    ) -> Result<Fundamentals, Box<dyn Error>>;
+}
+
+async fn get_statement<T: DeserializeOwned>(
+    provider: &KISProvider,
+    endpoint: DomesticAnalysisEndpoint,
+    code: &str,
+    period: FinancialPeriod,
+) -> Result<Vec<T>, Box<dyn Error>> {
+    tokio::time::sleep(request_interval(provider.practice)).await;
+    let response = provider
+        .get_analysis_raw(
+            endpoint,
+            &[
+                ("FID_DIV_CLS_CODE", period.code()),
+                ("fid_cond_mrkt_div_code", "J"),
+                ("fid_input_iscd", code),
+            ],
+        )
+        .await?;
+    Ok(response.body.rows_as("output")?)
+}
+
+/// 결산년월별 항목을 채우고 없으면 새 결산기를 만듭니다
+fn merge<T>(
+    periods: &mut BTreeMap<String, FiscalPeriodFundamentals>,
+    rows: Vec<T>,
+    stac_yymm: impl Fn(&T) -> &str,
+    slot: impl Fn(&mut FiscalPeriodFundamentals) -> &mut Option<T>,
+) {
+    for row in rows {
+        let key = stac_yymm(&row).to_string();
+        if key.is_empty() {
+            continue;
+        }
+        let entry = periods
+            .entry(key.clone())
+            .or_insert_with(|| FiscalPeriodFundamentals {
+                stac_yymm: key,
+                ..Default::default()
+            });
+        *slot(entry) = Some(row);
+    }
+}
+
+#[async_trait]
+impl DomesticFundamentals for KISProvider {
+    async fn fundamentals(
+        &self,
+        code: &str,
+        period: FinancialPeriod,
+    ) -> Result<Fundamentals, Box<dyn Error>> {
+        // 재무 API는 실전 전용이므로 시세 조회 전에 확인합니다
+        DomesticAnalysisEndpoint::BalanceSheet
+            .endpoint()
+            .url(self.practice)?;
+        let quote = self
+            .get_inquire_price(MarketDivision::Krx, code)
+            .await?
+            .body;
+        let balance_sheets: Vec<BalanceSheetRow> =
+            get_statement(self, DomesticAnalysisEndpoint::BalanceSheet, code, period).await?;
+        let income_statements: Vec<IncomeStatementRow> = get_statement(
+            self,
+            DomesticAnalysisEndpoint::IncomeStatement,
+            code,
+            period,
+        )
+        .await?;
+        let financial_ratios: Vec<FinancialRatioRow> =
+            get_statement(self, DomesticAnalysisEndpoint::FinancialRatio, code, period).await?;
+        let profit_ratios: Vec<ProfitRatioRow> =
+            get_statement(self, DomesticAnalysisEndpoint::ProfitRatio, code, period).await?;
+        let stability_ratios: Vec<StabilityRatioRow> =
+            get_statement(self, DomesticAnalysisEndpoint::StabilityRatio, code, period).await?;
+        let growth_ratios: Vec<GrowthRatioRow> =
+            get_statement(self, DomesticAnalysisEndpoint::GrowthRatio, code, period).await?;
+
+        let mut periods = BTreeMap::new();
+        merge(
+            &mut periods,
+            balance_sheets,
+            |row| &row.stac_yymm,
+            |p| &mut p.balance_sheet,
+        );
+        merge(
+            &mut periods,
+            income_statements,
+            |row| &row.stac_yymm,
+            |p| &mut p.income_statement,
+        );
+        merge(
+            &mut periods,
+            financial_ratios,
+            |row| &row.stac_yymm,
+            |p| &mut p.financial_ratio,
+        );
+        merge(
+            &mut periods,
+            profit_ratios,
+            |row| &row.stac_yymm,
+            |p| &mut p.profit_ratio,
+        );
+        merge(
+            &mut periods,
+            stability_ratios,
+            |row| &row.stac_yymm,
+            |p| &mut p.stability_ratio,
+        );
+        merge(
+            &mut periods,
+            growth_ratios,
+            |row| &row.stac_yymm,
+            |p| &mut p.growth_ratio,
+        );
+
+        Ok(Fundamentals {
+            code: code.to_string(),
+            period,
+            price: number(&quote.stck_prpr),
+            market_cap: number(&quote.hts_avls),
+            per: number(&quote.per),
+            pbr: number(&quote.pbr),
+            eps: number(&quote.eps),
+            bps: number(&quote.bps),
+            periods: periods.into_values().rev().collect(),
+        })
+    }
+}
@@ -1,3 +1,30 @@
+pub mod condition;
+pub mod fundamentals;
+pub mod ranking;
+pub mod watchlist;
+
+pub use condition::{
+    ConditionEvent, ConditionMatch, ConditionRealtimeSync, ConditionTitle, ConditionWatcher,
+    DomesticConditionSearch,
+};
+pub use fundamentals::{
+    BalanceSheetRow, DomesticFundamentals, FinancialPeriod, FinancialRatioRow,
+    FiscalPeriodFundamentals, Fundamentals, GrowthRatioRow, IncomeStatementRow, ProfitRatioRow,
+    StabilityRatioRow,
+};
+pub use ranking::{
+    DisparityDays, DisparityRankRequest, DisparityRankRow, DividendKind, DividendRateRankRequest,
+    DividendRateRankRow, DividendSettlement, DomesticRankings, FluctuationRankRequest,
+    FluctuationRankRow, FluctuationSort, MarketCapRankRequest, MarketCapRankRow, NearExtreme,
+    NearNewHighLowRankRequest, NearNewHighLowRankRow, RankingExclusions, RankingFilter,
+    RankingMarket, RankingQuote, ShareClass, ShortSalePeriod, ShortSaleRankRequest,
+    ShortSaleRankRow, VolumePowerRankRequest, VolumePowerRankRow, VolumeRankRequest, VolumeRankRow,
+    VolumeRankSort,
+};
+pub use watchlist::{
+    DomesticWatchlist, WatchlistDiff, WatchlistGroup, WatchlistMember, WatchlistTracker,
+};
+
 use crate::provider::KISProvider;
 use crate::utils::{ApiEndpoint, ApiResponse, RawApiBody, TrId, call_get_api};
 use async_trait::async_trait;
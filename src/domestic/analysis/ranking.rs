@@ -0,0 +1,993 @@
+use super::{DomesticAnalysis, DomesticRankingEndpoint};
+use crate::provider::KISProvider;
+use crate::utils::ApiResponse;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+/// 순위 대상 시장 (FID_INPUT_ISCD)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RankingMarket {
+    #[default]
+    All,
+    Kospi,
+    Kosdaq,
+    Kospi200,
+}
+
+impl RankingMarket {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::All => "0000",
+            Self::Kospi => "0001",
+            Self::Kosdaq => "1001",
+            Self::Kospi200 => "2001",
+        }
+    }
+}
+
+/// 보통주/우선주 구분 (FID_DIV_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShareClass {
+    #[default]
+    All,
+    Common,
+    Preferred,
+}
+
+impl ShareClass {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::All => "0",
+            Self::Common => "1",
+            Self::Preferred => "2",
+        }
+    }
+}
+
+/// 순위 공통 조건
+///
+/// 가격은 원, 거래량은 주 단위이고 `None`이면 제한하지 않습니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RankingFilter {
+    pub market: RankingMarket,
+    pub price_min: Option<u64>,
+    pub price_max: Option<u64>,
+    /// 최소 거래량
+    pub min_volume: Option<u64>,
+}
+
+/// 거래량순위 제외 종목 (FID_TRGT_EXLS_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RankingExclusions {
+    /// 투자위험/경고/주의
+    pub investment_warning: bool,
+    pub administrative: bool,
+    /// 정리매매
+    pub liquidation: bool,
+    /// 불성실공시
+    pub unfaithful_disclosure: bool,
+    pub preferred: bool,
+    /// 거래정지
+    pub suspended: bool,
+    pub etf: bool,
+    pub etn: bool,
+    /// 신용주문불가
+    pub no_credit: bool,
+    pub spac: bool,
+}
+
+impl RankingExclusions {
+    /// 일반 주식만 남기는 제외 조건 (투자주의·관리·정리매매·불성실공시·거래정지·ETF·ETN·SPAC 제외)
+    pub fn common_stocks() -> Self {
+        Self {
+            investment_warning: true,
+            administrative: true,
+            liquidation: true,
+            unfaithful_disclosure: true,
+            preferred: false,
+            suspended: true,
+            etf: true,
+            etn: true,
+            no_credit: false,
+            spac: true,
+        }
+    }
+
+    /// 10자리 제외 구분 코드 (1: 제외)
+    pub fn code(&self) -> String {
+        [
+            self.investment_warning,
+            self.administrative,
+            self.liquidation,
+            self.unfaithful_disclosure,
+            self.preferred,
+            self.suspended,
+            self.etf,
+            self.etn,
+            self.no_credit,
+            self.spac,
+        ]
+        .iter()
+        .map(|excluded| if *excluded { '1' } else { '0' })
+        .collect()
+    }
+}
+
+type Query = Vec<(&'static str, String)>;
+
+fn optional(value: Option<u64>) -> String {
+    value.map(|v| v.to_string()).unwrap_or_default()
+}
+
+/// 가격/거래량 조건을 FID_INPUT_PRICE_1/2, FID_VOL_CNT로 쓰는 순위의 공통 파라미터
+fn base_query(filter: &RankingFilter, screen: &str, share_class: ShareClass) -> Query {
+    vec![
+        ("FID_COND_MRKT_DIV_CODE", "J".to_string()),
+        ("FID_COND_SCR_DIV_CODE", screen.to_string()),
+        ("FID_INPUT_ISCD", filter.market.code().to_string()),
+        ("FID_DIV_CLS_CODE", share_class.code().to_string()),
+        ("FID_INPUT_PRICE_1", optional(filter.price_min)),
+        ("FID_INPUT_PRICE_2", optional(filter.price_max)),
+        ("FID_VOL_CNT", optional(filter.min_volume)),
+        ("FID_TRGT_CLS_CODE", "0".to_string()),
+        ("FID_TRGT_EXLS_CLS_CODE", "0".to_string()),
+    ]
+}
+
+/// 가격/거래량 조건을 FID_APLY_RANG_* 로 쓰는 순위의 공통 파라미터
+fn apply_range_query(filter: &RankingFilter, screen: &str) -> Query {
+    vec![
+        ("FID_COND_MRKT_DIV_CODE", "J".to_string()),
+        ("FID_COND_SCR_DIV_CODE", screen.to_string()),
+        ("FID_INPUT_ISCD", filter.market.code().to_string()),
+        ("FID_APLY_RANG_PRC_1", optional(filter.price_min)),
+        ("FID_APLY_RANG_PRC_2", optional(filter.price_max)),
+        ("FID_APLY_RANG_VOL", optional(filter.min_volume)),
+        ("FID_TRGT_CLS_CODE", "0".to_string()),
+        ("FID_TRGT_EXLS_CLS_CODE", "0".to_string()),
+    ]
+}
+
+fn set(query: &mut Query, key: &'static str, value: String) {
+    match query.iter_mut().find(|(k, _)| *k == key) {
+        Some(entry) => entry.1 = value,
+        None => query.push((key, value)),
+    }
+}
+
+/// 거래량순위 정렬 (FID_BLNG_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VolumeRankSort {
+    #[default]
+    AverageVolume,
+    VolumeGrowth,
+    AverageTurnover,
+    TradeAmount,
+    AverageAmountTurnover,
+}
+
+impl VolumeRankSort {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::AverageVolume => "0",
+            Self::VolumeGrowth => "1",
+            Self::AverageTurnover => "2",
+            Self::TradeAmount => "3",
+            Self::AverageAmountTurnover => "4",
+        }
+    }
+}
+
+/// 거래량순위 (volume-rank)
+#[derive(Debug, Clone, Default)]
+pub struct VolumeRankRequest {
+    pub filter: RankingFilter,
+    pub share_class: ShareClass,
+    pub sort: VolumeRankSort,
+    pub exclusions: RankingExclusions,
+}
+
+impl VolumeRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(&self.filter, "20171", self.share_class);
+        // 증거금 30/40/50/60/100%, 신용보증금 30/40/50/60% 종목 모두 포함
+        set(&mut query, "FID_TRGT_CLS_CODE", "111111111".to_string());
+        set(&mut query, "FID_TRGT_EXLS_CLS_CODE", self.exclusions.code());
+        query.push(("FID_BLNG_CLS_CODE", self.sort.code().to_string()));
+        query.push(("FID_INPUT_DATE_1", String::new()));
+        query
+    }
+}
+
+/// 등락률순위 정렬 (fid_rank_sort_cls_code)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FluctuationSort {
+    #[default]
+    Rise,
+    Fall,
+    RiseFromOpen,
+    FallFromOpen,
+    Volatility,
+}
+
+impl FluctuationSort {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Rise => "0",
+            Self::Fall => "1",
+            Self::RiseFromOpen => "2",
+            Self::FallFromOpen => "3",
+            Self::Volatility => "4",
+        }
+    }
+}
+
+/// 등락률순위 (fluctuation)
+#[derive(Debug, Clone, Default)]
+pub struct FluctuationRankRequest {
+    pub filter: RankingFilter,
+    pub share_class: ShareClass,
+    pub sort: FluctuationSort,
+    /// 누적일수 (0: 당일)
+    pub days: u32,
+    /// `false`: 저가(상승)/고가(하락) 대비, `true`: 종가 대비
+    pub from_close: bool,
+    /// 등락률 하한 (%)
+    pub rate_min: Option<f64>,
+    /// 등락률 상한 (%)
+    pub rate_max: Option<f64>,
+}
+
+impl FluctuationRankRequest {
+    fn query(&self) -> Query {
+        let rate = |rate: Option<f64>| rate.map(|r| r.to_string()).unwrap_or_default();
+        let mut query = base_query(&self.filter, "20170", self.share_class);
+        query.push(("FID_RANK_SORT_CLS_CODE", self.sort.code().to_string()));
+        query.push(("FID_INPUT_CNT_1", self.days.to_string()));
+        query.push((
+            "FID_PRC_CLS_CODE",
+            if self.from_close { "1" } else { "0" }.to_string(),
+        ));
+        query.push(("FID_RSFL_RATE1", rate(self.rate_min)));
+        query.push(("FID_RSFL_RATE2", rate(self.rate_max)));
+        query
+    }
+}
+
+/// 시가총액 상위 (market-cap)
+#[derive(Debug, Clone, Default)]
+pub struct MarketCapRankRequest {
+    pub filter: RankingFilter,
+    pub share_class: ShareClass,
+}
+
+impl MarketCapRankRequest {
+    fn query(&self) -> Query {
+        base_query(&self.filter, "20174", self.share_class)
+    }
+}
+
+/// 체결강도 상위 (volume-power)
+#[derive(Debug, Clone, Default)]
+pub struct VolumePowerRankRequest {
+    pub filter: RankingFilter,
+    pub share_class: ShareClass,
+}
+
+impl VolumePowerRankRequest {
+    fn query(&self) -> Query {
+        base_query(&self.filter, "20168", self.share_class)
+    }
+}
+
+/// 신고/신저 근접 구분 (fid_prc_cls_code)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NearExtreme {
+    #[default]
+    High,
+    Low,
+}
+
+/// 신고/신저근접종목 상위 (near-new-highlow)
+#[derive(Debug, Clone, Default)]
+pub struct NearNewHighLowRankRequest {
+    pub filter: RankingFilter,
+    pub share_class: ShareClass,
+    pub extreme: NearExtreme,
+    /// 신고/신저가 대비 괴리율 하한 (%)
+    pub gap_min: Option<u64>,
+    /// 신고/신저가 대비 괴리율 상한 (%)
+    pub gap_max: Option<u64>,
+}
+
+impl NearNewHighLowRankRequest {
+    fn query(&self) -> Query {
+        let mut query = apply_range_query(&self.filter, "20187");
+        let extreme = match self.extreme {
+            NearExtreme::High => "0",
+            NearExtreme::Low => "1",
+        };
+        query.push(("FID_DIV_CLS_CODE", self.share_class.code().to_string()));
+        query.push(("FID_PRC_CLS_CODE", extreme.to_string()));
+        query.push(("FID_INPUT_CNT_1", optional(self.gap_min)));
+        query.push(("FID_INPUT_CNT_2", optional(self.gap_max)));
+        query
+    }
+}
+
+/// 배당 종류 (GB3)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DividendKind {
+    Stock,
+    #[default]
+    Cash,
+}
+
+/// 결산/중간배당 구분 (GB4)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DividendSettlement {
+    #[default]
+    All,
+    Annual,
+    Interim,
+}
+
+/// 배당률 상위 (dividend-rate)
+///
+/// 가격/거래량 조건은 쓰지 않고 `market`만 반영합니다.
+#[derive(Debug, Clone, Default)]
+pub struct DividendRateRankRequest {
+    pub market: RankingMarket,
+    pub share_class: ShareClass,
+    pub kind: DividendKind,
+    pub settlement: DividendSettlement,
+    /// 기준일 시작 (YYYYMMDD)
+    pub from: String,
+    /// 기준일 종료 (YYYYMMDD)
+    pub to: String,
+}
+
+impl DividendRateRankRequest {
+    fn query(&self) -> Result<Query, Box<dyn Error>> {
+        if self.from.is_empty() || self.to.is_empty() {
+            return Err("배당률 순위는 조회 기간(from/to)이 필요합니다".into());
+        }
+        let (market, sector) = match self.market {
+            RankingMarket::All => ("0", "0001"),
+            RankingMarket::Kospi => ("1", "0001"),
+            RankingMarket::Kospi200 => ("2", "2001"),
+            RankingMarket::Kosdaq => ("3", "1001"),
+        };
+        let share_class = match self.share_class {
+            ShareClass::All => "0",
+            ShareClass::Common => "6",
+            ShareClass::Preferred => "7",
+        };
+        let kind = match self.kind {
+            DividendKind::Stock => "1",
+            DividendKind::Cash => "2",
+        };
+        let settlement = match self.settlement {
+            DividendSettlement::All => "0",
+            DividendSettlement::Annual => "1",
+            DividendSettlement::Interim => "2",
+        };
+        Ok(vec![
+            ("CTS_AREA", String::new()),
+            ("GB1", market.to_string()),
+            ("UPJONG", sector.to_string()),
+            ("GB2", share_class.to_string()),
+            ("GB3", kind.to_string()),
+            ("F_DT", self.from.clone()),
+            ("T_DT", self.to.clone()),
+            ("GB4", settlement.to_string()),
+        ])
+    }
+}
+
+/// 공매도 집계 기간 (FID_PERIOD_DIV_CODE, FID_INPUT_CNT_1)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShortSalePeriod {
+    #[default]
+    Day1,
+    Days2,
+    Days3,
+    Days4,
+    Week1,
+    Weeks2,
+    Weeks3,
+    Month1,
+    Months2,
+    Months3,
+}
+
+impl ShortSalePeriod {
+    fn codes(&self) -> (&'static str, &'static str) {
+        match self {
+            Self::Day1 => ("D", "0"),
+            Self::Days2 => ("D", "1"),
+            Self::Days3 => ("D", "2"),
+            Self::Days4 => ("D", "3"),
+            Self::Week1 => ("D", "4"),
+            Self::Weeks2 => ("D", "9"),
+            Self::Weeks3 => ("D", "14"),
+            Self::Month1 => ("M", "1"),
+            Self::Months2 => ("M", "2"),
+            Self::Months3 => ("M", "3"),
+        }
+    }
+}
+
+/// 공매도 상위 (short-sale)
+#[derive(Debug, Clone, Default)]
+pub struct ShortSaleRankRequest {
+    pub filter: RankingFilter,
+    pub period: ShortSalePeriod,
+}
+
+impl ShortSaleRankRequest {
+    fn query(&self) -> Query {
+        let (period, count) = self.period.codes();
+        let mut query = apply_range_query(&self.filter, "20482");
+        query.push(("FID_PERIOD_DIV_CODE", period.to_string()));
+        query.push(("FID_INPUT_CNT_1", count.to_string()));
+        query
+    }
+}
+
+/// 이격도 기준 이동평균 (fid_hour_cls_code)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DisparityDays {
+    D5,
+    D10,
+    #[default]
+    D20,
+    D60,
+    D120,
+}
+
+impl DisparityDays {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::D5 => "5",
+            Self::D10 => "10",
+            Self::D20 => "20",
+            Self::D60 => "60",
+            Self::D120 => "120",
+        }
+    }
+}
+
+/// 이격도 순위 (disparity)
+#[derive(Debug, Clone, Default)]
+pub struct DisparityRankRequest {
+    pub filter: RankingFilter,
+    pub share_class: ShareClass,
+    pub days: DisparityDays,
+    /// `false`: 이격도 상위, `true`: 이격도 하위
+    pub lowest: bool,
+}
+
+impl DisparityRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(&self.filter, "20178", self.share_class);
+        query.push((
+            "FID_RANK_SORT_CLS_CODE",
+            if self.lowest { "1" } else { "0" }.to_string(),
+        ));
+        query.push(("FID_HOUR_CLS_CODE", self.days.code().to_string()));
+        query
+    }
+}
+
+/// 순위 응답 공통 필드
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankingQuote {
+    /// 단축종목코드
+    #[serde(alias = "stck_shrn_iscd")]
+    pub mksc_shrn_iscd: String,
+    pub hts_kor_isnm: String,
+    pub data_rank: String,
+    pub stck_prpr: String,
+    pub prdy_vrss_sign: String,
+    pub prdy_vrss: String,
+    pub prdy_ctrt: String,
+    pub acml_vol: String,
+}
+
+/// 거래량순위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumeRankRow {
+    #[serde(flatten)]
+    pub quote: RankingQuote,
+    pub prdy_vol: String,
+    /// 상장주수
+    pub lstn_stcn: String,
+    pub avrg_vol: String,
+    /// N일전종가대비현재가대비율
+    pub n_befr_clpr_vrss_prpr_rate: String,
+    /// 거래량증가율
+    pub vol_inrt: String,
+    /// 거래량회전율
+    pub vol_tnrt: String,
+    pub nday_vol_tnrt: String,
+    pub avrg_tr_pbmn: String,
+    pub tr_pbmn_tnrt: String,
+    pub nday_tr_pbmn_tnrt: String,
+    pub acml_tr_pbmn: String,
+}
+
+/// 등락률순위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FluctuationRankRow {
+    #[serde(flatten)]
+    pub quote: RankingQuote,
+    pub stck_hgpr: String,
+    pub hgpr_hour: String,
+    pub acml_hgpr_date: String,
+    pub stck_lwpr: String,
+    pub lwpr_hour: String,
+    pub acml_lwpr_date: String,
+    /// 저가대비현재가비율
+    pub lwpr_vrss_prpr_rate: String,
+    /// 지정일종가대비현재가비율
+    pub dsgt_date_clpr_vrss_prpr_rate: String,
+    /// 연속상승일수
+    pub cnnt_ascn_dynu: String,
+    pub hgpr_vrss_prpr_rate: String,
+    /// 연속하락일수
+    pub cnnt_down_dynu: String,
+    pub oprc_vrss_prpr_sign: String,
+    pub oprc_vrss_prpr: String,
+    pub oprc_vrss_prpr_rate: String,
+    /// 기간등락
+    pub prd_rsfl: String,
+    pub prd_rsfl_rate: String,
+}
+
+/// 시가총액 상위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketCapRankRow {
+    #[serde(flatten)]
+    pub quote: RankingQuote,
+    pub lstn_stcn: String,
+    /// 시가총액 (억원)
+    pub stck_avls: String,
+    /// 시장전체시가총액비중
+    pub mrkt_whol_avls_rlim: String,
+}
+
+/// 체결강도 상위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumePowerRankRow {
+    #[serde(flatten)]
+    pub quote: RankingQuote,
+    /// 당일체결강도
+    pub tday_rltv: String,
+    /// 매도체결량합계
+    pub seln_cnqn_smtn: String,
+    /// 매수체결량합계
+    pub shnu_cnqn_smtn: String,
+}
+
+/// 신고/신저근접종목 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NearNewHighLowRankRow {
+    #[serde(flatten)]
+    pub quote: RankingQuote,
+    pub askp: String,
+    pub askp_rsqn1: String,
+    pub bidp: String,
+    pub bidp_rsqn1: String,
+    pub new_hgpr: String,
+    /// 신고가근접비율
+    pub hprc_near_rate: String,
+    pub new_lwpr: String,
+    /// 신저가근접비율
+    pub lwpr_near_rate: String,
+    /// 기준가
+    pub stck_sdpr: String,
+}
+
+/// 배당률 상위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DividendRateRankRow {
+    pub rank: String,
+    pub sht_cd: String,
+    pub isin_name: String,
+    /// 기준일
+    pub record_date: String,
+    /// 주당배당금
+    pub per_sto_divi_amt: String,
+    /// 배당률
+    pub divi_rate: String,
+    pub divi_kind: String,
+}
+
+/// 공매도 상위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ShortSaleRankRow {
+    #[serde(flatten)]
+    pub quote: RankingQuote,
+    pub acml_tr_pbmn: String,
+    /// 공매도체결수량
+    pub ssts_cntg_qty: String,
+    /// 공매도거래량비중
+    pub ssts_vol_rlim: String,
+    /// 공매도거래대금
+    pub ssts_tr_pbmn: String,
+    pub ssts_tr_pbmn_rlim: String,
+    pub stnd_date1: String,
+    pub stnd_date2: String,
+    /// 공매도평균가격
+    pub avrg_prc: String,
+}
+
+/// 이격도 순위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisparityRankRow {
+    #[serde(flatten)]
+    pub quote: RankingQuote,
+    pub d5_dsrt: String,
+    pub d10_dsrt: String,
+    pub d20_dsrt: String,
+    pub d60_dsrt: String,
+    pub d120_dsrt: String,
+}
+
+/// 타입 요청/응답 행으로 조회하는 국내주식 순위
+///
+/// 순위 API 22개 중 아래 8개만 다룹니다. 예상체결 상승/하락, 호가잔량, 신용잔고, 시간외 거래량/등락률,
+/// 시간외잔량, HTS조회상위, 수익자산지표, 우선주 괴리율, 대량체결건수, 재무비율, 당사매매종목,
+/// 시장가치, 관심종목등록 상위는 [`DomesticAnalysis::get_ranking_raw`]로 조회합니다.
+#[async_trait]
+pub trait DomesticRankings {
+    /// 거래량순위
+    async fn get_volume_rank(
+        &self,
+        request: &VolumeRankRequest,
+    ) -> Result<ApiResponse<Vec<VolumeRankRow>>, Box<dyn Error>>;
+    /// 등락률순위
+    async fn get_fluctuation_rank(
+        &self,
+        request: &FluctuationRankRequest,
+    ) -> Result<ApiResponse<Vec<FluctuationRankRow>>, Box<dyn Error>>;
+    /// 시가총액 상위
+    async fn get_market_cap_rank(
+        &self,
+        request: &MarketCapRankRequest,
+    ) -> Result<ApiResponse<Vec<MarketCapRankRow>>, Box<dyn Error>>;
+    /// 체결강도 상위
+    async fn get_volume_power_rank(
+        &self,
+        request: &VolumePowerRankRequest,
+    ) -> Result<ApiResponse<Vec<VolumePowerRankRow>>, Box<dyn Error>>;
+    /// 신고/신저근접종목 상위
+    async fn get_near_new_high_low_rank(
+        &self,
+        request: &NearNewHighLowRankRequest,
+    ) -> Result<ApiResponse<Vec<NearNewHighLowRankRow>>, Box<dyn Error>>;
+    /// 배당률 상위
+    async fn get_dividend_rate_rank(
+        &self,
+        request: &DividendRateRankRequest,
+    ) -> Result<ApiResponse<Vec<DividendRateRankRow>>, Box<dyn Error>>;
+    /// 공매도 상위
+    async fn get_short_sale_rank(
+        &self,
+        request: &ShortSaleRankRequest,
+    ) -> Result<ApiResponse<Vec<ShortSaleRankRow>>, Box<dyn Error>>;
+    /// 이격도 순위
+    async fn get_disparity_rank(
+        &self,
+        request: &DisparityRankRequest,
+    ) -> Result<ApiResponse<Vec<DisparityRankRow>>, Box<dyn Error>>;
+}
+
+/// 순위 조회 후 행 목록으로 변환 (배당률 상위는 output1, 나머지는 output)
+async fn get_ranking<R: DeserializeOwned>(
+    provider: &KISProvider,
+    endpoint: DomesticRankingEndpoint,
+    query: Query,
+) -> Result<ApiResponse<Vec<R>>, Box<dyn Error>> {
+    let refs: Vec<(&str, &str)> = query
+        .iter()
+        .map(|(key, value)| (*key, value.as_str()))
+        .collect();
+    let response = provider.get_ranking_raw(endpoint, &refs).await?;
+    let key = if response.body.extra.contains_key("output") {
+        "output"
+    } else {
+        "output1"
+    };
+    Ok(ApiResponse {
+        body: response.body.rows_as(key)?,
+        headers: response.headers,
+    })
+}
+
+#[async_trait]
+impl DomesticRankings for KISProvider {
+    async fn get_volume_rank(
+        &self,
+        request: &VolumeRankRequest,
+    ) -> Result<ApiResponse<Vec<VolumeRankRow>>, Box<dyn Error>> {
+        get_ranking(self, DomesticRankingEndpoint::VolumeRank, request.query()).await
+    }
+
+    async fn get_fluctuation_rank(
+        &self,
+        request: &FluctuationRankRequest,
+    ) -> Result<ApiResponse<Vec<FluctuationRankRow>>, Box<dyn Error>> {
+        get_ranking(self, DomesticRankingEndpoint::Fluctuation, request.query()).await
+    }
+
+    async fn get_market_cap_rank(
+        &self,
+        request: &MarketCapRankRequest,
+    ) -> Result<ApiResponse<Vec<MarketCapRankRow>>, Box<dyn Error>> {
+        get_ranking(self, DomesticRankingEndpoint::MarketCap, request.query()).await
+    }
+
+    async fn get_volume_power_rank(
+        &self,
+        request: &VolumePowerRankRequest,
+    ) -> Result<ApiResponse<Vec<VolumePowerRankRow>>, Box<dyn Error>> {
+        get_ranking(self, DomesticRankingEndpoint::VolumePower, request.query()).await
+    }
+
+    async fn get_near_new_high_low_rank(
+        &self,
+        request: &NearNewHighLowRankRequest,
+    ) -> Result<ApiResponse<Vec<NearNewHighLowRankRow>>, Box<dyn Error>> {
+        get_ranking(
+            self,
+            DomesticRankingEndpoint::NearNewHighLow,
+            request.query(),
+        )
+        .await
+    }
+
+    async fn get_dividend_rate_rank(
+        &self,
+        request: &DividendRateRankRequest,
+    ) -> Result<ApiResponse<Vec<DividendRateRankRow>>, Box<dyn Error>> {
+        let query = request.query()?;
+        get_ranking(self, DomesticRankingEndpoint::DividendRate, query).await
+    }
+
+    async fn get_short_sale_rank(
+        &self,
+        request: &ShortSaleRankRequest,
+    ) -> Result<ApiResponse<Vec<ShortSaleRankRow>>, Box<dyn Error>> {
+        get_ranking(self, DomesticRankingEndpoint::ShortSale, request.query()).await
+    }
+
+    async fn get_disparity_rank(
+        &self,
+        request: &DisparityRankRequest,
+    ) -> Result<ApiResponse<Vec<DisparityRankRow>>, Box<dyn Error>> {
+        get_ranking(self, DomesticRankingEndpoint::Disparity, request.query()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pairs(query: &Query) -> Vec<(&str, &str)> {
+        query
+            .iter()
+            .map(|(key, value)| (*key, value.as_str()))
+            .collect()
+    }
+
+    fn kosdaq_filter() -> RankingFilter {
+        RankingFilter {
+            market: RankingMarket::Kosdaq,
+            price_min: Some(1_000),
+            price_max: Some(50_000),
+            min_volume: Some(100_000),
+        }
+    }
+
+    #[test]
+    fn exclusions_code_is_ten_flags() {
+        assert_eq!(RankingExclusions::default().code(), "0000000000");
+        assert_eq!(RankingExclusions::common_stocks().code(), "1111011101");
+    }
+
+    #[test]
+    fn volume_rank_query() {
+        let request = VolumeRankRequest {
+            filter: kosdaq_filter(),
+            share_class: ShareClass::Common,
+            sort: VolumeRankSort::VolumeGrowth,
+            exclusions: RankingExclusions::common_stocks(),
+        };
+        assert_eq!(
+            pairs(&request.query()),
+            [
+                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_SCR_DIV_CODE", "20171"),
+                ("FID_INPUT_ISCD", "1001"),
+                ("FID_DIV_CLS_CODE", "1"),
+                ("FID_INPUT_PRICE_1", "1000"),
+                ("FID_INPUT_PRICE_2", "50000"),
+                ("FID_VOL_CNT", "100000"),
+                ("FID_TRGT_CLS_CODE", "111111111"),
+                ("FID_TRGT_EXLS_CLS_CODE", "1111011101"),
+                ("FID_BLNG_CLS_CODE", "1"),
+                ("FID_INPUT_DATE_1", ""),
+            ]
+        );
+    }
+
+    #[test]
+    fn fluctuation_rank_query() {
+        let request = FluctuationRankRequest {
+            sort: FluctuationSort::Fall,
+            days: 5,
+            from_close: true,
+            rate_min: Some(-30.0),
+            rate_max: Some(-5.5),
+            ..Default::default()
+        };
+        assert_eq!(
+            pairs(&request.query()),
+            [
+                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_SCR_DIV_CODE", "20170"),
+                ("FID_INPUT_ISCD", "0000"),
+                ("FID_DIV_CLS_CODE", "0"),
+                ("FID_INPUT_PRICE_1", ""),
+                ("FID_INPUT_PRICE_2", ""),
+                ("FID_VOL_CNT", ""),
+                ("FID_TRGT_CLS_CODE", "0"),
+                ("FID_TRGT_EXLS_CLS_CODE", "0"),
+                ("FID_RANK_SORT_CLS_CODE", "1"),
+                ("FID_INPUT_CNT_1", "5"),
+                ("FID_PRC_CLS_CODE", "1"),
+                ("FID_RSFL_RATE1", "-30"),
+                ("FID_RSFL_RATE2", "-5.5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn screen_codes() {
+        let filter = kosdaq_filter();
+        let screen = |query: Query| {
+            query
+                .into_iter()
+                .find(|(key, _)| *key == "FID_COND_SCR_DIV_CODE")
+                .map(|(_, value)| value)
+                .unwrap()
+        };
+        let market_cap = MarketCapRankRequest {
+            filter,
+            share_class: ShareClass::Preferred,
+        };
+        assert_eq!(screen(market_cap.query()), "20174");
+        assert_eq!(
+            pairs(&market_cap.query())[..4],
+            [
+                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_SCR_DIV_CODE", "20174"),
+                ("FID_INPUT_ISCD", "1001"),
+                ("FID_DIV_CLS_CODE", "2"),
+            ]
+        );
+        let volume_power = VolumePowerRankRequest {
+            filter,
+            share_class: ShareClass::All,
+        };
+        assert_eq!(screen(volume_power.query()), "20168");
+    }
+
+    #[test]
+    fn near_new_high_low_query() {
+        let request = NearNewHighLowRankRequest {
+            filter: kosdaq_filter(),
+            share_class: ShareClass::All,
+            extreme: NearExtreme::Low,
+            gap_min: Some(0),
+            gap_max: Some(5),
+        };
+        assert_eq!(
+            pairs(&request.query()),
+            [
+                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_SCR_DIV_CODE", "20187"),
+                ("FID_INPUT_ISCD", "1001"),
+                ("FID_APLY_RANG_PRC_1", "1000"),
+                ("FID_APLY_RANG_PRC_2", "50000"),
+                ("FID_APLY_RANG_VOL", "100000"),
+                ("FID_TRGT_CLS_CODE", "0"),
+                ("FID_TRGT_EXLS_CLS_CODE", "0"),
+                ("FID_DIV_CLS_CODE", "0"),
+                ("FID_PRC_CLS_CODE", "1"),
+                ("FID_INPUT_CNT_1", "0"),
+                ("FID_INPUT_CNT_2", "5"),
+            ]
+        );
+    }
+
+    #[test]
+    fn dividend_rate_query() {
+        let mut request = DividendRateRankRequest {
+            market: RankingMarket::Kosdaq,
+            share_class: ShareClass::Preferred,
+            kind: DividendKind::Cash,
+            settlement: DividendSettlement::Interim,
+            ..Default::default()
+        };
+        assert!(request.query().is_err());
+
+        request.from = "20240101".to_string();
+        request.to = "20241231".to_string();
+        assert_eq!(
+            pairs(&request.query().unwrap()),
+            [
+                ("CTS_AREA", ""),
+                ("GB1", "3"),
+                ("UPJONG", "1001"),
+                ("GB2", "7"),
+                ("GB3", "2"),
+                ("F_DT", "20240101"),
+                ("T_DT", "20241231"),
+                ("GB4", "2"),
+            ]
+        );
+    }
+
+    #[test]
+    fn short_sale_query() {
+        let request = ShortSaleRankRequest {
+            filter: RankingFilter::default(),
+            period: ShortSalePeriod::Weeks2,
+        };
+        let query = request.query();
+        assert_eq!(
+            pairs(&query)[8..],
+            [("FID_PERIOD_DIV_CODE", "D"), ("FID_INPUT_CNT_1", "9")]
+        );
+        assert_eq!(pairs(&query)[1], ("FID_COND_SCR_DIV_CODE", "20482"));
+
+        let request = ShortSaleRankRequest {
+            period: ShortSalePeriod::Months3,
+            ..request
+        };
+        assert_eq!(
+            pairs(&request.query())[8..],
+            [("FID_PERIOD_DIV_CODE", "M"), ("FID_INPUT_CNT_1", "3")]
+        );
+    }
+
+    #[test]
+    fn disparity_query() {
+        let request = DisparityRankRequest {
+            filter: kosdaq_filter(),
+            share_class: ShareClass::Common,
+            days: DisparityDays::D60,
+            lowest: true,
+        };
+        let query = request.query();
+        assert_eq!(pairs(&query)[1], ("FID_COND_SCR_DIV_CODE", "20178"));
+        assert_eq!(
+            pairs(&query)[9..],
+            [("FID_RANK_SORT_CLS_CODE", "1"), ("FID_HOUR_CLS_CODE", "60")]
+        );
+    }
+}
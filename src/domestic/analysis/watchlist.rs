@@ -0,0 +1,276 @@
+use super::{DomesticAnalysis, DomesticAnalysisEndpoint};
+use crate::provider::KISProvider;
+use crate::utils::ApiResponse;
+use crate::websocket::{RealtimeClient, RealtimeData, RealtimeUniverse};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::error::Error;
+
+/// HTS 관심종목 그룹
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchlistGroup {
+    pub data_rank: String,
+    /// 관심그룹코드 (종목조회 `INTER_GRP_CODE`)
+    pub inter_grp_code: String,
+    pub inter_grp_name: String,
+    /// 등록 종목 수
+    pub ask_cnt: String,
+    pub date: String,
+    pub trnm_hour: String,
+}
+
+/// HTS 관심종목 그룹의 종목
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WatchlistMember {
+    /// 시장구분 (J: 주식)
+    pub fid_mrkt_cls_code: String,
+    pub data_rank: String,
+    pub exch_code: String,
+    /// 종목코드
+    pub jong_code: String,
+    pub color_code: String,
+    pub memo: String,
+    pub hts_kor_isnm: String,
+    /// 고정기간순매수수량
+    pub fxdt_ntby_qty: String,
+    /// 체결단가
+    pub cntg_unpr: String,
+    pub cntg_cls_code: String,
+}
+
+impl WatchlistMember {
+    /// 국내 주식 종목인지 (실시간 주식 체결/호가 구독 대상)
+    pub fn is_stock(&self) -> bool {
+        self.fid_mrkt_cls_code.is_empty() || self.fid_mrkt_cls_code == "J"
+    }
+}
+
+/// 이전 조회 대비 관심종목 변경 내역 (종목코드 기준)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WatchlistDiff {
+    pub added: Vec<WatchlistMember>,
+    pub removed: Vec<WatchlistMember>,
+}
+
+impl WatchlistDiff {
+    pub fn between(previous: &[WatchlistMember], current: &[WatchlistMember]) -> Self {
+        let codes = |members: &[WatchlistMember]| -> BTreeSet<String> {
+            members
+                .iter()
+                .map(|member| member.jong_code.clone())
+                .collect()
+        };
+        let (before, after) = (codes(previous), codes(current));
+        Self {
+            added: current
+                .iter()
+                .filter(|member| !before.contains(&member.jong_code))
+                .cloned()
+                .collect(),
+            removed: previous
+                .iter()
+                .filter(|member| !after.contains(&member.jong_code))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[async_trait]
+pub trait DomesticWatchlist {
+    /// 관심종목 그룹 목록 (HTS ID 기준)
+    async fn list_watchlist_groups(
+        &self,
+        user_id: &str,
+    ) -> Result<ApiResponse<Vec<WatchlistGroup>>, Box<dyn Error>>;
+    /// 관심종목 그룹의 종목 목록
+    async fn list_watchlist_members(
+        &self,
+        user_id: &str,
+        group_code: &str,
+    ) -> Result<ApiResponse<Vec<WatchlistMember>>, Box<dyn Error>>;
+}
+
+#[async_trait]
+impl DomesticWatchlist for KISProvider {
+    async fn list_watchlist_groups(
+        &self,
+        user_id: &str,
+    ) -> Result<ApiResponse<Vec<WatchlistGroup>>, Box<dyn Error>> {
+        let response = self
+            .get_analysis_raw(
+                DomesticAnalysisEndpoint::InterestGroupList,
+                &[
+                    ("TYPE", "1"),
+                    ("FID_ETC_CLS_CODE", "00"),
+                    ("USER_ID", user_id),
+                ],
+            )
+            .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn list_watchlist_members(
+        &self,
+        user_id: &str,
+        group_code: &str,
+    ) -> Result<ApiResponse<Vec<WatchlistMember>>, Box<dyn Error>> {
+        let response = self
+            .get_analysis_raw(
+                DomesticAnalysisEndpoint::InterestStocksByGroup,
+                &[
+                    ("TYPE", "1"),
+                    ("USER_ID", user_id),
+                    ("DATA_RANK", ""),
+                    ("INTER_GRP_CODE", group_code),
+                    ("INTER_GRP_NAME", ""),
+                    ("HTS_KOR_ISNM", ""),
+                    ("CNTG_CLS_CODE", ""),
+                    ("FID_ETC_CLS_CODE", "4"),
+                ],
+            )
+            .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
+}
+
+/// HTS 관심종목 그룹 하나를 따라가며 변경 내역을 계산
+///
+/// HTS에서 그룹 종목을 편집하면 다음 [`Self::refresh`]에서 추가/삭제로 나옵니다.
+/// 첫 조회는 그룹 전체가 추가로 나옵니다.
+#[derive(Debug, Clone)]
+pub struct WatchlistTracker {
+    user_id: String,
+    group_code: String,
+    members: Vec<WatchlistMember>,
+}
+
+impl WatchlistTracker {
+    pub fn new(user_id: &str, group_code: &str) -> Self {
+        Self {
+            user_id: user_id.to_string(),
+            group_code: group_code.to_string(),
+            members: Vec::new(),
+        }
+    }
+
+    /// 그룹 이름으로 그룹을 찾아 생성
+    pub async fn by_name(
+        provider: &KISProvider,
+        user_id: &str,
+        group_name: &str,
+    ) -> Result<Self, Box<dyn Error>> {
+        let groups = provider.list_watchlist_groups(user_id).await?;
+        let group = groups
+            .body
+            .iter()
+            .find(|group| group.inter_grp_name.trim() == group_name)
+            .ok_or_else(|| format!("관심종목 그룹을 찾을 수 없습니다: {group_name}"))?;
+        Ok(Self::new(user_id, &group.inter_grp_code))
+    }
+
+    pub fn group_code(&self) -> &str {
+        &self.group_code
+    }
+
+    /// 마지막으로 조회한 그룹 종목
+    pub fn members(&self) -> &[WatchlistMember] {
+        &self.members
+    }
+
+    /// 그룹을 다시 조회하고 이전 조회 대비 변경 내역을 돌려줌
+    pub async fn refresh(
+        &mut self,
+        provider: &KISProvider,
+    ) -> Result<WatchlistDiff, Box<dyn Error>> {
+        let current = provider
+            .list_watchlist_members(&self.user_id, &self.group_code)
+            .await?
+            .body;
+        let diff = WatchlistDiff::between(&self.members, &current);
+        self.members = current;
+        Ok(diff)
+    }
+
+    /// 그룹을 다시 조회하고 실시간 구독을 그룹의 주식 종목과 같게 맞춤
+    ///
+    /// 변경 내역은 그룹 기준이며, 주식이 아닌 종목(업종 등)은 구독하지 않습니다.
+    pub async fn sync_realtime<T: RealtimeData + Clone + Send + Sync + 'static>(
+        &mut self,
+        provider: &KISProvider,
+        client: &RealtimeClient,
+        universe: &mut RealtimeUniverse<T>,
+    ) -> Result<WatchlistDiff, Box<dyn Error>> {
+        let diff = self.refresh(provider).await?;
+        let symbols = self
+            .members
+            .iter()
+            .filter(|member| member.is_stock())
+            .map(|member| member.jong_code.as_str());
+        universe.sync(client, symbols).await?;
+        Ok(diff)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(code: &str, name: &str) -> WatchlistMember {
+        WatchlistMember {
+            jong_code: code.to_string(),
+            hts_kor_isnm: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn codes(members: &[WatchlistMember]) -> Vec<&str> {
+        members
+            .iter()
+            .map(|member| member.jong_code.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn diff_between_lists() {
+        let previous = [member("005930", "삼성전자"), member("000660", "SK하이닉스")];
+        let current = [
+            member("000660", "SK하이닉스"),
+            member("035420", "NAVER"),
+            member("035720", "카카오"),
+        ];
+        let diff = WatchlistDiff::between(&previous, &current);
+        assert_eq!(codes(&diff.added), ["035420", "035720"]);
+        assert_eq!(codes(&diff.removed), ["005930"]);
+        assert_eq!(diff.removed[0].hts_kor_isnm, "삼성전자");
+    }
+
+    #[test]
+    fn diff_ignores_order_and_renames() {
+        let previous = [member("005930", "삼성전자"), member("000660", "SK하이닉스")];
+        let current = [member("000660", "SK hynix"), member("005930", "삼성전자")];
+        assert!(WatchlistDiff::between(&previous, &current).is_empty());
+    }
+
+    #[test]
+    fn first_diff_adds_everything() {
+        let current = [member("005930", "삼성전자"), member("0001", "코스피")];
+        let diff = WatchlistDiff::between(&[], &current);
+        assert_eq!(codes(&diff.added), ["005930", "0001"]);
+        assert!(diff.removed.is_empty());
+        assert!(WatchlistDiff::between(&current, &[]).added.is_empty());
+    }
+}
@@ -0,0 +1,933 @@
+use super::get_quotation;
+use crate::domestic::analysis::RankingMarket;
+use crate::provider::KISProvider;
+use crate::types::{Level, MarketDivision, Orderbook, OrderbookTotals};
+use crate::utils::{ApiEndpoint, ApiResponse, TrId, json_num, json_str};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+// ======================================================
+// ETF/ETN, 업종지수, 시간외, VI, 장마감 예상체결가
+// ======================================================
+
+#[async_trait]
+pub trait DomesticMarketQuotations {
+    /// ETF/ETN 현재가
+    async fn get_etf_price(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<EtfPrice>, Box<dyn Error>>;
+    /// NAV 비교추이(일): `from`~`to` (YYYYMMDD)
+    async fn get_nav_daily_trend(
+        &self,
+        market: MarketDivision,
+        code: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<ApiResponse<Vec<NavDailyRow>>, Box<dyn Error>>;
+    /// NAV 비교추이(분): `interval_seconds` 60, 180, 300, 600 ...
+    async fn get_nav_minute_trend(
+        &self,
+        market: MarketDivision,
+        code: &str,
+        interval_seconds: &str,
+    ) -> Result<ApiResponse<Vec<NavMinuteRow>>, Box<dyn Error>>;
+    /// ETF 구성종목시세
+    async fn get_etf_components(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<EtfComponents>, Box<dyn Error>>;
+    /// 업종 기간별 시세: `period` D/W/M/Y
+    async fn get_index_daily_chart(
+        &self,
+        market: MarketDivision,
+        index_code: &str,
+        from: &str,
+        to: &str,
+        period: &str,
+    ) -> Result<ApiResponse<Vec<IndexChartRow>>, Box<dyn Error>>;
+    /// 업종 분봉: `interval_seconds` 30, 60, 600, 3600
+    async fn get_index_minute_chart(
+        &self,
+        market: MarketDivision,
+        index_code: &str,
+        interval_seconds: &str,
+        include_past: bool,
+    ) -> Result<ApiResponse<Vec<IndexMinuteRow>>, Box<dyn Error>>;
+    /// 업종 시간별지수: `interval_seconds` 60, 300, 600 ...
+    async fn get_index_time_price(
+        &self,
+        market: MarketDivision,
+        index_code: &str,
+        interval_seconds: &str,
+    ) -> Result<ApiResponse<Vec<IndexTimeRow>>, Box<dyn Error>>;
+    /// 시간외 단일가 현재가
+    async fn get_after_hours_price(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<AfterHoursPrice>, Box<dyn Error>>;
+    /// 시간외 단일가 호가
+    async fn get_after_hours_orderbook(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<Orderbook>, Box<dyn Error>>;
+    /// 변동성완화장치(VI) 발동 현황: `date` 영업일(YYYYMMDD), `stock_code` 빈 문자열이면 전체 종목
+    async fn get_vi_status(
+        &self,
+        date: &str,
+        market: ViMarket,
+        direction: ViDirection,
+        kind: ViKind,
+        stock_code: &str,
+    ) -> Result<ApiResponse<Vec<ViStatusRow>>, Box<dyn Error>>;
+    /// 장마감 예상체결가: `extended_only`면 종가범위연장 종목만
+    async fn get_expected_closing_prices(
+        &self,
+        market: MarketDivision,
+        index: RankingMarket,
+        sort: ExpectedClosingSort,
+        extended_only: bool,
+    ) -> Result<ApiResponse<Vec<ExpectedClosingRow>>, Box<dyn Error>>;
+    /// 국내휴장일조회: 기준일(YYYYMMDD)부터의 영업일/개장일 여부
+    ///
+    /// 원장 서비스 부하로 한국투자증권은 1일 1회 호출을 권장합니다.
+    async fn get_holidays(
+        &self,
+        base_date: &str,
+    ) -> Result<ApiResponse<Vec<HolidayRow>>, Box<dyn Error>>;
+}
+
+// ETF/ETN 현재가
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EtfPrice {
+    /// 현재가
+    pub stck_prpr: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 전일거래량
+    pub prdy_vol: String,
+    /// 상한가
+    pub stck_mxpr: String,
+    /// 하한가
+    pub stck_llam: String,
+    /// 시가
+    pub stck_oprc: String,
+    /// 고가
+    pub stck_hgpr: String,
+    /// 저가
+    pub stck_lwpr: String,
+    /// NAV
+    pub nav: String,
+    /// NAV 전일대비
+    pub nav_prdy_vrss: String,
+    /// NAV 전일대비부호
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일대비율
+    pub nav_prdy_ctrt: String,
+    /// 추적오차율
+    pub trc_errt: String,
+    /// 괴리율
+    pub dprt: String,
+    /// 기준가
+    pub stck_sdpr: String,
+    /// 상장주수
+    pub etf_crcl_stcn: String,
+    /// 순자산총액
+    pub etf_ntas_ttam: String,
+    /// 추종지수 대비율
+    pub etf_trc_ertt: String,
+    /// 최초상장일
+    pub lstn_date: String,
+}
+
+// NAV 비교추이(일)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NavDailyRow {
+    /// 영업일자
+    pub stck_bsop_date: String,
+    /// 종가
+    pub stck_clpr: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 체결거래량
+    pub cntg_vol: String,
+    /// 괴리율
+    pub dprt: String,
+    /// NAV 대비 현재가
+    pub nav_vrss_prpr: String,
+    /// NAV
+    pub nav: String,
+    /// NAV 전일대비부호
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일대비
+    pub nav_prdy_vrss: String,
+    /// NAV 전일대비율
+    pub nav_prdy_ctrt: String,
+}
+
+// NAV 비교추이(분)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NavMinuteRow {
+    /// 영업시간 (HHMMSS)
+    pub bsop_hour: String,
+    /// NAV
+    pub nav: String,
+    /// NAV 전일대비부호
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일대비
+    pub nav_prdy_vrss: String,
+    /// NAV 전일대비율
+    pub nav_prdy_ctrt: String,
+    /// NAV 대비 현재가
+    pub nav_vrss_prpr: String,
+    /// 괴리율
+    pub dprt: String,
+    /// 현재가
+    pub stck_prpr: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 체결거래량
+    pub cntg_vol: String,
+}
+
+// ETF 구성종목시세
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EtfComponentSummary {
+    /// ETF 현재가
+    pub stck_prpr: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// NAV
+    pub nav: String,
+    /// NAV 전일대비부호
+    pub nav_prdy_vrss_sign: String,
+    /// NAV 전일대비
+    pub nav_prdy_vrss: String,
+    /// NAV 전일대비율
+    pub nav_prdy_ctrt: String,
+    /// 순자산총액
+    pub etf_ntas_ttam: String,
+    /// 전일종가 NAV
+    pub prdy_clpr_nav: String,
+    /// 시가 NAV
+    pub oprc_nav: String,
+    /// 고가 NAV
+    pub hprc_nav: String,
+    /// 저가 NAV
+    pub lprc_nav: String,
+    /// CU 단위 증권수
+    pub etf_cu_unit_scrt_cnt: String,
+    /// 구성종목수
+    pub etf_cnfg_issu_cnt: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EtfComponent {
+    /// 종목코드
+    pub stck_shrn_iscd: String,
+    /// 종목명
+    pub hts_kor_isnm: String,
+    /// 현재가
+    pub stck_prpr: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+    /// 당일 등락률
+    pub tday_rsfl_rate: String,
+    /// 전일대비 거래량
+    pub prdy_vrss_vol: String,
+    /// 거래대금 회전율
+    pub tr_pbmn_tnrt: String,
+    /// 시가총액
+    pub hts_avls: String,
+    /// 구성종목 시가총액
+    pub etf_cnfg_issu_avls: String,
+    /// 구성종목 비중
+    pub etf_cnfg_issu_rlim: String,
+    /// 평가금액
+    pub etf_vltn_amt: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EtfComponents {
+    pub summary: EtfComponentSummary,
+    pub components: Vec<EtfComponent>,
+}
+
+// 업종 기간별 시세
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexChartRow {
+    /// 영업일자
+    pub stck_bsop_date: String,
+    /// 업종지수 현재가(종가)
+    pub bstp_nmix_prpr: String,
+    /// 시가
+    pub bstp_nmix_oprc: String,
+    /// 고가
+    pub bstp_nmix_hgpr: String,
+    /// 저가
+    pub bstp_nmix_lwpr: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+    /// 변경여부
+    pub mod_yn: String,
+}
+
+// 업종 분봉
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexMinuteRow {
+    /// 영업일자
+    pub stck_bsop_date: String,
+    /// 체결시간
+    pub stck_cntg_hour: String,
+    /// 업종지수 현재가
+    pub bstp_nmix_prpr: String,
+    /// 시가
+    pub bstp_nmix_oprc: String,
+    /// 고가
+    pub bstp_nmix_hgpr: String,
+    /// 저가
+    pub bstp_nmix_lwpr: String,
+    /// 체결거래량
+    pub cntg_vol: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+}
+
+// 업종 시간별지수
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct IndexTimeRow {
+    /// 영업시간
+    pub bsop_hour: String,
+    /// 업종지수 현재가
+    pub bstp_nmix_prpr: String,
+    /// 전일대비
+    pub bstp_nmix_prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    pub bstp_nmix_prdy_ctrt: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 체결거래량
+    pub cntg_vol: String,
+}
+
+// 시간외 단일가 현재가
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AfterHoursPrice {
+    /// 업종명
+    pub bstp_kor_isnm: String,
+    /// 관리종목 구분명
+    pub mang_issu_cls_name: String,
+    /// 시간외 현재가
+    pub ovtm_untp_prpr: String,
+    /// 시간외 전일대비
+    pub ovtm_untp_prdy_vrss: String,
+    /// 시간외 전일대비부호
+    pub ovtm_untp_prdy_vrss_sign: String,
+    /// 시간외 전일대비율
+    pub ovtm_untp_prdy_ctrt: String,
+    /// 시간외 거래량
+    pub ovtm_untp_vol: String,
+    /// 시간외 거래대금
+    pub ovtm_untp_tr_pbmn: String,
+    /// 시간외 상한가
+    pub ovtm_untp_mxpr: String,
+    /// 시간외 하한가
+    pub ovtm_untp_llam: String,
+    /// 시간외 시가
+    pub ovtm_untp_oprc: String,
+    /// 시간외 고가
+    pub ovtm_untp_hgpr: String,
+    /// 시간외 저가
+    pub ovtm_untp_lwpr: String,
+    /// 시간외 예상체결가
+    pub ovtm_untp_antc_cnpr: String,
+    /// 시간외 예상체결 대비
+    pub ovtm_untp_antc_cntg_vrss: String,
+    /// 시간외 예상체결 대비부호
+    pub ovtm_untp_antc_cntg_vrss_sign: String,
+    /// 시간외 예상체결 대비율
+    pub ovtm_untp_antc_cntg_ctrt: String,
+    /// 시간외 예상체결량
+    pub ovtm_untp_antc_cnqn: String,
+    /// 정규장 현재가
+    pub stck_prpr: String,
+    /// 정규장 누적거래량
+    pub acml_vol: String,
+}
+
+// VI 발동 현황
+
+/// VI 대상 시장 (FID_MRKT_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViMarket {
+    #[default]
+    All,
+    Kospi,
+    Kosdaq,
+}
+
+impl ViMarket {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::All => "0",
+            Self::Kospi => "K",
+            Self::Kosdaq => "Q",
+        }
+    }
+}
+
+/// VI 발동 방향 (FID_DIV_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViDirection {
+    #[default]
+    All,
+    Up,
+    Down,
+}
+
+impl ViDirection {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::All => "0",
+            Self::Up => "1",
+            Self::Down => "2",
+        }
+    }
+}
+
+/// VI 종류 (FID_RANK_SORT_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ViKind {
+    #[default]
+    All,
+    Static,
+    Dynamic,
+    StaticAndDynamic,
+}
+
+impl ViKind {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::All => "0",
+            Self::Static => "1",
+            Self::Dynamic => "2",
+            Self::StaticAndDynamic => "3",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ViStatusRow {
+    /// 종목명
+    pub hts_kor_isnm: String,
+    /// 종목코드
+    pub mksc_shrn_iscd: String,
+    /// VI 해제 여부 (Y: 발동 중)
+    pub vi_cls_code: String,
+    /// 영업일자
+    pub bsop_date: String,
+    /// VI 발동 시각
+    pub cntg_vi_hour: String,
+    /// VI 해제 시각
+    pub vi_cncl_hour: String,
+    /// VI 종류 (1: 정적, 2: 동적, 3: 정적&동적)
+    pub vi_kind_code: String,
+    /// VI 발동가격
+    pub vi_prc: String,
+    /// 정적 VI 기준가격
+    pub vi_stnd_prc: String,
+    /// 정적 VI 괴리율
+    pub vi_dprt: String,
+    /// 동적 VI 기준가격
+    pub vi_dmc_stnd_prc: String,
+    /// 동적 VI 괴리율
+    pub vi_dmc_dprt: String,
+    /// VI 발동 횟수
+    pub vi_count: String,
+}
+
+// 장마감 예상체결가
+
+/// 장마감 예상체결가 정렬 (FID_RANK_SORT_CLS_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExpectedClosingSort {
+    #[default]
+    All,
+    /// 상한가 마감 예상
+    UpperLimit,
+    /// 하한가 마감 예상
+    LowerLimit,
+    /// 직전대비 상승률 상위
+    Rising,
+    /// 직전대비 하락률 상위
+    Falling,
+}
+
+impl ExpectedClosingSort {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::All => "0",
+            Self::UpperLimit => "1",
+            Self::LowerLimit => "2",
+            Self::Rising => "3",
+            Self::Falling => "4",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExpectedClosingRow {
+    /// 종목코드
+    pub stck_shrn_iscd: String,
+    /// 종목명
+    pub hts_kor_isnm: String,
+    /// 예상체결가
+    pub stck_prpr: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// 직전 체결가 대비
+    pub sdpr_vrss_prpr: String,
+    /// 직전 체결가 대비율
+    pub sdpr_vrss_prpr_rate: String,
+    /// 예상체결량
+    pub cntg_vol: String,
+}
+
+// 국내휴장일조회
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HolidayRow {
+    /// 기준일자 (YYYYMMDD)
+    pub bass_dt: String,
+    /// 요일구분코드 (01: 일요일 ~ 07: 토요일)
+    pub wday_dvsn_cd: String,
+    /// 영업일여부 (금융기관 업무일)
+    pub bzdy_yn: String,
+    /// 거래일여부 (증권 업무 가능일)
+    pub tr_day_yn: String,
+    /// 개장일여부 (주식시장 개장일)
+    pub opnd_yn: String,
+    /// 결제일여부
+    pub sttl_day_yn: String,
+}
+
+const ETF_PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/etfetn/v1/quotations/inquire-price",
+    TrId::new("FHPST02400000", None),
+);
+const NAV_DAILY_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/etfetn/v1/quotations/nav-comparison-daily-trend",
+    TrId::new("FHPST02440200", None),
+);
+const NAV_MINUTE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/etfetn/v1/quotations/nav-comparison-time-trend",
+    TrId::new("FHPST02440100", None),
+);
+const ETF_COMPONENT_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/etfetn/v1/quotations/inquire-component-stock-price",
+    TrId::new("FHKST121600C0", None),
+);
+const INDEX_DAILY_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/domestic-stock/v1/quotations/inquire-daily-indexchartprice",
+    TrId::new("FHKUP03500100", Some("FHKUP03500100")),
+);
+const INDEX_MINUTE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/inquire-time-indexchartprice",
+    TrId::new("FHKUP03500200", None),
+);
+const INDEX_TIME_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/inquire-index-timeprice",
+    TrId::new("FHPUP02110200", None),
+);
+const AFTER_HOURS_PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/inquire-overtime-price",
+    TrId::new("FHPST02300000", None),
+);
+const AFTER_HOURS_ORDERBOOK_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/inquire-overtime-asking-price",
+    TrId::new("FHPST02300400", None),
+);
+const VI_STATUS_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/inquire-vi-status",
+    TrId::new("FHPST01390000", None),
+);
+const EXPECTED_CLOSING_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/exp-closing-price",
+    TrId::new("FHKST117300C0", None),
+);
+const HOLIDAY_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/chk-holiday",
+    TrId::new("CTCA0903R", None),
+);
+
+// 시장 분류별 지원 API
+const ETF_DIVISIONS: &[MarketDivision] = &[MarketDivision::Krx, MarketDivision::Etn];
+const KRX_DIVISIONS: &[MarketDivision] = &[MarketDivision::Krx];
+const ETN_DIVISIONS: &[MarketDivision] = &[MarketDivision::Etn];
+const INDEX_DIVISIONS: &[MarketDivision] = &[MarketDivision::Index];
+
+#[async_trait]
+impl DomesticMarketQuotations for KISProvider {
+    async fn get_etf_price(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<EtfPrice>, Box<dyn Error>> {
+        market.ensure_supported("ETF/ETN 현재가", ETF_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            ETF_PRICE_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", code),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.output_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_nav_daily_trend(
+        &self,
+        market: MarketDivision,
+        code: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<ApiResponse<Vec<NavDailyRow>>, Box<dyn Error>> {
+        market.ensure_supported("NAV 비교추이(일)", ETF_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            NAV_DAILY_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", code),
+                ("FID_INPUT_DATE_1", from),
+                ("FID_INPUT_DATE_2", to),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_nav_minute_trend(
+        &self,
+        market: MarketDivision,
+        code: &str,
+        interval_seconds: &str,
+    ) -> Result<ApiResponse<Vec<NavMinuteRow>>, Box<dyn Error>> {
+        market.ensure_supported("NAV 비교추이(분)", ETN_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            NAV_MINUTE_ENDPOINT,
+            &[
+                ("FID_HOUR_CLS_CODE", interval_seconds),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", code),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_etf_components(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<EtfComponents>, Box<dyn Error>> {
+        market.ensure_supported("ETF 구성종목시세", KRX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            ETF_COMPONENT_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", code),
+                ("FID_COND_SCR_DIV_CODE", "11216"),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: EtfComponents {
+                summary: response.body.output_as("output1")?,
+                components: response.body.rows_as("output2")?,
+            },
+            headers: response.headers,
+        })
+    }
+
+    async fn get_index_daily_chart(
+        &self,
+        market: MarketDivision,
+        index_code: &str,
+        from: &str,
+        to: &str,
+        period: &str,
+    ) -> Result<ApiResponse<Vec<IndexChartRow>>, Box<dyn Error>> {
+        market.ensure_supported("업종 기간별 시세", INDEX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            INDEX_DAILY_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", index_code),
+                ("FID_INPUT_DATE_1", from),
+                ("FID_INPUT_DATE_2", to),
+                ("FID_PERIOD_DIV_CODE", period),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_index_minute_chart(
+        &self,
+        market: MarketDivision,
+        index_code: &str,
+        interval_seconds: &str,
+        include_past: bool,
+    ) -> Result<ApiResponse<Vec<IndexMinuteRow>>, Box<dyn Error>> {
+        market.ensure_supported("업종 분봉", INDEX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            INDEX_MINUTE_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_ETC_CLS_CODE", "0"),
+                ("FID_INPUT_ISCD", index_code),
+                ("FID_INPUT_HOUR_1", interval_seconds),
+                ("FID_PW_DATA_INCU_YN", if include_past { "Y" } else { "N" }),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_index_time_price(
+        &self,
+        market: MarketDivision,
+        index_code: &str,
+        interval_seconds: &str,
+    ) -> Result<ApiResponse<Vec<IndexTimeRow>>, Box<dyn Error>> {
+        market.ensure_supported("업종 시간별지수", INDEX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            INDEX_TIME_ENDPOINT,
+            &[
+                ("FID_INPUT_HOUR_1", interval_seconds),
+                ("FID_INPUT_ISCD", index_code),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_after_hours_price(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<AfterHoursPrice>, Box<dyn Error>> {
+        market.ensure_supported("시간외 단일가 현재가", KRX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            AFTER_HOURS_PRICE_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", code),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.output_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_after_hours_orderbook(
+        &self,
+        market: MarketDivision,
+        code: &str,
+    ) -> Result<ApiResponse<Orderbook>, Box<dyn Error>> {
+        market.ensure_supported("시간외 단일가 호가", KRX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            AFTER_HOURS_ORDERBOOK_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", code),
+            ],
+        )
+        .await?;
+        let output = response
+            .body
+            .output("output")
+            .ok_or("시간외 호가 응답에 output이 없습니다")?;
+        let level = |side: &str, i: usize| Level {
+            price: json_num(output, &format!("ovtm_untp_{side}p{i}")),
+            quantity: json_num(output, &format!("ovtm_untp_{side}p_rsqn{i}")),
+            change: json_num(output, &format!("ovtm_untp_{side}p_rsqn_icdc{i}")),
+        };
+        Ok(ApiResponse {
+            body: Orderbook {
+                time: json_str(output, "ovtm_untp_last_hour").to_string(),
+                asks: std::array::from_fn(|i| level("ask", i + 1)),
+                bids: std::array::from_fn(|i| level("bid", i + 1)),
+                totals: OrderbookTotals {
+                    ask_quantity: json_num(output, "ovtm_untp_total_askp_rsqn"),
+                    bid_quantity: json_num(output, "ovtm_untp_total_bidp_rsqn"),
+                    ask_change: json_num(output, "ovtm_untp_total_askp_rsqn_icdc"),
+                    bid_change: json_num(output, "ovtm_untp_total_bidp_rsqn_icdc"),
+                },
+                expected: None,
+            },
+            headers: response.headers,
+        })
+    }
+
+    async fn get_vi_status(
+        &self,
+        date: &str,
+        market: ViMarket,
+        direction: ViDirection,
+        kind: ViKind,
+        stock_code: &str,
+    ) -> Result<ApiResponse<Vec<ViStatusRow>>, Box<dyn Error>> {
+        let response = get_quotation(
+            self,
+            VI_STATUS_ENDPOINT,
+            &[
+                ("FID_DIV_CLS_CODE", direction.code()),
+                ("FID_COND_SCR_DIV_CODE", "20139"),
+                ("FID_MRKT_CLS_CODE", market.code()),
+                ("FID_INPUT_ISCD", stock_code),
+                ("FID_RANK_SORT_CLS_CODE", kind.code()),
+                ("FID_INPUT_DATE_1", date),
+                ("FID_TRGT_CLS_CODE", ""),
+                ("FID_TRGT_EXLS_CLS_CODE", ""),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_expected_closing_prices(
+        &self,
+        market: MarketDivision,
+        index: RankingMarket,
+        sort: ExpectedClosingSort,
+        extended_only: bool,
+    ) -> Result<ApiResponse<Vec<ExpectedClosingRow>>, Box<dyn Error>> {
+        market.ensure_supported("장마감 예상체결가", KRX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            EXPECTED_CLOSING_ENDPOINT,
+            &[
+                ("FID_RANK_SORT_CLS_CODE", sort.code()),
+                ("FID_INPUT_ISCD", index.code()),
+                ("FID_BLNG_CLS_CODE", if extended_only { "1" } else { "0" }),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_COND_SCR_DIV_CODE", "11173"),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_holidays(
+        &self,
+        base_date: &str,
+    ) -> Result<ApiResponse<Vec<HolidayRow>>, Box<dyn Error>> {
+        let response = get_quotation(
+            self,
+            HOLIDAY_ENDPOINT,
+            &[
+                ("BASS_DT", base_date),
+                ("CTX_AREA_NK", ""),
+                ("CTX_AREA_FK", ""),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
+    }
+}
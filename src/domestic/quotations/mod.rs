@@ -1,84 +1,139 @@
+pub mod market;
+
 use crate::provider::KISProvider;
-use crate::utils::{ApiEndpoint, ApiResponse, RawApiBody, TrId, call_api, call_get_api};
+use crate::types::{
+    ExpectedExecution, Level, MarketDivision, Orderbook, OrderbookTotals, PriceAdjustment,
+};
+use crate::utils::{
+    ApiEndpoint, ApiResponse, RawApiBody, TrId, call_get_api, json_num, json_str, request_interval,
+};
 use async_trait::async_trait;
+use futures_util::future::join_all;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use std::error::Error;
+use std::time::Duration;
 // ======================================================
 // Domestic Trait 정의
 // ======================================================
 #[async_trait]
 pub trait Domestic {
-    async fn get_inquire_price(&self, stock_code: &str)
-    -> Result<StockPriceOutput, Box<dyn Error>>;
+    async fn get_inquire_price(
+        &self,
+        market: MarketDivision,
+        stock_code: &str,
+    ) -> Result<ApiResponse<StockPriceOutput>, Box<dyn Error>>;
+    /// 주식현재가 시세2 (모의투자 미지원)
     async fn get_inquire_price2(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<StockPrice2Output, Box<dyn Error>>;
+    ) -> Result<ApiResponse<StockPrice2Output>, Box<dyn Error>>;
+    /// 업종 현재지수 (`MarketDivision::Index`만 지원)
     async fn get_inquire_index_price(
         &self,
-        market_div_code: &str,
+        market: MarketDivision,
         index_code: &str,
-    ) -> Result<IndexPriceOutput, Box<dyn Error>>;
+    ) -> Result<ApiResponse<IndexPriceOutput>, Box<dyn Error>>;
     async fn get_inquire_period_price(
         &self,
+        market: MarketDivision,
+        stock_code: &str,
+        from: &str,
+        to: &str,
+        period: &str,
+    ) -> Result<ApiResponse<(PeriodPriceOutput1, Vec<PeriodPriceOutput2>)>, Box<dyn Error>>;
+    /// 기간별 시세 조회 (수정주가/원주가 지정, `get_inquire_period_price`는 수정주가)
+    async fn get_inquire_period_price_with(
+        &self,
+        market: MarketDivision,
         stock_code: &str,
         from: &str,
         to: &str,
         period: &str,
-    ) -> Result<PeriodPriceResponse, Box<dyn Error>>;
-    async fn get_recent_ticks(&self, stock_code: &str) -> Result<Vec<Tick>, Box<dyn Error>>;
+        adjustment: PriceAdjustment,
+    ) -> Result<ApiResponse<(PeriodPriceOutput1, Vec<PeriodPriceOutput2>)>, Box<dyn Error>>;
+    async fn get_recent_ticks(
+        &self,
+        market: MarketDivision,
+        stock_code: &str,
+    ) -> Result<ApiResponse<Vec<Tick>>, Box<dyn Error>>;
     async fn get_today_minutes(
         &self,
+        market: MarketDivision,
         stock_code: &str,
         interval: &str,
-    ) -> Result<Vec<TodayMinuteCandle>, Box<dyn Error>>;
+    ) -> Result<ApiResponse<Vec<TodayMinuteCandle>>, Box<dyn Error>>;
+    /// 주식일별분봉조회 (모의투자 미지원)
     async fn get_minutes_by_day(
         &self,
+        market: MarketDivision,
         stock_code: &str,
         date: &str,
         interval: &str,
-    ) -> Result<Vec<ByDayMinuteCandle>, Box<dyn Error>>;
+    ) -> Result<ApiResponse<Vec<ByDayMinuteCandle>>, Box<dyn Error>>;
 }
 
 #[async_trait]
 pub trait DomesticExtendedQuotations {
     async fn get_orderbook(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>>;
+    ) -> Result<ApiResponse<Orderbook>, Box<dyn Error>>;
     async fn get_investor_trend(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>>;
+    ) -> Result<ApiResponse<Vec<InvestorTrendRow>>, Box<dyn Error>>;
     async fn get_member_trend(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>>;
+    ) -> Result<ApiResponse<MemberTrend>, Box<dyn Error>>;
+    /// 관심종목(멀티종목) 시세 (최대 30종목, 초과하면 오류, 모의투자 미지원)
+    async fn get_multi_price(
+        &self,
+        items: &[(MarketDivision, &str)],
+    ) -> Result<ApiResponse<Vec<MultiPriceRow>>, Box<dyn Error>>;
+    /// 종목 수 제한 없는 복수종목 시세 (모의투자 미지원)
+    ///
+    /// 30종목씩 나눠 동시에 조회하고, 종목코드별 결과를 돌려줍니다.
+    /// 일부 호출이 실패해도 해당 종목만 오류로 채웁니다.
+    ///
+    /// 호출 시작 시각은 이 호출 안에서만 호출 제한 간격만큼 벌립니다.
+    /// 다른 API 호출과 공유하는 제한기가 아니므로 동시에 다른 조회를 하면 초당 호출 제한을 넘을 수 있습니다.
+    async fn batch_quotes(
+        &self,
+        market: MarketDivision,
+        codes: &[&str],
+    ) -> Result<HashMap<String, Result<MultiPriceRow, String>>, Box<dyn Error>>;
 }
 
+// 시장 분류별 지원 API
+const PRICE_DIVISIONS: &[MarketDivision] = &[
+    MarketDivision::Krx,
+    MarketDivision::Nxt,
+    MarketDivision::Unified,
+    MarketDivision::Etn,
+    MarketDivision::Elw,
+];
+const PERIOD_DIVISIONS: &[MarketDivision] = &[
+    MarketDivision::Krx,
+    MarketDivision::Nxt,
+    MarketDivision::Unified,
+    MarketDivision::Etn,
+];
+const INDEX_DIVISIONS: &[MarketDivision] = &[MarketDivision::Index];
+
 // ======================================================
 // 공통 Query & Response Struct
 // ======================================================
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct QueryParam<'a> {
-    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
-    pub market_division_code: &'a str,
-    #[serde(rename = "FID_INPUT_ISCD")]
-    pub stock_code: &'a str,
-}
-
-impl<'a> QueryParam<'a> {
-    pub fn stock(stock_code: &'a str) -> Self {
-        Self {
-            market_division_code: "J",
-            stock_code,
-        }
-    }
-}
-
 // 현재가 응답
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct StockPriceOutput {
     pub stck_prpr: String,    // 현재가
     pub prdy_vrss: String,    // 전일 대비
@@ -88,18 +143,17 @@ pub struct StockPriceOutput {
     pub stck_oprc: String,    // 시가
     pub stck_hgpr: String,    // 고가
     pub stck_lwpr: String,    // 저가
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StockPriceResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output: StockPriceOutput,
+    pub hts_avls: String,     // 시가총액 (억원)
+    pub lstn_stcn: String,    // 상장주수
+    pub per: String,
+    pub pbr: String,
+    pub eps: String,
+    pub bps: String,
 }
 
 // 시세2 응답
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct StockPrice2Output {
     pub stck_prpr: String,
     pub prdy_vrss: String,
@@ -113,24 +167,9 @@ pub struct StockPrice2Output {
     pub stck_mxpr: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct StockPrice2Response {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output: StockPrice2Output,
-}
-
 // 업종 현재지수 응답
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IndexPriceQuery<'a> {
-    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
-    pub fid_cond_mrkt_div_code: &'a str, // 업종(U)
-    #[serde(rename = "FID_INPUT_ISCD")]
-    pub fid_input_iscd: &'a str, // 코스피(0001), 코스닥(1001), 코스피200(2001) ...
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct IndexPriceOutput {
     pub bstp_nmix_prpr: String,
     pub bstp_nmix_prdy_vrss: String,
@@ -170,39 +209,17 @@ pub struct IndexPriceOutput {
     pub ntby_rsqn: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IndexPriceResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output: IndexPriceOutput,
-}
-
 // 기간별 조회
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PeriodPriceQuery<'a> {
-    #[serde(rename = "FID_COND_MRKT_DIV_CODE")]
-    pub fid_cond_mrkt_div_code: &'a str,
-    #[serde(rename = "FID_INPUT_ISCD")]
-    pub fid_input_iscd: &'a str,
-    #[serde(rename = "FID_INPUT_DATE_1")]
-    pub fid_input_date_1: &'a str,
-    #[serde(rename = "FID_INPUT_DATE_2")]
-    pub fid_input_date_2: &'a str,
-    #[serde(rename = "FID_PERIOD_DIV_CODE")]
-    pub fid_period_div_code: &'a str,
-    #[serde(rename = "FID_ORG_ADJ_PRC")]
-    pub fid_org_adj_prc: &'a str,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PeriodPriceOutput1 {
     pub stck_prpr: String,
     pub prdy_vrss: String,
     pub prdy_ctrt: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct PeriodPriceOutput2 {
     pub stck_bsop_date: String,
     pub stck_clpr: String,
@@ -212,273 +229,348 @@ pub struct PeriodPriceOutput2 {
     pub acml_vol: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct PeriodPriceResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output1: PeriodPriceOutput1,
-    pub output2: Vec<PeriodPriceOutput2>,
-}
-
 // 틱 체결
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Tick {
     pub stck_cntg_hour: String,
     pub stck_prpr: String,
     pub cntg_vol: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TicksResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output: Vec<Tick>,
-}
-
 // 당일 분봉: 당일 기준 시간 이전 1분봉을 조회합니다.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TodayMinuteCandle {
-    #[serde(default)]
     pub stck_bsop_date: String,
     pub stck_cntg_hour: String,
     pub stck_prpr: String,
     pub stck_oprc: String,
     pub stck_hgpr: String,
     pub stck_lwpr: String,
-    #[serde(default, alias = "cntg_vol")]
+    #[serde(alias = "cntg_vol")]
     pub acml_vol: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TodayMinuteResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    #[serde(alias = "output2")]
-    pub output: Vec<TodayMinuteCandle>,
-}
-
 // 특정일 분봉: 입력 날짜/시간 기준으로 과거 분봉을 조회합니다.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ByDayMinuteCandle {
-    #[serde(default)]
     pub stck_bsop_date: String,
     pub stck_cntg_hour: String,
     pub stck_prpr: String,
     pub stck_oprc: String,
     pub stck_hgpr: String,
     pub stck_lwpr: String,
-    #[serde(default, alias = "cntg_vol")]
+    #[serde(alias = "cntg_vol")]
     pub acml_vol: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ByDayMinutesResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    #[serde(alias = "output2")]
-    pub output: Vec<ByDayMinuteCandle>,
+// 투자자별 매매동향 (일별)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InvestorTrendRow {
+    /// 영업일자
+    pub stck_bsop_date: String,
+    /// 종가
+    pub stck_clpr: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 개인 순매수 수량
+    pub prsn_ntby_qty: String,
+    /// 외국인 순매수 수량
+    pub frgn_ntby_qty: String,
+    /// 기관계 순매수 수량
+    pub orgn_ntby_qty: String,
+    /// 개인 순매수 거래대금
+    pub prsn_ntby_tr_pbmn: String,
+    /// 외국인 순매수 거래대금
+    pub frgn_ntby_tr_pbmn: String,
+    /// 기관계 순매수 거래대금
+    pub orgn_ntby_tr_pbmn: String,
+    /// 개인 매수 거래량
+    pub prsn_shnu_vol: String,
+    /// 외국인 매수 거래량
+    pub frgn_shnu_vol: String,
+    /// 기관계 매수 거래량
+    pub orgn_shnu_vol: String,
+    /// 개인 매수 거래대금
+    pub prsn_shnu_tr_pbmn: String,
+    /// 외국인 매수 거래대금
+    pub frgn_shnu_tr_pbmn: String,
+    /// 기관계 매수 거래대금
+    pub orgn_shnu_tr_pbmn: String,
+    /// 개인 매도 거래량
+    pub prsn_seln_vol: String,
+    /// 외국인 매도 거래량
+    pub frgn_seln_vol: String,
+    /// 기관계 매도 거래량
+    pub orgn_seln_vol: String,
+    /// 개인 매도 거래대금
+    pub prsn_seln_tr_pbmn: String,
+    /// 외국인 매도 거래대금
+    pub frgn_seln_tr_pbmn: String,
+    /// 기관계 매도 거래대금
+    pub orgn_seln_tr_pbmn: String,
+}
+
+// 회원사 매매동향: 매도/매수 상위 5개 회원사
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemberTradeRow {
+    /// 회원사 번호
+    pub member_no: String,
+    /// 회원사명
+    pub member_name: String,
+    /// 총 수량
+    pub quantity: String,
+    /// 비중
+    pub ratio: String,
+    /// 수량 증감
+    pub change: String,
+    /// 외국계 여부
+    pub global_yn: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MemberTrend {
+    /// 매도 상위 회원사
+    pub sells: Vec<MemberTradeRow>,
+    /// 매수 상위 회원사
+    pub buys: Vec<MemberTradeRow>,
+    /// 외국계 총 매도 수량
+    pub glob_total_seln_qty: String,
+    /// 외국계 총 매수 수량
+    pub glob_total_shnu_qty: String,
+    /// 외국계 순매수 수량
+    pub glob_ntby_qty: String,
+}
+
+const PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/domestic-stock/v1/quotations/inquire-price",
+    TrId::new("FHKST01010100", Some("FHKST01010100")),
+);
+const PRICE2_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/inquire-price-2",
+    TrId::new("FHPST01010000", None),
+);
+const INDEX_PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/domestic-stock/v1/quotations/inquire-index-price",
+    TrId::new("FHPUP02100000", Some("FHPUP02100000")),
+);
+const PERIOD_PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/domestic-stock/v1/quotations/inquire-daily-itemchartprice",
+    TrId::new("FHKST03010100", Some("FHKST03010100")),
+);
+const TICKS_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/domestic-stock/v1/quotations/inquire-ccnl",
+    TrId::new("FHKST01010300", Some("FHKST01010300")),
+);
+const TODAY_MINUTES_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/domestic-stock/v1/quotations/inquire-time-itemchartprice",
+    TrId::new("FHKST03010200", Some("FHKST03010200")),
+);
+const MINUTES_BY_DAY_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/inquire-time-dailychartprice",
+    TrId::new("FHKST03010230", None),
+);
+
+pub(crate) async fn get_quotation(
+    provider: &KISProvider,
+    endpoint: ApiEndpoint,
+    query: &[(&str, &str)],
+) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
+    let response = call_get_api::<RawApiBody>(
+        &provider.oauth,
+        &provider.header,
+        provider.practice,
+        endpoint,
+        query,
+    )
+    .await?;
+    response.body.ensure_success()?;
+    Ok(response)
 }
 
 // ======================================================
 // Domestic 구현체
 // ======================================================
 #[async_trait]
-
 impl Domestic for KISProvider {
     async fn get_inquire_price(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<StockPriceOutput, Box<dyn Error>> {
-        let query = QueryParam::stock(stock_code);
-        let url = "https://openapi.koreainvestment.com:9443/uapi/domestic-stock/v1/quotations/inquire-price";
-
-        let response: StockPriceResponse = call_api(
-            &self.oauth,
-            &self.header,
-            url,
-            "FHKST01010100",
+    ) -> Result<ApiResponse<StockPriceOutput>, Box<dyn Error>> {
+        market.ensure_supported("주식현재가 시세", PRICE_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            PRICE_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", query.market_division_code),
-                ("FID_INPUT_ISCD", query.stock_code),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", stock_code),
             ],
         )
         .await?;
-
-        if response.rt_cd != "0" {
-            return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-        }
-        Ok(response.output)
+        Ok(ApiResponse {
+            body: response.body.output_as("output")?,
+            headers: response.headers,
+        })
     }
 
     async fn get_inquire_price2(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<StockPrice2Output, Box<dyn Error>> {
-        let query = QueryParam::stock(stock_code);
-        let url = "https://openapi.koreainvestment.com:9443/uapi/domestic-stock/v1/quotations/inquire-price-2";
-
-        let response: StockPrice2Response = call_api(
-            &self.oauth,
-            &self.header,
-            url,
-            "FHPST01010000",
+    ) -> Result<ApiResponse<StockPrice2Output>, Box<dyn Error>> {
+        market.ensure_supported("주식현재가 시세2", MarketDivision::STOCK)?;
+        let response = get_quotation(
+            self,
+            PRICE2_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", query.market_division_code),
-                ("FID_INPUT_ISCD", query.stock_code),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", stock_code),
             ],
         )
         .await?;
-
-        if response.rt_cd != "0" {
-            return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-        }
-        Ok(response.output)
+        Ok(ApiResponse {
+            body: response.body.output_as("output")?,
+            headers: response.headers,
+        })
     }
 
     async fn get_inquire_index_price(
         &self,
-        market_div_code: &str,
+        market: MarketDivision,
         index_code: &str,
-    ) -> Result<IndexPriceOutput, Box<dyn Error>> {
-        let query = IndexPriceQuery {
-            fid_cond_mrkt_div_code: market_div_code,
-            fid_input_iscd: index_code,
-        };
-        let url = "https://openapi.koreainvestment.com:9443/uapi/domestic-stock/v1/quotations/inquire-index-price";
-
-        let response: IndexPriceResponse = call_api(
-            &self.oauth,
-            &self.header,
-            url,
-            "FHPUP02100000",
+    ) -> Result<ApiResponse<IndexPriceOutput>, Box<dyn Error>> {
+        market.ensure_supported("업종 현재지수", INDEX_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            INDEX_PRICE_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", query.fid_cond_mrkt_div_code),
-                ("FID_INPUT_ISCD", query.fid_input_iscd),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", index_code),
             ],
         )
         .await?;
-
-        if response.rt_cd != "0" {
-            return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-        }
-        Ok(response.output)
+        Ok(ApiResponse {
+            body: response.body.output_as("output")?,
+            headers: response.headers,
+        })
     }
 
     async fn get_inquire_period_price(
         &self,
+        market: MarketDivision,
         stock_code: &str,
         from: &str,
         to: &str,
         period: &str,
-    ) -> Result<PeriodPriceResponse, Box<dyn Error>> {
-        let query = PeriodPriceQuery {
-            fid_cond_mrkt_div_code: "J",
-            fid_input_iscd: stock_code,
-            fid_input_date_1: from,
-            fid_input_date_2: to,
-            fid_period_div_code: period,
-            fid_org_adj_prc: "0",
-        };
-        let url = "https://openapi.koreainvestment.com:9443/uapi/domestic-stock/v1/quotations/inquire-daily-itemchartprice";
+    ) -> Result<ApiResponse<(PeriodPriceOutput1, Vec<PeriodPriceOutput2>)>, Box<dyn Error>> {
+        self.get_inquire_period_price_with(
+            market,
+            stock_code,
+            from,
+            to,
+            period,
+            PriceAdjustment::Adjusted,
+        )
+        .await
+    }
 
-        let response: PeriodPriceResponse = call_api(
-            &self.oauth,
-            &self.header,
-            url,
-            "FHKST03010100",
+    async fn get_inquire_period_price_with(
+        &self,
+        market: MarketDivision,
+        stock_code: &str,
+        from: &str,
+        to: &str,
+        period: &str,
+        adjustment: PriceAdjustment,
+    ) -> Result<ApiResponse<(PeriodPriceOutput1, Vec<PeriodPriceOutput2>)>, Box<dyn Error>> {
+        market.ensure_supported("국내주식기간별시세", PERIOD_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            PERIOD_PRICE_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", query.fid_cond_mrkt_div_code),
-                ("FID_INPUT_ISCD", query.fid_input_iscd),
-                ("FID_INPUT_DATE_1", query.fid_input_date_1),
-                ("FID_INPUT_DATE_2", query.fid_input_date_2),
-                ("FID_PERIOD_DIV_CODE", query.fid_period_div_code),
-                ("FID_ORG_ADJ_PRC", query.fid_org_adj_prc),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", stock_code),
+                ("FID_INPUT_DATE_1", from),
+                ("FID_INPUT_DATE_2", to),
+                ("FID_PERIOD_DIV_CODE", period),
+                ("FID_ORG_ADJ_PRC", adjustment.domestic_code()),
             ],
         )
         .await?;
-
-        if response.rt_cd != "0" {
-            return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-        }
-        Ok(response)
+        Ok(ApiResponse {
+            body: (
+                response.body.output_as("output1")?,
+                response.body.rows_as("output2")?,
+            ),
+            headers: response.headers,
+        })
     }
 
-    async fn get_recent_ticks(&self, stock_code: &str) -> Result<Vec<Tick>, Box<dyn Error>> {
-        let query = QueryParam::stock(stock_code);
-        let url = "https://openapi.koreainvestment.com:9443/uapi/domestic-stock/v1/quotations/inquire-ccnl";
-
-        let response: TicksResponse = call_api(
-            &self.oauth,
-            &self.header,
-            url,
-            "FHKST01010300",
+    async fn get_recent_ticks(
+        &self,
+        market: MarketDivision,
+        stock_code: &str,
+    ) -> Result<ApiResponse<Vec<Tick>>, Box<dyn Error>> {
+        market.ensure_supported("주식현재가 체결", MarketDivision::STOCK)?;
+        let response = get_quotation(
+            self,
+            TICKS_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", query.market_division_code),
-                ("FID_INPUT_ISCD", query.stock_code),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
+                ("FID_INPUT_ISCD", stock_code),
             ],
         )
         .await?;
-
-        if response.rt_cd != "0" {
-            return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-        }
-        Ok(response.output)
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
     }
 
     async fn get_today_minutes(
         &self,
+        market: MarketDivision,
         stock_code: &str,
         interval: &str,
-    ) -> Result<Vec<TodayMinuteCandle>, Box<dyn Error>> {
-        let endpoint = ApiEndpoint::new(
-            "/uapi/domestic-stock/v1/quotations/inquire-time-itemchartprice",
-            TrId::new("FHKST03010200", Some("FHKST03010200")),
-        );
-
-        let response = call_get_api::<TodayMinuteResponse>(
-            &self.oauth,
-            &self.header,
-            self.practice,
-            endpoint,
+    ) -> Result<ApiResponse<Vec<TodayMinuteCandle>>, Box<dyn Error>> {
+        market.ensure_supported("주식당일분봉조회", PERIOD_DIVISIONS)?;
+        let response = get_quotation(
+            self,
+            TODAY_MINUTES_ENDPOINT,
             &[
                 ("FID_ETC_CLS_CODE", ""),
-                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
                 ("FID_INPUT_ISCD", stock_code),
                 ("FID_INPUT_HOUR_1", interval),
                 ("FID_PW_DATA_INCU_YN", "N"),
             ],
         )
         .await?;
-
-        let response = response.body;
-        if response.rt_cd != "0" {
-            return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-        }
-        Ok(response.output)
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
     }
 
     async fn get_minutes_by_day(
         &self,
+        market: MarketDivision,
         stock_code: &str,
         date: &str,
         interval: &str,
-    ) -> Result<Vec<ByDayMinuteCandle>, Box<dyn Error>> {
-        let endpoint = ApiEndpoint::new(
-            "/uapi/domestic-stock/v1/quotations/inquire-time-dailychartprice",
-            TrId::new("FHKST03010230", Some("FHKST03010230")),
-        );
-
-        let response = call_get_api::<ByDayMinutesResponse>(
-            &self.oauth,
-            &self.header,
-            self.practice,
-            endpoint,
+    ) -> Result<ApiResponse<Vec<ByDayMinuteCandle>>, Box<dyn Error>> {
+        market.ensure_supported("주식일별분봉조회", MarketDivision::STOCK)?;
+        let response = get_quotation(
+            self,
+            MINUTES_BY_DAY_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
                 ("FID_INPUT_ISCD", stock_code),
                 ("FID_INPUT_DATE_1", date),
                 ("FID_INPUT_HOUR_1", interval),
@@ -487,15 +579,65 @@ impl Domestic for KISProvider {
             ],
         )
         .await?;
-
-        let response = response.body;
-        if response.rt_cd != "0" {
-            return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-        }
-        Ok(response.output)
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
     }
 }
 
+// 관심종목(멀티종목) 시세
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MultiPriceRow {
+    /// 시장 구분명 (코스피/코스닥)
+    pub kospi_kosdaq_cls_name: String,
+    /// 장운영 구분명
+    pub mrkt_trtm_cls_name: String,
+    /// 시간 구분 코드
+    pub hour_cls_code: String,
+    /// 종목코드
+    pub inter_shrn_iscd: String,
+    /// 종목명
+    pub inter_kor_isnm: String,
+    /// 현재가
+    pub inter2_prpr: String,
+    /// 전일 대비
+    pub inter2_prdy_vrss: String,
+    pub prdy_vrss_sign: String,
+    pub prdy_ctrt: String,
+    pub acml_vol: String,
+    pub acml_tr_pbmn: String,
+    pub inter2_oprc: String,
+    pub inter2_hgpr: String,
+    pub inter2_lwpr: String,
+    /// 하한가
+    pub inter2_llam: String,
+    /// 상한가
+    pub inter2_mxpr: String,
+    /// 매도호가
+    pub inter2_askp: String,
+    /// 매수호가
+    pub inter2_bidp: String,
+    pub seln_rsqn: String,
+    pub shnu_rsqn: String,
+    pub total_askp_rsqn: String,
+    pub total_bidp_rsqn: String,
+    /// 전일 종가
+    pub inter2_prdy_clpr: String,
+    /// 기준가
+    pub inter2_sdpr: String,
+    /// 예상체결 대비
+    pub intr_antc_cntg_vrss: String,
+    pub intr_antc_cntg_vrss_sign: String,
+    pub intr_antc_cntg_prdy_ctrt: String,
+    /// 예상 거래량
+    pub intr_antc_vol: String,
+}
+
+/// 관심종목 시세 1회 호출당 최대 종목 수
+const MULTI_PRICE_LIMIT: usize = 30;
+
 const ORDERBOOK_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
     "/uapi/domestic-stock/v1/quotations/inquire-asking-price-exp-ccn",
     TrId::new("FHKST01010200", Some("FHKST01010200")),
@@ -508,63 +650,234 @@ const MEMBER_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
     "/uapi/domestic-stock/v1/quotations/inquire-member",
     TrId::new("FHKST01010600", Some("FHKST01010600")),
 );
+const MULTI_PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/domestic-stock/v1/quotations/intstock-multprice",
+    TrId::new("FHKST11300006", None),
+);
 
 #[async_trait]
 impl DomesticExtendedQuotations for KISProvider {
     async fn get_orderbook(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
+    ) -> Result<ApiResponse<Orderbook>, Box<dyn Error>> {
+        market.ensure_supported("주식현재가 호가/예상체결", MarketDivision::STOCK)?;
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &self.header,
             self.practice,
             ORDERBOOK_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
                 ("FID_INPUT_ISCD", stock_code),
             ],
         )
         .await?;
         response.body.ensure_success()?;
-        Ok(response)
+        Ok(ApiResponse {
+            body: parse_orderbook(&response.body)?,
+            headers: response.headers,
+        })
     }
 
     async fn get_investor_trend(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
+    ) -> Result<ApiResponse<Vec<InvestorTrendRow>>, Box<dyn Error>> {
+        market.ensure_supported("주식현재가 투자자", MarketDivision::STOCK)?;
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &self.header,
             self.practice,
             INVESTOR_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
                 ("FID_INPUT_ISCD", stock_code),
             ],
         )
         .await?;
         response.body.ensure_success()?;
-        Ok(response)
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
     }
 
     async fn get_member_trend(
         &self,
+        market: MarketDivision,
         stock_code: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
+    ) -> Result<ApiResponse<MemberTrend>, Box<dyn Error>> {
+        market.ensure_supported("주식현재가 회원사", MarketDivision::STOCK)?;
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &self.header,
             self.practice,
             MEMBER_ENDPOINT,
             &[
-                ("FID_COND_MRKT_DIV_CODE", "J"),
+                ("FID_COND_MRKT_DIV_CODE", market.code()),
                 ("FID_INPUT_ISCD", stock_code),
             ],
         )
         .await?;
         response.body.ensure_success()?;
-        Ok(response)
+        let output = response
+            .body
+            .output("output")
+            .ok_or("회원사 응답에 output이 없습니다")?;
+        Ok(ApiResponse {
+            body: MemberTrend::from_output(output),
+            headers: response.headers,
+        })
+    }
+
+    async fn get_multi_price(
+        &self,
+        items: &[(MarketDivision, &str)],
+    ) -> Result<ApiResponse<Vec<MultiPriceRow>>, Box<dyn Error>> {
+        if items.len() > MULTI_PRICE_LIMIT {
+            return Err(format!(
+                "관심종목 시세는 최대 {MULTI_PRICE_LIMIT}종목까지 조회할 수 있습니다 ({}종목)",
+                items.len()
+            )
+            .into());
+        }
+        let mut pairs: Vec<(String, &str)> = Vec::with_capacity(items.len() * 2);
+        for (index, (market, code)) in items.iter().enumerate() {
+            market.ensure_supported("관심종목(멀티종목) 시세", MarketDivision::STOCK)?;
+            let slot = index + 1;
+            pairs.push((format!("FID_COND_MRKT_DIV_CODE_{slot}"), market.code()));
+            pairs.push((format!("FID_INPUT_ISCD_{slot}"), code));
+        }
+        let refs: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|(key, value)| (key.as_str(), *value))
+            .collect();
+        let response = call_get_api::<RawApiBody>(
+            &self.oauth,
+            &self.header,
+            self.practice,
+            MULTI_PRICE_ENDPOINT,
+            &refs,
+        )
+        .await?;
+        response.body.ensure_success()?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn batch_quotes(
+        &self,
+        market: MarketDivision,
+        codes: &[&str],
+    ) -> Result<HashMap<String, Result<MultiPriceRow, String>>, Box<dyn Error>> {
+        market.ensure_supported("관심종목(멀티종목) 시세", MarketDivision::STOCK)?;
+        if self.practice {
+            return Err("관심종목(멀티종목) 시세는 모의투자를 지원하지 않습니다".into());
+        }
+        let interval = request_interval(self.practice);
+        // 이 호출 안에서만 호출 시작 시각을 간격만큼 벌립니다 (provider 전역 제한기 아님)
+        let calls = codes
+            .chunks(MULTI_PRICE_LIMIT)
+            .enumerate()
+            .map(|(index, chunk)| {
+                fetch_multi_price_chunk(self, interval * index as u32, market, chunk)
+            });
+
+        let mut quotes = HashMap::new();
+        for (chunk, result) in codes.chunks(MULTI_PRICE_LIMIT).zip(join_all(calls).await) {
+            for code in chunk {
+                let quote = match &result {
+                    Ok(rows) => rows
+                        .iter()
+                        .find(|row| row.inter_shrn_iscd == *code)
+                        .cloned()
+                        .ok_or_else(|| format!("{code} 시세가 응답에 없습니다")),
+                    Err(error) => Err(error.clone()),
+                };
+                quotes.insert(code.to_string(), quote);
+            }
+        }
+        Ok(quotes)
+    }
+}
+
+/// 관심종목 시세 1회 호출 (`delay` 후 시작, 오류는 종목별로 나눠 담을 수 있게 문자열로 변환)
+async fn fetch_multi_price_chunk(
+    provider: &KISProvider,
+    delay: Duration,
+    market: MarketDivision,
+    codes: &[&str],
+) -> Result<Vec<MultiPriceRow>, String> {
+    tokio::time::sleep(delay).await;
+    let items: Vec<(MarketDivision, &str)> = codes.iter().map(|code| (market, *code)).collect();
+    provider
+        .get_multi_price(&items)
+        .await
+        .map(|response| response.body)
+        .map_err(|error| error.to_string())
+}
+
+/// 호가/예상체결 응답(output1: 호가, output2: 예상체결)을 `Orderbook`으로 변환
+fn parse_orderbook(body: &RawApiBody) -> Result<Orderbook, Box<dyn Error>> {
+    let output = body
+        .output("output1")
+        .ok_or("호가 응답에 output1이 없습니다")?;
+    let level = |side: &str, i: usize| Level {
+        price: json_num(output, &format!("{side}p{i}")),
+        quantity: json_num(output, &format!("{side}p_rsqn{i}")),
+        change: json_num(output, &format!("{side}p_rsqn_icdc{i}")),
+    };
+    let expected = body.output("output2").and_then(|output| {
+        let price: f64 = json_num(output, "antc_cnpr");
+        (price > 0.0).then(|| ExpectedExecution {
+            price,
+            quantity: json_num(output, "antc_cnqn"),
+            volume: json_num(output, "antc_vol"),
+            change: json_num(output, "antc_cntg_vrss"),
+            sign: json_str(output, "antc_cntg_vrss_sign").to_string(),
+            change_rate: json_num(output, "antc_cntg_prdy_ctrt"),
+        })
+    });
+    Ok(Orderbook {
+        time: json_str(output, "aspr_acpt_hour").to_string(),
+        asks: std::array::from_fn(|i| level("ask", i + 1)),
+        bids: std::array::from_fn(|i| level("bid", i + 1)),
+        totals: OrderbookTotals {
+            ask_quantity: json_num(output, "total_askp_rsqn"),
+            bid_quantity: json_num(output, "total_bidp_rsqn"),
+            ask_change: json_num(output, "total_askp_rsqn_icdc"),
+            bid_change: json_num(output, "total_bidp_rsqn_icdc"),
+        },
+        expected,
+    })
+}
+
+impl MemberTrend {
+    fn from_output(output: &Value) -> Self {
+        let rows = |side: &str| {
+            (1..=5)
+                .map(|i| MemberTradeRow {
+                    member_no: json_str(output, &format!("{side}_mbcr_no{i}")).to_string(),
+                    member_name: json_str(output, &format!("{side}_mbcr_name{i}")).to_string(),
+                    quantity: json_str(output, &format!("total_{side}_qty{i}")).to_string(),
+                    ratio: json_str(output, &format!("{side}_mbcr_rlim{i}")).to_string(),
+                    change: json_str(output, &format!("{side}_qty_icdc{i}")).to_string(),
+                    global_yn: json_str(output, &format!("{side}_mbcr_glob_yn_{i}")).to_string(),
+                })
+                .filter(|row| !row.member_no.is_empty() || !row.member_name.is_empty())
+                .collect()
+        };
+        Self {
+            sells: rows("seln"),
+            buys: rows("shnu"),
+            glob_total_seln_qty: json_str(output, "glob_total_seln_qty").to_string(),
+            glob_total_shnu_qty: json_str(output, "glob_total_shnu_qty").to_string(),
+            glob_ntby_qty: json_str(output, "glob_ntby_qty").to_string(),
+        }
     }
 }
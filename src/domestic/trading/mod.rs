@@ -545,10 +545,7 @@ impl DomesticTrading for KISProvider {
         request: DomesticBalanceRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -637,10 +634,7 @@ impl DomesticTrading for KISProvider {
         request: DomesticReviseCancelPossibleRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -665,10 +659,7 @@ impl DomesticTrading for KISProvider {
         request: DomesticRealizedProfitBalanceRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -699,10 +690,7 @@ impl DomesticTrading for KISProvider {
         request: DomesticPeriodTradeProfitRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -730,10 +718,7 @@ impl DomesticTrading for KISProvider {
         request: DomesticPeriodProfitRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -783,10 +768,7 @@ impl DomesticTrading for KISProvider {
         request: DomesticReserveOrderListRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -816,10 +798,7 @@ impl DomesticTrading for KISProvider {
         request: PensionBalanceRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -888,10 +867,7 @@ impl DomesticTrading for KISProvider {
         request: PensionDailyCcldRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -918,10 +894,7 @@ impl DomesticTrading for KISProvider {
         request: PensionPresentBalanceRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -993,10 +966,7 @@ impl DomesticTrading for KISProvider {
         request: DomesticPeriodRightsRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -712,7 +712,7 @@ impl DomesticFutureOptionTrading for KISProvider {
         query: &[(&str, &str)],
         continuation: Option<&str>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
-        let header = self.header.clone().with_tr_cont(continuation);
+        let header = self.header.with_tr_cont(continuation);
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
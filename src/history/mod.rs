@@ -0,0 +1,702 @@
+use crate::calendar::{Date, Market, MarketCalendar};
+use crate::domestic::quotations::Domestic;
+use crate::overseas::{
+    Exchange, OverseasDailyPriceQuery, OverseasQuotations, OverseasTodayMinuteQuery,
+};
+use crate::provider::KISProvider;
+use crate::types::MarketDivision;
+pub use crate::types::PriceAdjustment;
+use crate::utils::request_interval;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::error::Error;
+
+// ======================================================
+// 과거 봉 일괄 조회 (History Backfill)
+// ======================================================
+//
+// 기간별 시세는 1회 최대 100건, 분봉은 1회 최대 120건까지만 내려주므로
+// 조회 종료 시점부터 과거 방향으로 구간을 나눠 반복 조회한 뒤 중복을 제거해 합칩니다.
+
+/// 조회 대상 종목
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Instrument {
+    /// 국내주식 (시장 분류, 종목코드)
+    Domestic {
+        market: MarketDivision,
+        stock_code: String,
+    },
+    /// 해외주식 (거래소, 종목코드)
+    Overseas { exchange: Exchange, symbol: String },
+}
+
+impl Instrument {
+    /// KRX 국내주식
+    pub fn domestic(stock_code: &str) -> Self {
+        Self::domestic_market(MarketDivision::Krx, stock_code)
+    }
+
+    /// 시장 분류(KRX/NXT/통합)를 지정한 국내주식
+    pub fn domestic_market(market: MarketDivision, stock_code: &str) -> Self {
+        Self::Domestic {
+            market,
+            stock_code: stock_code.to_string(),
+        }
+    }
+
+    pub fn overseas(exchange: Exchange, symbol: &str) -> Self {
+        Self::Overseas {
+            exchange,
+            symbol: symbol.to_string(),
+        }
+    }
+}
+
+/// 조회 봉 단위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HistoryInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+    /// N분봉 (국내는 1분봉을 받아 N분으로 묶습니다)
+    Minute(u32),
+}
+
+/// 과거 봉 1개
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Candle {
+    /// 영업일자 (YYYYMMDD, 해외는 현지일자)
+    pub date: String,
+    /// 봉 시작 시각 (HHMMSS, 일/주/월/년봉은 빈 문자열)
+    pub time: String,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: u64,
+}
+
+#[async_trait]
+pub trait CandleHistory {
+    /// `from`~`to`(YYYYMMDD, 양끝 포함) 구간의 봉을 수정주가 기준으로 모두 조회해 시간순으로 반환
+    async fn fetch_history(
+        &self,
+        instrument: &Instrument,
+        interval: HistoryInterval,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Candle>, Box<dyn Error>>;
+
+    /// 수정주가/원주가를 지정해 조회
+    async fn fetch_history_with(
+        &self,
+        instrument: &Instrument,
+        interval: HistoryInterval,
+        from: &str,
+        to: &str,
+        adjustment: PriceAdjustment,
+    ) -> Result<Vec<Candle>, Box<dyn Error>>;
+}
+
+#[async_trait]
+impl CandleHistory for KISProvider {
+    async fn fetch_history(
+        &self,
+        instrument: &Instrument,
+        interval: HistoryInterval,
+        from: &str,
+        to: &str,
+    ) -> Result<Vec<Candle>, Box<dyn Error>> {
+        self.fetch_history_with(instrument, interval, from, to, PriceAdjustment::Adjusted)
+            .await
+    }
+
+    async fn fetch_history_with(
+        &self,
+        instrument: &Instrument,
+        interval: HistoryInterval,
+        from: &str,
+        to: &str,
+        adjustment: PriceAdjustment,
+    ) -> Result<Vec<Candle>, Box<dyn Error>> {
+        let (start, end) = (day_number(from)?, day_number(to)?);
+        if start > end {
+            return Err(format!("조회 시작일({from})이 종료일({to})보다 늦습니다").into());
+        }
+        if interval == HistoryInterval::Minute(0) {
+            return Err("분봉 간격은 1분 이상이어야 합니다".into());
+        }
+
+        let mut candles = BTreeMap::new();
+        match (instrument, interval) {
+            (Instrument::Domestic { market, stock_code }, HistoryInterval::Minute(minutes)) => {
+                domestic_minutes(self, *market, stock_code, start, end, &mut candles).await?;
+                return Ok(resample_minutes(candles.into_values(), minutes));
+            }
+            (Instrument::Domestic { market, stock_code }, interval) => {
+                domestic_periods(
+                    self,
+                    *market,
+                    stock_code,
+                    interval,
+                    start,
+                    end,
+                    adjustment,
+                    &mut candles,
+                )
+                .await?;
+            }
+            (Instrument::Overseas { exchange, symbol }, HistoryInterval::Minute(minutes)) => {
+                overseas_minutes(self, *exchange, symbol, minutes, start, end, &mut candles)
+                    .await?;
+            }
+            (Instrument::Overseas { exchange, symbol }, HistoryInterval::Year) => {
+                // 해외 기간별 시세는 연봉이 없어 월봉을 묶어 만듭니다
+                overseas_periods(
+                    self,
+                    *exchange,
+                    symbol,
+                    HistoryInterval::Month,
+                    start,
+                    end,
+                    adjustment,
+                    &mut candles,
+                )
+                .await?;
+                return Ok(resample_years(candles.into_values()));
+            }
+            (Instrument::Overseas { exchange, symbol }, interval) => {
+                overseas_periods(
+                    self,
+                    *exchange,
+                    symbol,
+                    interval,
+                    start,
+                    end,
+                    adjustment,
+                    &mut candles,
+                )
+                .await?;
+            }
+        }
+        Ok(candles.into_values().collect())
+    }
+}
+
+/// (일자, 시각) 기준으로 정렬/중복 제거된 봉 모음
+type CandleMap = BTreeMap<(String, String), Candle>;
+
+/// 새로 추가된 봉 수 반환 (이미 받은 봉은 덮어쓰지 않음)
+fn insert_candles(candles: &mut CandleMap, rows: impl IntoIterator<Item = Candle>) -> usize {
+    let mut added = 0;
+    for candle in rows {
+        candles
+            .entry((candle.date.clone(), candle.time.clone()))
+            .or_insert_with(|| {
+                added += 1;
+                candle
+            });
+    }
+    added
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn domestic_periods(
+    provider: &KISProvider,
+    market: MarketDivision,
+    stock_code: &str,
+    interval: HistoryInterval,
+    start: i64,
+    end: i64,
+    adjustment: PriceAdjustment,
+    candles: &mut CandleMap,
+) -> Result<(), Box<dyn Error>> {
+    let period = match interval {
+        HistoryInterval::Week => "W",
+        HistoryInterval::Month => "M",
+        HistoryInterval::Year => "Y",
+        _ => "D",
+    };
+    let from = date_string(start);
+    let mut cursor = end;
+    while cursor >= start {
+        let to = date_string(cursor);
+        let response = provider
+            .get_inquire_period_price_with(market, stock_code, &from, &to, period, adjustment)
+            .await?;
+        let rows: Vec<Candle> = response
+            .body
+            .1
+            .iter()
+            .filter(|row| !row.stck_bsop_date.is_empty())
+            .map(|row| Candle {
+                date: row.stck_bsop_date.clone(),
+                time: String::new(),
+                open: number(&row.stck_oprc),
+                high: number(&row.stck_hgpr),
+                low: number(&row.stck_lwpr),
+                close: number(&row.stck_clpr),
+                volume: number(&row.acml_vol) as u64,
+            })
+            .collect();
+        let Some(next) = period_page(candles, rows, start) else {
+            break;
+        };
+        cursor = next;
+        pace(provider).await;
+    }
+    Ok(())
+}
+
+async fn domestic_minutes(
+    provider: &KISProvider,
+    market: MarketDivision,
+    stock_code: &str,
+    start: i64,
+    end: i64,
+    candles: &mut CandleMap,
+) -> Result<(), Box<dyn Error>> {
+    market.ensure_supported("주식일별분봉조회", MarketDivision::STOCK)?;
+    // 통합 시세는 NXT 애프터마켓까지 포함하지만 휴장일은 KRX와 같습니다
+    let (session_market, close) = match market {
+        MarketDivision::Krx => (Market::Krx, "153000"),
+        MarketDivision::Nxt => (Market::Nxt, "200000"),
+        _ => (Market::Krx, "200000"),
+    };
+    let calendar = MarketCalendar::new();
+    let business_days = (start..=end)
+        .rev()
+        .filter(|day| calendar.is_business_day(session_market, Date::from_days(*day)));
+    for day in business_days {
+        let date = date_string(day);
+        // 해당 시장 분류의 장 마감 시각부터 120분씩 거슬러 올라갑니다
+        let mut hour = close.to_string();
+        loop {
+            let response = provider
+                .get_minutes_by_day(market, stock_code, &date, &hour)
+                .await?;
+            pace(provider).await;
+            let rows: Vec<Candle> = response
+                .body
+                .iter()
+                .filter(|row| row.stck_bsop_date.is_empty() || row.stck_bsop_date == date)
+                .map(|row| Candle {
+                    date: date.clone(),
+                    time: row.stck_cntg_hour.clone(),
+                    open: number(&row.stck_oprc),
+                    high: number(&row.stck_hgpr),
+                    low: number(&row.stck_lwpr),
+                    close: number(&row.stck_prpr),
+                    volume: number(&row.acml_vol) as u64,
+                })
+                .collect();
+            let Some(earliest) = minute_page(candles, rows) else {
+                break;
+            };
+            hour = earliest;
+        }
+    }
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn overseas_periods(
+    provider: &KISProvider,
+    exchange: Exchange,
+    symbol: &str,
+    interval: HistoryInterval,
+    start: i64,
+    end: i64,
+    adjustment: PriceAdjustment,
+    candles: &mut CandleMap,
+) -> Result<(), Box<dyn Error>> {
+    let gubn = match interval {
+        HistoryInterval::Week => "1",
+        HistoryInterval::Month => "2",
+        _ => "0",
+    };
+    let mut cursor = end;
+    while cursor >= start {
+        let bymd = date_string(cursor);
+        let rows = provider
+            .get_overseas_daily_price(OverseasDailyPriceQuery {
+                auth: "",
+                exchange,
+                symbol,
+                gubn,
+                bymd: &bymd,
+                modp: adjustment.overseas_code(),
+                keyb: None,
+            })
+            .await?
+            .body
+            .1;
+        let rows: Vec<Candle> = rows
+            .iter()
+            .filter(|row| !row.xymd.is_empty())
+            .map(|row| Candle {
+                date: row.xymd.clone(),
+                time: String::new(),
+                open: number(&row.open),
+                high: number(&row.high),
+                low: number(&row.low),
+                close: number(&row.clos),
+                volume: number(&row.tvol) as u64,
+            })
+            .collect();
+        let Some(next) = period_page(candles, rows, start) else {
+            break;
+        };
+        cursor = next;
+        pace(provider).await;
+    }
+    Ok(())
+}
+
+async fn overseas_minutes(
+    provider: &KISProvider,
+    exchange: Exchange,
+    symbol: &str,
+    minutes: u32,
+    start: i64,
+    end: i64,
+    candles: &mut CandleMap,
+) -> Result<(), Box<dyn Error>> {
+    let nmin = minutes.to_string();
+    let (first, last) = (date_string(start), date_string(end));
+    let mut next_key = String::new();
+    loop {
+        let rows = provider
+            .get_overseas_today_minutes(OverseasTodayMinuteQuery {
+                auth: "",
+                exchange,
+                symbol,
+                nmin: &nmin,
+                include_prev_day: "1",
+                next: if next_key.is_empty() { "" } else { "1" },
+                record_count: "120",
+                fill: "",
+                next_key: &next_key,
+            })
+            .await?
+            .body
+            .1;
+        pace(provider).await;
+        let rows: Vec<Candle> = rows
+            .iter()
+            .filter(|row| !row.xymd.is_empty())
+            .map(|row| Candle {
+                date: row.xymd.clone(),
+                time: row.xhms.clone(),
+                open: number(&row.open),
+                high: number(&row.high),
+                low: number(&row.low),
+                close: number(&row.last),
+                volume: number(&row.evol) as u64,
+            })
+            .collect();
+        let Some(earliest) = overseas_minute_page(candles, rows, &first, &last, &next_key) else {
+            break;
+        };
+        next_key = earliest;
+    }
+    Ok(())
+}
+
+/// 분봉 1회 조회 최대 건수
+const MINUTE_PAGE: usize = 120;
+
+/// 기간별 시세 한 페이지 반영, 이어서 조회할 기준일 반환
+///
+/// 기준일 이전 100건을 내려주므로 조회 시작일 이전 봉은 버리며, 빈 페이지이거나 새 봉이 없으면 종료합니다.
+fn period_page(candles: &mut CandleMap, rows: Vec<Candle>, start: i64) -> Option<i64> {
+    let earliest = earliest_day(&rows)?;
+    let rows = rows
+        .into_iter()
+        .filter(|row| day_number(&row.date).is_ok_and(|day| day >= start));
+    (insert_candles(candles, rows) > 0).then_some(earliest - 1)
+}
+
+/// 국내 분봉 한 페이지 반영, 이어서 조회할 시각 반환
+///
+/// 새 봉이 없거나 마지막 페이지(120건 미만)면 종료합니다.
+fn minute_page(candles: &mut CandleMap, rows: Vec<Candle>) -> Option<String> {
+    let earliest = rows.iter().map(|row| row.time.clone()).min()?;
+    let full_page = rows.len() >= MINUTE_PAGE;
+    (insert_candles(candles, rows) > 0 && full_page).then_some(earliest)
+}
+
+/// 해외 분봉 한 페이지 반영, 다음 조회 키(일자+시각) 반환
+///
+/// 조회 시작일 이전까지 내려왔거나 더 받을 데이터가 없으면 종료합니다.
+fn overseas_minute_page(
+    candles: &mut CandleMap,
+    rows: Vec<Candle>,
+    first: &str,
+    last: &str,
+    next_key: &str,
+) -> Option<String> {
+    let earliest = rows
+        .iter()
+        .map(|row| format!("{}{}", row.date, row.time))
+        .min()?;
+    let in_range = rows
+        .into_iter()
+        .filter(|row| row.date.as_str() >= first && row.date.as_str() <= last);
+    let added = insert_candles(candles, in_range);
+    let earliest_date = earliest.get(..8)?;
+    if earliest_date < first || (added == 0 && earliest == next_key) {
+        return None;
+    }
+    Some(earliest)
+}
+
+/// 1분봉을 N분봉으로 묶기 (자정 기준 N분 경계)
+fn resample_minutes(candles: impl Iterator<Item = Candle>, minutes: u32) -> Vec<Candle> {
+    if minutes <= 1 {
+        return candles.collect();
+    }
+    let mut merged: Vec<Candle> = Vec::new();
+    for candle in candles {
+        let hhmm = candle.time.get(..4).and_then(|t| t.parse::<u32>().ok());
+        let Some(hhmm) = hhmm else {
+            continue;
+        };
+        let bucket = (hhmm / 100 * 60 + hhmm % 100) / minutes * minutes;
+        let time = format!("{:02}{:02}00", bucket / 60, bucket % 60);
+        match merged.last_mut() {
+            Some(last) if last.date == candle.date && last.time == time => merge(last, &candle),
+            _ => merged.push(Candle { time, ..candle }),
+        }
+    }
+    merged
+}
+
+/// 월봉을 연봉으로 묶기
+fn resample_years(candles: impl Iterator<Item = Candle>) -> Vec<Candle> {
+    let mut merged: Vec<Candle> = Vec::new();
+    for candle in candles {
+        match merged.last_mut() {
+            Some(last) if last.date.get(..4) == candle.date.get(..4) => merge(last, &candle),
+            _ => merged.push(candle),
+        }
+    }
+    merged
+}
+
+fn merge(bar: &mut Candle, next: &Candle) {
+    bar.high = bar.high.max(next.high);
+    bar.low = bar.low.min(next.low);
+    bar.close = next.close;
+    bar.volume += next.volume;
+}
+
+fn earliest_day(rows: &[Candle]) -> Option<i64> {
+    rows.iter()
+        .filter_map(|row| day_number(&row.date).ok())
+        .min()
+}
+
+fn number(text: &str) -> f64 {
+    text.trim().parse().unwrap_or(0.0)
+}
+
+/// 연속 조회 사이 간격 (모의투자는 초당 호출 제한이 낮음)
+async fn pace(provider: &KISProvider) {
+    tokio::time::sleep(request_interval(provider.practice)).await;
+}
+
+// 날짜 계산 (1970-01-01 기준 일수)
+
+fn day_number(date: &str) -> Result<i64, Box<dyn Error>> {
+    Ok(date.parse::<Date>()?.days())
+}
+
+fn date_string(days: i64) -> String {
+    Date::from_days(days).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bar(date: &str, time: &str, open: f64, close: f64, volume: u64) -> Candle {
+        Candle {
+            date: date.to_string(),
+            time: time.to_string(),
+            open,
+            high: open.max(close),
+            low: open.min(close),
+            close,
+            volume,
+        }
+    }
+
+    fn day(date: &str) -> i64 {
+        day_number(date).unwrap()
+    }
+
+    #[test]
+    fn overlapping_pages_keep_the_first_copy() {
+        let mut candles = CandleMap::new();
+        let first = vec![
+            bar("20250103", "", 10.0, 11.0, 100),
+            bar("20250102", "", 9.0, 10.0, 90),
+        ];
+        assert_eq!(
+            period_page(&mut candles, first, day("20241201")),
+            Some(day("20250101"))
+        );
+
+        // 두 번째 페이지가 20250102를 다시 내려줘도 먼저 받은 봉을 유지
+        let second = vec![
+            bar("20250102", "", 1.0, 1.0, 1),
+            bar("20241231", "", 8.0, 9.0, 80),
+        ];
+        assert_eq!(
+            period_page(&mut candles, second, day("20241201")),
+            Some(day("20241230"))
+        );
+        assert_eq!(candles.len(), 3);
+        let kept = &candles[&("20250102".to_string(), String::new())];
+        assert_eq!(kept.volume, 90);
+
+        // 새 봉이 없거나 빈 페이지면 종료
+        let repeated = vec![bar("20241231", "", 8.0, 9.0, 80)];
+        assert_eq!(period_page(&mut candles, repeated, day("20241201")), None);
+        assert_eq!(period_page(&mut candles, Vec::new(), day("20241201")), None);
+    }
+
+    #[test]
+    fn period_page_drops_rows_before_start() {
+        let mut candles = CandleMap::new();
+        let rows = vec![
+            bar("20250102", "", 9.0, 10.0, 90),
+            bar("20241231", "", 8.0, 9.0, 80),
+        ];
+        assert_eq!(
+            period_page(&mut candles, rows, day("20250101")),
+            Some(day("20241230"))
+        );
+        assert_eq!(candles.len(), 1);
+
+        // 조회 시작일 이전 봉만 남으면 추가된 봉이 없어 종료
+        let rows = vec![bar("20241230", "", 8.0, 9.0, 80)];
+        assert_eq!(period_page(&mut candles, rows, day("20250101")), None);
+    }
+
+    #[test]
+    fn minute_pages_stop_on_short_or_repeated_pages() {
+        let mut candles = CandleMap::new();
+        let page = |last_minute: u32| -> Vec<Candle> {
+            (0..MINUTE_PAGE as u32)
+                .map(|offset| {
+                    let minute = 9 * 60 + last_minute - offset;
+                    let time = format!("{:02}{:02}00", minute / 60, minute % 60);
+                    bar("20250102", &time, 1.0, 1.0, 1)
+                })
+                .collect()
+        };
+        // 10:59 ~ 09:00 120건, 가장 이른 시각부터 이어서 조회
+        let full = page(119);
+        assert_eq!(
+            minute_page(&mut candles, full.clone()),
+            Some("090000".to_string())
+        );
+        // 같은 페이지를 다시 받으면 종료
+        assert_eq!(minute_page(&mut candles, full), None);
+        // 120건 미만이면 마지막 페이지
+        let short = vec![bar("20250102", "085900", 1.0, 1.0, 1)];
+        assert_eq!(minute_page(&mut candles, short), None);
+        assert_eq!(candles.len(), MINUTE_PAGE + 1);
+        assert_eq!(minute_page(&mut candles, Vec::new()), None);
+    }
+
+    #[test]
+    fn overseas_minute_pages_stop_before_first_day() {
+        let mut candles = CandleMap::new();
+        let rows = vec![
+            bar("20250103", "093100", 1.0, 1.0, 1),
+            bar("20250103", "093000", 1.0, 1.0, 1),
+        ];
+        let key = overseas_minute_page(&mut candles, rows, "20250102", "20250103", "");
+        assert_eq!(key.as_deref(), Some("20250103093000"));
+
+        // 같은 키로 새 봉 없이 같은 페이지가 오면 종료
+        let rows = vec![bar("20250103", "093000", 1.0, 1.0, 1)];
+        assert_eq!(
+            overseas_minute_page(&mut candles, rows, "20250102", "20250103", "20250103093000"),
+            None
+        );
+
+        // 조회 시작일 이전까지 내려오면 범위 안 봉만 넣고 종료
+        let rows = vec![
+            bar("20250102", "155900", 1.0, 1.0, 1),
+            bar("20250101", "155900", 1.0, 1.0, 1),
+        ];
+        assert_eq!(
+            overseas_minute_page(&mut candles, rows, "20250102", "20250103", "20250103093000"),
+            None
+        );
+        assert_eq!(candles.len(), 3);
+    }
+
+    #[test]
+    fn minutes_are_bucketed_from_midnight() {
+        let minutes: Vec<Candle> = (0..60)
+            .map(|offset| {
+                let minute = 9 * 60 + offset;
+                let time = format!("{:02}{:02}00", minute / 60, minute % 60);
+                bar("20250102", &time, offset as f64, offset as f64 + 1.0, 10)
+            })
+            .chain([bar("20250103", "090000", 100.0, 101.0, 5)])
+            .collect();
+
+        let five = resample_minutes(minutes.clone().into_iter(), 5);
+        assert_eq!(five.len(), 13);
+        assert_eq!(five[0].time, "090000");
+        assert_eq!(
+            (five[0].open, five[0].close, five[0].volume),
+            (0.0, 5.0, 50)
+        );
+        assert_eq!((five[0].high, five[0].low), (5.0, 0.0));
+        assert_eq!(five[11].time, "095500");
+
+        let fifteen = resample_minutes(minutes.clone().into_iter(), 15);
+        let times: Vec<&str> = fifteen.iter().map(|bar| bar.time.as_str()).collect();
+        assert_eq!(times, ["090000", "091500", "093000", "094500", "090000"]);
+        assert_eq!(fifteen[1].volume, 150);
+
+        // 09:00~09:59는 한 시간봉, 다음 날 봉은 따로 묶음
+        let hourly = resample_minutes(minutes.into_iter(), 60);
+        assert_eq!(hourly.len(), 2);
+        assert_eq!((hourly[0].open, hourly[0].close), (0.0, 60.0));
+        assert_eq!(hourly[0].volume, 600);
+        assert_eq!((hourly[1].date.as_str(), hourly[1].volume), ("20250103", 5));
+    }
+
+    #[test]
+    fn months_merge_into_years() {
+        let months = vec![
+            bar("20231130", "", 10.0, 12.0, 1),
+            bar("20231229", "", 12.0, 9.0, 2),
+            bar("20240131", "", 9.0, 15.0, 3),
+            bar("20240229", "", 15.0, 14.0, 4),
+        ];
+        let years = resample_years(months.into_iter());
+        assert_eq!(years.len(), 2);
+        assert_eq!(years[0].date, "20231130");
+        assert_eq!((years[0].open, years[0].close), (10.0, 9.0));
+        assert_eq!(
+            (years[0].high, years[0].low, years[0].volume),
+            (12.0, 9.0, 3)
+        );
+        assert_eq!((years[1].open, years[1].close), (9.0, 14.0));
+        assert_eq!(
+            (years[1].high, years[1].low, years[1].volume),
+            (15.0, 9.0, 7)
+        );
+    }
+}
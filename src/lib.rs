@@ -1,7 +1,13 @@
+pub mod bars;
 pub mod bond;
+pub mod calendar;
 pub mod domestic;
 pub mod futures;
+pub mod history;
+pub mod master;
+pub mod money;
 pub mod oauth;
+pub mod orderbook;
 pub mod overseas;
 pub mod provider;
 pub mod types;
@@ -0,0 +1,460 @@
+pub mod overseas;
+
+pub use overseas::{
+    OverseasSymbolInfo, download_overseas_master, load_overseas_master, parse_overseas_master,
+};
+
+use crate::utils::http_client;
+use encoding_rs::EUC_KR;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io::{Cursor, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+// ======================================================
+// 종목 마스터파일
+// ======================================================
+//
+// KIS가 배포하는 국내 종목 마스터파일(kospi_code.mst / kosdaq_code.mst / konex_code.mst)은
+// EUC-KR 고정폭 텍스트입니다. 한 줄은 단축코드(9) + 표준코드(12) + 한글명(가변) + 시장별 고정폭 필드로 구성되며,
+// 한글명 뒤의 필드는 줄 끝에서부터 길이가 고정되어 있습니다.
+
+const MASTER_BASE_URL: &str = "https://new.real.download.dws.co.kr/common/master";
+
+/// 국내 상장 시장
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ListedMarket {
+    Kospi,
+    Kosdaq,
+    Konex,
+}
+
+impl ListedMarket {
+    pub const ALL: [ListedMarket; 3] = [Self::Kospi, Self::Kosdaq, Self::Konex];
+
+    /// 마스터파일 이름 (zip 안의 파일명)
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Self::Kospi => "kospi_code.mst",
+            Self::Kosdaq => "kosdaq_code.mst",
+            Self::Konex => "konex_code.mst",
+        }
+    }
+
+    /// 마스터파일 다운로드 주소
+    pub fn download_url(&self) -> String {
+        format!("{MASTER_BASE_URL}/{}.zip", self.file_name())
+    }
+
+    /// 한글명 뒤 고정폭 필드 길이 (줄바꿈 제외)
+    ///
+    /// KIS 예제 코드의 `row[-228:]` 등은 줄바꿈 문자를 포함한 길이라 1씩 작습니다.
+    fn tail_len(&self) -> usize {
+        match self {
+            Self::Kospi => 227,
+            Self::Kosdaq => 221,
+            Self::Konex => 183,
+        }
+    }
+
+    fn layout(&self) -> &'static TailLayout {
+        match self {
+            Self::Kospi => &KOSPI_LAYOUT,
+            Self::Kosdaq => &KOSDAQ_LAYOUT,
+            Self::Konex => &KONEX_LAYOUT,
+        }
+    }
+}
+
+impl fmt::Display for ListedMarket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Kospi => "KOSPI",
+            Self::Kosdaq => "KOSDAQ",
+            Self::Konex => "KONEX",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for ListedMarket {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        match text.to_ascii_uppercase().as_str() {
+            "KOSPI" => Ok(Self::Kospi),
+            "KOSDAQ" => Ok(Self::Kosdaq),
+            "KONEX" => Ok(Self::Konex),
+            _ => Err(format!("알 수 없는 시장입니다: {text}")),
+        }
+    }
+}
+
+/// 국내 종목 기본 정보 (마스터파일 1줄)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SymbolInfo {
+    /// 단축코드 (예: 005930)
+    pub code: String,
+    /// 표준코드 (ISIN)
+    pub isin: String,
+    /// 한글 종목명
+    pub name: String,
+    pub market: ListedMarket,
+    /// 증권그룹구분코드 (ST: 주권, EF: ETF, EN: ETN, RT: 리츠, MF: 뮤추얼펀드, DR: 예탁증서 등)
+    pub group_code: String,
+    /// 지수업종 대분류 코드 (코넥스는 빈 값)
+    pub sector_large: String,
+    /// 지수업종 중분류 코드
+    pub sector_medium: String,
+    /// 지수업종 소분류 코드
+    pub sector_small: String,
+    pub is_etf: bool,
+    pub is_etn: bool,
+    /// 기업인수목적회사(SPAC) 여부
+    pub is_spac: bool,
+    /// 우선주 여부
+    pub is_preferred: bool,
+    /// 거래정지 여부
+    pub trading_halted: bool,
+    /// 관리종목 여부
+    pub administrative: bool,
+    /// 기준가
+    pub base_price: u64,
+    /// 정규장 매매수량단위
+    pub lot_size: u64,
+    /// 액면가
+    pub par_value: f64,
+    /// 상장일자 (YYYYMMDD)
+    pub listing_date: String,
+    /// 상장주수 (천 주)
+    pub listed_shares: u64,
+    /// 전일기준 시가총액 (억 원)
+    pub market_cap: u64,
+}
+
+/// 한글명 뒤 고정폭 필드 위치 (tail 시작 기준 byte offset, 길이)
+struct TailLayout {
+    group_code: (usize, usize),
+    sector: Option<[(usize, usize); 3]>,
+    spac: Option<usize>,
+    base_price: (usize, usize),
+    lot_size: (usize, usize),
+    trading_halted: usize,
+    administrative: usize,
+    par_value: (usize, usize),
+    listing_date: (usize, usize),
+    listed_shares: (usize, usize),
+    preferred: usize,
+    market_cap: (usize, usize),
+}
+
+const KOSPI_LAYOUT: TailLayout = TailLayout {
+    group_code: (0, 2),
+    sector: Some([(3, 4), (7, 4), (11, 4)]),
+    spac: Some(29),
+    base_price: (41, 9),
+    lot_size: (50, 5),
+    trading_halted: 60,
+    administrative: 62,
+    par_value: (93, 12),
+    listing_date: (105, 8),
+    listed_shares: (113, 15),
+    preferred: 158,
+    market_cap: (212, 9),
+};
+
+const KOSDAQ_LAYOUT: TailLayout = TailLayout {
+    group_code: (0, 2),
+    sector: Some([(3, 4), (7, 4), (11, 4)]),
+    spac: Some(24),
+    base_price: (36, 9),
+    lot_size: (45, 5),
+    trading_halted: 55,
+    administrative: 57,
+    par_value: (88, 12),
+    listing_date: (100, 8),
+    listed_shares: (108, 15),
+    preferred: 153,
+    market_cap: (206, 9),
+};
+
+const KONEX_LAYOUT: TailLayout = TailLayout {
+    group_code: (0, 2),
+    sector: None,
+    spac: None,
+    base_price: (2, 9),
+    lot_size: (11, 5),
+    trading_halted: 21,
+    administrative: 23,
+    par_value: (54, 12),
+    listing_date: (66, 8),
+    listed_shares: (74, 15),
+    preferred: 119,
+    // 코넥스는 KRX300 여부 필드가 없어 재무 필드부터 1칸 앞당겨집니다
+    market_cap: (171, 9),
+};
+
+/// 마스터파일 내용(EUC-KR) 파싱
+///
+/// 빈 줄은 건너뛰고, 길이가 모자란 줄이 있으면 오류를 반환합니다.
+pub fn parse_master(market: ListedMarket, bytes: &[u8]) -> Result<Vec<SymbolInfo>, Box<dyn Error>> {
+    bytes
+        .split(|b| *b == b'\n')
+        .map(|line| line.strip_suffix(b"\r").unwrap_or(line))
+        .filter(|line| !line.is_empty())
+        .map(|line| parse_line(market, line))
+        .collect()
+}
+
+fn parse_line(market: ListedMarket, line: &[u8]) -> Result<SymbolInfo, Box<dyn Error>> {
+    let tail_len = market.tail_len();
+    if line.len() < 21 + tail_len {
+        return Err(format!(
+            "{} 마스터파일 형식이 올바르지 않습니다 (줄 길이 {})",
+            market,
+            line.len()
+        )
+        .into());
+    }
+    let (head, tail) = line.split_at(line.len() - tail_len);
+    let (name, _, _) = EUC_KR.decode(&head[21..]);
+    let layout = market.layout();
+
+    let text = |(offset, len): (usize, usize)| {
+        String::from_utf8_lossy(&tail[offset..offset + len])
+            .trim()
+            .to_string()
+    };
+    let number = |field: (usize, usize)| text(field).parse::<u64>().unwrap_or_default();
+    let flag = |offset: usize| tail[offset] == b'Y';
+
+    let group_code = text(layout.group_code);
+    let [sector_large, sector_medium, sector_small] = layout
+        .sector
+        .map(|fields| fields.map(text))
+        .unwrap_or_default();
+
+    Ok(SymbolInfo {
+        code: String::from_utf8_lossy(&head[..9]).trim().to_string(),
+        isin: String::from_utf8_lossy(&head[9..21]).trim().to_string(),
+        name: name.trim().to_string(),
+        market,
+        is_etf: group_code == "EF",
+        is_etn: group_code == "EN",
+        group_code,
+        sector_large,
+        sector_medium,
+        sector_small,
+        is_spac: layout.spac.is_some_and(flag),
+        is_preferred: !matches!(tail[layout.preferred], b'0' | b' '),
+        trading_halted: flag(layout.trading_halted),
+        administrative: flag(layout.administrative),
+        base_price: number(layout.base_price),
+        lot_size: number(layout.lot_size),
+        par_value: text(layout.par_value).parse().unwrap_or_default(),
+        listing_date: text(layout.listing_date),
+        listed_shares: number(layout.listed_shares),
+        market_cap: number(layout.market_cap),
+    })
+}
+
+/// zip 압축된 마스터파일에서 첫 번째 파일을 꺼내 파싱
+pub fn parse_master_zip(
+    market: ListedMarket,
+    bytes: &[u8],
+) -> Result<Vec<SymbolInfo>, Box<dyn Error>> {
+    let content = unzip_first(bytes)?;
+    parse_master(market, &content)
+}
+
+pub(crate) fn unzip_first(bytes: &[u8]) -> Result<Vec<u8>, Box<dyn Error>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(bytes))?;
+    let mut file = archive.by_index(0)?;
+    let mut content = Vec::with_capacity(file.size() as usize);
+    file.read_to_end(&mut content)?;
+    Ok(content)
+}
+
+pub(crate) async fn download(url: &str) -> Result<Vec<u8>, Box<dyn Error>> {
+    let response = http_client().get(url).send().await?.error_for_status()?;
+    Ok(response.bytes().await?.to_vec())
+}
+
+/// 국내 종목 목록 (코드/ISIN 조회, 종목명 검색)
+#[derive(Debug, Clone, Default)]
+pub struct SymbolMaster {
+    symbols: Vec<SymbolInfo>,
+    by_code: HashMap<String, usize>,
+    by_isin: HashMap<String, usize>,
+}
+
+impl SymbolMaster {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 로컬 마스터파일(.mst 또는 .zip) 읽기
+    pub fn load_file(
+        &mut self,
+        market: ListedMarket,
+        path: impl AsRef<Path>,
+    ) -> Result<usize, Box<dyn Error>> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        let symbols = if path.extension().is_some_and(|ext| ext == "zip") {
+            parse_master_zip(market, &bytes)?
+        } else {
+            parse_master(market, &bytes)?
+        };
+        Ok(self.extend(symbols))
+    }
+
+    /// KIS 배포 서버에서 시장별 마스터파일(zip) 다운로드 후 반영
+    pub async fn download(&mut self, market: ListedMarket) -> Result<usize, Box<dyn Error>> {
+        let bytes = download(&market.download_url()).await?;
+        let symbols = parse_master_zip(market, &bytes)?;
+        Ok(self.extend(symbols))
+    }
+
+    /// 코스피/코스닥/코넥스 마스터파일 모두 다운로드
+    pub async fn download_all() -> Result<Self, Box<dyn Error>> {
+        let mut master = Self::new();
+        for market in ListedMarket::ALL {
+            master.download(market).await?;
+        }
+        Ok(master)
+    }
+
+    /// 종목 추가 (같은 단축코드는 덮어씀), 반영한 종목 수 반환
+    pub fn extend(&mut self, symbols: impl IntoIterator<Item = SymbolInfo>) -> usize {
+        let mut count = 0;
+        for symbol in symbols {
+            match self.by_code.get(&symbol.code) {
+                Some(&index) => {
+                    self.by_isin.remove(&self.symbols[index].isin);
+                    self.by_isin.insert(symbol.isin.clone(), index);
+                    self.symbols[index] = symbol;
+                }
+                None => {
+                    let index = self.symbols.len();
+                    self.by_code.insert(symbol.code.clone(), index);
+                    self.by_isin.insert(symbol.isin.clone(), index);
+                    self.symbols.push(symbol);
+                }
+            }
+            count += 1;
+        }
+        count
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn symbols(&self) -> &[SymbolInfo] {
+        &self.symbols
+    }
+
+    /// 단축코드로 조회
+    pub fn get(&self, code: &str) -> Option<&SymbolInfo> {
+        self.by_code.get(code).map(|index| &self.symbols[*index])
+    }
+
+    /// 표준코드(ISIN)로 조회
+    pub fn get_by_isin(&self, isin: &str) -> Option<&SymbolInfo> {
+        self.by_isin.get(isin).map(|index| &self.symbols[*index])
+    }
+
+    /// 종목명 완전 일치 (공백/대소문자 무시)
+    pub fn find_by_name(&self, name: &str) -> Option<&SymbolInfo> {
+        let name = normalize(name);
+        self.symbols
+            .iter()
+            .find(|symbol| normalize(&symbol.name) == name)
+    }
+
+    /// 시장별 종목 목록
+    pub fn by_market(&self, market: ListedMarket) -> impl Iterator<Item = &SymbolInfo> {
+        self.symbols
+            .iter()
+            .filter(move |symbol| symbol.market == market)
+    }
+
+    /// 종목명 또는 단축코드가 `prefix`로 시작하는 종목 (종목명 짧은 순)
+    pub fn search_prefix(&self, prefix: &str) -> Vec<&SymbolInfo> {
+        let prefix = normalize(prefix);
+        let mut found: Vec<&SymbolInfo> = self
+            .symbols
+            .iter()
+            .filter(|symbol| {
+                symbol.code.starts_with(&prefix) || normalize(&symbol.name).starts_with(&prefix)
+            })
+            .collect();
+        found.sort_by_key(|symbol| (symbol.name.chars().count(), symbol.code.clone()));
+        found
+    }
+
+    /// 종목명 유사 검색 (완전 일치 > 앞부분 일치 > 포함 > 글자 순서 일치 순으로 최대 `limit`개)
+    pub fn search(&self, query: &str, limit: usize) -> Vec<&SymbolInfo> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let mut scored: Vec<(u8, usize, &SymbolInfo)> = self
+            .symbols
+            .iter()
+            .filter_map(|symbol| {
+                let name = normalize(&symbol.name);
+                let score = if name == query || symbol.code == query {
+                    0
+                } else if name.starts_with(&query) || symbol.code.starts_with(&query) {
+                    1
+                } else if name.contains(&query) {
+                    2
+                } else if is_subsequence(&query, &name) {
+                    3
+                } else {
+                    return None;
+                };
+                Some((score, name.chars().count(), symbol))
+            })
+            .collect();
+        scored.sort_by(|a, b| (a.0, a.1, &a.2.code).cmp(&(b.0, b.1, &b.2.code)));
+        scored
+            .into_iter()
+            .take(limit)
+            .map(|(_, _, symbol)| symbol)
+            .collect()
+    }
+}
+
+fn normalize(text: &str) -> String {
+    text.chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_subsequence(query: &str, text: &str) -> bool {
+    let mut chars = text.chars();
+    query.chars().all(|q| chars.any(|c| c == q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_line_is_rejected() {
+        let mut line = format!("{:<9}{:<12}", "005930", "KR7005930003").into_bytes();
+        line.extend(vec![b'N'; ListedMarket::Kospi.tail_len() - 1]);
+        assert!(parse_line(ListedMarket::Kospi, &line).is_err());
+    }
+}
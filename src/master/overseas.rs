@@ -0,0 +1,243 @@
+use super::{MASTER_BASE_URL, download, unzip_first};
+use crate::overseas::Exchange;
+use encoding_rs::EUC_KR;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::path::Path;
+
+// 해외 종목 마스터파일 (NASMST.COD, NYSMST.COD, ... ) 은 탭으로 구분된 EUC-KR 텍스트입니다.
+// 컬럼: 국가코드, 거래소ID, 거래소코드, 거래소명, 심볼, 실시간심볼, 한글명, 영문명, 종목유형,
+//       통화, 소수점자리수, 데이터유형, 기준가, 매수주문단위, 매도주문단위, 장시작시각, 장마감시각,
+//       DR여부, DR국가코드, 업종분류코드, 지수구성종목존재여부, 호가단위유형, 구분코드, 호가단위유형상세
+
+/// 해외 종목 기본 정보 (마스터파일 1줄)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverseasSymbolInfo {
+    pub exchange: Exchange,
+    /// 종목코드 (티커)
+    pub symbol: String,
+    /// 실시간 심볼 (예: DNASAAPL)
+    pub realtime_symbol: String,
+    pub korean_name: String,
+    pub english_name: String,
+    /// 종목유형 (1: 지수, 2: 주식, 3: ETP, 4: 워런트)
+    pub security_type: String,
+    pub currency: String,
+    /// 가격 소수점 자리수
+    pub decimals: u32,
+    pub base_price: f64,
+    pub bid_lot_size: u64,
+    pub ask_lot_size: u64,
+    /// 장 시작 시각 (HHMM, 현지)
+    pub market_open: String,
+    /// 장 마감 시각 (HHMM, 현지)
+    pub market_close: String,
+    /// 예탁증서(DR) 여부
+    pub is_dr: bool,
+    pub sector_code: String,
+    /// ETP 구분 (001: ETF, 002: ETN, 003: ETC, 004: 기타, 005: VIX 기초 ETF, 006: VIX 기초 ETN)
+    pub etp_kind: String,
+}
+
+impl OverseasSymbolInfo {
+    pub fn is_etf(&self) -> bool {
+        matches!(self.etp_kind.as_str(), "001" | "005")
+    }
+}
+
+/// 해외 마스터파일 내용(EUC-KR) 파싱
+pub fn parse_overseas_master(bytes: &[u8]) -> Result<Vec<OverseasSymbolInfo>, Box<dyn Error>> {
+    let (text, _, _) = EUC_KR.decode(bytes);
+    text.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(parse_line)
+        .collect()
+}
+
+fn parse_line(line: &str) -> Result<OverseasSymbolInfo, Box<dyn Error>> {
+    let columns: Vec<&str> = line.split('\t').map(str::trim).collect();
+    if columns.len() < 23 {
+        return Err(format!(
+            "해외 마스터파일 형식이 올바르지 않습니다 (컬럼 {}개)",
+            columns.len()
+        )
+        .into());
+    }
+    let column = |index: usize| columns[index].to_string();
+    let number = |index: usize| columns[index].parse::<u64>().unwrap_or_default();
+
+    Ok(OverseasSymbolInfo {
+        exchange: columns[2].parse()?,
+        symbol: column(4),
+        realtime_symbol: column(5),
+        korean_name: column(6),
+        english_name: column(7),
+        security_type: column(8),
+        currency: column(9),
+        decimals: columns[10].parse().unwrap_or_default(),
+        base_price: columns[12].parse().unwrap_or_default(),
+        bid_lot_size: number(13),
+        ask_lot_size: number(14),
+        market_open: column(15),
+        market_close: column(16),
+        is_dr: columns[17] == "Y",
+        sector_code: column(19),
+        etp_kind: column(22),
+    })
+}
+
+/// 로컬 해외 마스터파일(.COD 또는 .zip) 읽기
+pub fn load_overseas_master(
+    path: impl AsRef<Path>,
+) -> Result<Vec<OverseasSymbolInfo>, Box<dyn Error>> {
+    let path = path.as_ref();
+    let bytes = std::fs::read(path)?;
+    if path.extension().is_some_and(|ext| ext == "zip") {
+        parse_overseas_master(&unzip_first(&bytes)?)
+    } else {
+        parse_overseas_master(&bytes)
+    }
+}
+
+/// KIS 배포 서버에서 거래소별 해외 마스터파일 다운로드
+pub async fn download_overseas_master(
+    exchange: Exchange,
+) -> Result<Vec<OverseasSymbolInfo>, Box<dyn Error>> {
+    let url = format!(
+        "{MASTER_BASE_URL}/{}.zip",
+        exchange.master_file_name().to_ascii_lowercase()
+    );
+    let bytes = download(&url).await?;
+    parse_overseas_master(&unzip_first(&bytes)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn master_bytes(lines: &[&[&str]]) -> Vec<u8> {
+        let text: Vec<String> = lines.iter().map(|columns| columns.join("\t")).collect();
+        EUC_KR.encode(&text.join("\r\n")).0.into_owned()
+    }
+
+    #[test]
+    fn parses_overseas_master_lines() {
+        let bytes = master_bytes(&[
+            &[
+                "US",
+                "22",
+                "NAS",
+                "나스닥",
+                "AAPL",
+                "DNASAAPL",
+                "애플",
+                "APPLE INC",
+                "2",
+                "USD",
+                "4",
+                "",
+                "251.4500",
+                "1",
+                "1",
+                "0930",
+                "1600",
+                "N",
+                "",
+                "0",
+                "0",
+                "0",
+                "",
+                "",
+            ],
+            &[
+                "US",
+                "22",
+                "NAS",
+                "나스닥",
+                "QQQ",
+                "DNASQQQ",
+                "인베스코 QQQ 트러스트",
+                "INVESCO QQQ TRUST",
+                "3",
+                "USD",
+                "4",
+                "",
+                "601.2000",
+                "1",
+                "1",
+                "0930",
+                "1600",
+                "N",
+                "",
+                "",
+                "0",
+                "0",
+                "001",
+                "",
+            ],
+            &[
+                "HK",
+                "11",
+                "HKS",
+                "홍콩",
+                "00700",
+                "DHKS00700",
+                "텐센트",
+                "TENCENT",
+                "2",
+                "HKD",
+                "3",
+                "",
+                "512.500",
+                "100",
+                "100",
+                "0930",
+                "1600",
+                "N",
+                "",
+                "",
+                "0",
+                "0",
+                "",
+                "",
+            ],
+        ]);
+        let symbols = parse_overseas_master(&bytes).unwrap();
+        assert_eq!(symbols.len(), 3);
+
+        let apple = &symbols[0];
+        assert_eq!(apple.exchange, Exchange::Nasdaq);
+        assert_eq!(apple.symbol, "AAPL");
+        assert_eq!(apple.realtime_symbol, "DNASAAPL");
+        assert_eq!(apple.korean_name, "애플");
+        assert_eq!(apple.english_name, "APPLE INC");
+        assert_eq!(apple.security_type, "2");
+        assert_eq!(apple.currency, "USD");
+        assert_eq!(apple.decimals, 4);
+        assert_eq!(apple.base_price, 251.45);
+        assert_eq!((apple.bid_lot_size, apple.ask_lot_size), (1, 1));
+        assert_eq!(
+            (apple.market_open.as_str(), apple.market_close.as_str()),
+            ("0930", "1600")
+        );
+        assert!(!apple.is_dr && !apple.is_etf());
+
+        let qqq = &symbols[1];
+        assert_eq!(qqq.korean_name, "인베스코 QQQ 트러스트");
+        assert_eq!(qqq.etp_kind, "001");
+        assert!(qqq.is_etf());
+
+        let tencent = &symbols[2];
+        assert_eq!(tencent.exchange, Exchange::HongKong);
+        assert_eq!(tencent.symbol, "00700");
+        assert_eq!(tencent.currency, "HKD");
+        assert_eq!(tencent.decimals, 3);
+        assert_eq!((tencent.bid_lot_size, tencent.ask_lot_size), (100, 100));
+    }
+
+    #[test]
+    fn short_overseas_line_is_rejected() {
+        let bytes = master_bytes(&[&["US", "22", "NAS", "나스닥", "AAPL"]]);
+        assert!(parse_overseas_master(&bytes).is_err());
+    }
+}
@@ -0,0 +1,525 @@
+use crate::overseas::{
+    Exchange, OverseasDailyChartQuery, OverseasPresentBalanceRequest, OverseasQuotations,
+    OverseasTrading,
+};
+use crate::provider::KISProvider;
+use crate::types::AccountInfo;
+use crate::utils::{RawApiBody, json_num, json_str};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// 통화
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Currency {
+    Krw,
+    Usd,
+    Hkd,
+    Jpy,
+    Cny,
+    Vnd,
+}
+
+impl Currency {
+    pub const ALL: [Currency; 6] = [
+        Self::Krw,
+        Self::Usd,
+        Self::Hkd,
+        Self::Jpy,
+        Self::Cny,
+        Self::Vnd,
+    ];
+
+    /// 통화코드 (CRCY_CD, ISO 4217)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Krw => "KRW",
+            Self::Usd => "USD",
+            Self::Hkd => "HKD",
+            Self::Jpy => "JPY",
+            Self::Cny => "CNY",
+            Self::Vnd => "VND",
+        }
+    }
+
+    /// 소수점 자리수 (원/엔/동은 0)
+    pub fn decimals(&self) -> u32 {
+        match self {
+            Self::Krw | Self::Jpy | Self::Vnd => 0,
+            Self::Usd | Self::Hkd | Self::Cny => 2,
+        }
+    }
+
+    /// 환율 기간별시세(시장분류 `X`) 종목코드 (KIS가 공개한 코드만)
+    pub fn fx_chart_symbol(&self) -> Option<&'static str> {
+        match self {
+            Self::Usd => Some("FX@KRW"),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for Currency {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        let code = code.trim().to_ascii_uppercase();
+        // 위안화는 응답에 따라 CNH로 오기도 합니다
+        let code = if code == "CNH" {
+            "CNY".to_string()
+        } else {
+            code
+        };
+        Self::ALL
+            .into_iter()
+            .find(|currency| currency.code() == code)
+            .ok_or_else(|| format!("알 수 없는 통화코드입니다: {code}"))
+    }
+}
+
+/// 통화가 붙은 금액
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Money {
+    pub amount: f64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: f64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub fn krw(amount: f64) -> Self {
+        Self::new(amount, Currency::Krw)
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(0.0, currency)
+    }
+
+    /// API 응답 문자열 금액 (비어 있거나 잘못된 값이면 0)
+    pub fn parse(text: &str, currency: Currency) -> Self {
+        Self::new(text.trim().parse().unwrap_or(0.0), currency)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0.0
+    }
+
+    /// 통화 소수점 자리수로 반올림
+    pub fn round(&self) -> Self {
+        let scale = 10f64.powi(self.currency.decimals() as i32);
+        Self::new((self.amount * scale).round() / scale, self.currency)
+    }
+
+    /// 같은 통화끼리 더하기 (통화가 다르면 `None`)
+    pub fn checked_add(&self, other: Money) -> Option<Money> {
+        (self.currency == other.currency)
+            .then(|| Self::new(self.amount + other.amount, self.currency))
+    }
+
+    /// 같은 통화끼리 빼기 (통화가 다르면 `None`)
+    pub fn checked_sub(&self, other: Money) -> Option<Money> {
+        (self.currency == other.currency)
+            .then(|| Self::new(self.amount - other.amount, self.currency))
+    }
+
+    pub fn scale(&self, factor: f64) -> Self {
+        Self::new(self.amount * factor, self.currency)
+    }
+
+    /// 다른 통화로 환산
+    pub fn convert(&self, to: Currency, rates: &FxRates) -> Option<Money> {
+        rates.convert(*self, to)
+    }
+
+    /// 원화 환산
+    pub fn to_krw(&self, rates: &FxRates) -> Option<Money> {
+        self.convert(Currency::Krw, rates)
+    }
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let decimals = self.currency.decimals() as usize;
+        let text = format!("{:.*}", decimals, self.amount.abs());
+        let (integer, fraction) = text.split_once('.').unwrap_or((&text, ""));
+        let mut grouped = String::new();
+        for (index, digit) in integer.chars().enumerate() {
+            if index > 0 && (integer.len() - index) % 3 == 0 {
+                grouped.push(',');
+            }
+            grouped.push(digit);
+        }
+        // 반올림해서 0이 되는 음수는 부호 없이 표시
+        if self.amount < 0.0 && text.bytes().any(|digit| matches!(digit, b'1'..=b'9')) {
+            f.write_str("-")?;
+        }
+        f.write_str(&grouped)?;
+        if !fraction.is_empty() {
+            write!(f, ".{fraction}")?;
+        }
+        write!(f, " {}", self.currency)
+    }
+}
+
+/// 원화 기준 환율표 (통화 1단위당 원화)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FxRates {
+    rates: HashMap<Currency, f64>,
+}
+
+impl FxRates {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 환율 등록 (통화 1단위당 원화, 0 이하는 무시)
+    pub fn set(&mut self, currency: Currency, krw_per_unit: f64) {
+        if currency != Currency::Krw && krw_per_unit > 0.0 {
+            self.rates.insert(currency, krw_per_unit);
+        }
+    }
+
+    /// 통화 1단위당 원화 (원화는 항상 1)
+    pub fn rate(&self, currency: Currency) -> Option<f64> {
+        match currency {
+            Currency::Krw => Some(1.0),
+            _ => self.rates.get(&currency).copied(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+
+    /// 다른 환율표 값으로 덮어쓰기
+    pub fn merge(&mut self, other: &FxRates) {
+        self.rates.extend(other.rates.iter().map(|(k, v)| (*k, *v)));
+    }
+
+    /// 금액 환산 (원화를 거쳐 교차 환산, 환율이 없으면 `None`)
+    pub fn convert(&self, money: Money, to: Currency) -> Option<Money> {
+        if money.currency == to {
+            return Some(money);
+        }
+        let krw = money.amount * self.rate(money.currency)?;
+        Some(Money::new(krw / self.rate(to)?, to))
+    }
+
+    /// 체결기준현재잔고 응답(output2 통화별 최초고시환율, output1 종목별 기준환율)에서 환율 추출
+    pub fn from_present_balance(body: &RawApiBody) -> Self {
+        let mut rates = Self::new();
+        for row in body.rows("output1") {
+            if let Ok(currency) = json_str(row, "buy_crcy_cd").parse() {
+                rates.set(currency, json_num(row, "bass_exrt"));
+            }
+        }
+        for row in body.rows("output2") {
+            if let Ok(currency) = json_str(row, "crcy_cd").parse() {
+                rates.set(currency, json_num(row, "frst_bltn_exrt"));
+            }
+        }
+        rates
+    }
+
+    /// 체결기준현재잔고(전체 국가)를 조회해 환율표 생성
+    pub async fn load_present_balance(
+        provider: &KISProvider,
+        account: &AccountInfo,
+    ) -> Result<Self, Box<dyn Error>> {
+        let response = provider
+            .inquire_overseas_present_balance(OverseasPresentBalanceRequest {
+                account,
+                wcrc_frcr_dvsn_cd: "02",
+                natn_cd: "000",
+                tr_mket_cd: "00",
+                inqr_dvsn_cd: "00",
+            })
+            .await?;
+        Ok(Self::from_present_balance(&response.body))
+    }
+
+    /// 환율 기간별시세(시장분류 `X`)의 최근 값으로 환율 갱신
+    ///
+    /// `symbol`은 환율 종목코드 (미국 달러는 `Currency::fx_chart_symbol`의 `FX@KRW`),
+    /// `from`/`to`는 조회 기간(YYYYMMDD)입니다. 갱신된 환율을 돌려줍니다.
+    pub async fn load_fx_chart(
+        &mut self,
+        provider: &KISProvider,
+        currency: Currency,
+        symbol: &str,
+        from: &str,
+        to: &str,
+    ) -> Result<f64, Box<dyn Error>> {
+        let response = provider
+            .get_overseas_daily_chartprice(OverseasDailyChartQuery {
+                market_div_code: "X",
+                symbol,
+                start_date: from,
+                end_date: to,
+                period_div_code: "D",
+            })
+            .await?;
+        let (summary, rows) = response.body;
+        let current: f64 = summary.ovrs_nmix_prpr.trim().parse().unwrap_or(0.0);
+        let latest = rows
+            .iter()
+            .filter(|row| !row.stck_bsop_date.is_empty())
+            .max_by(|a, b| a.stck_bsop_date.cmp(&b.stck_bsop_date))
+            .and_then(|row| row.ovrs_nmix_prpr.trim().parse::<f64>().ok())
+            .unwrap_or(0.0);
+        let rate = if current > 0.0 { current } else { latest };
+        if rate <= 0.0 {
+            return Err(format!("{symbol} 환율 시세가 없습니다").into());
+        }
+        self.set(currency, rate);
+        Ok(rate)
+    }
+}
+
+/// 해외 보유 종목 (체결기준현재잔고 output1)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverseasPosition {
+    pub symbol: String,
+    pub name: String,
+    pub exchange: Option<Exchange>,
+    pub quantity: f64,
+    pub orderable_quantity: f64,
+    pub average_price: Money,
+    pub current_price: Money,
+    /// 응답의 기준환율 (통화 1단위당 원화)
+    pub base_rate: f64,
+}
+
+impl OverseasPosition {
+    /// 체결기준현재잔고 응답에서 보유 종목 목록 추출
+    pub fn from_present_balance(body: &RawApiBody) -> Vec<Self> {
+        body.rows("output1")
+            .into_iter()
+            .filter_map(|row| {
+                let currency = json_str(row, "buy_crcy_cd").parse().ok()?;
+                Some(Self {
+                    symbol: json_str(row, "pdno").to_string(),
+                    name: json_str(row, "prdt_name").to_string(),
+                    exchange: Exchange::from_code(json_str(row, "ovrs_excg_cd")),
+                    quantity: json_num(row, "cblc_qty13"),
+                    orderable_quantity: json_num(row, "ord_psbl_qty1"),
+                    average_price: Money::new(json_num(row, "avg_unpr3"), currency),
+                    current_price: Money::new(json_num(row, "ovrs_now_pric1"), currency),
+                    base_rate: json_num(row, "bass_exrt"),
+                })
+            })
+            .filter(|position| position.quantity > 0.0)
+            .collect()
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.current_price.currency
+    }
+
+    /// 매입금액
+    pub fn cost(&self) -> Money {
+        self.average_price.scale(self.quantity)
+    }
+
+    /// 평가금액
+    pub fn market_value(&self) -> Money {
+        self.current_price.scale(self.quantity)
+    }
+
+    /// 평가손익
+    pub fn profit(&self) -> Money {
+        Money::new(
+            self.market_value().amount - self.cost().amount,
+            self.currency(),
+        )
+    }
+
+    /// 원화 평가금액 (환율표에 없으면 응답의 기준환율 사용)
+    pub fn market_value_krw(&self, rates: &FxRates) -> Option<Money> {
+        self.to_krw(self.market_value(), rates)
+    }
+
+    /// 원화 평가손익 (환율표에 없으면 응답의 기준환율 사용)
+    pub fn profit_krw(&self, rates: &FxRates) -> Option<Money> {
+        self.to_krw(self.profit(), rates)
+    }
+
+    fn to_krw(&self, money: Money, rates: &FxRates) -> Option<Money> {
+        money
+            .to_krw(rates)
+            .or_else(|| (self.base_rate > 0.0).then(|| Money::krw(money.amount * self.base_rate)))
+    }
+}
+
+/// 보유 종목 전체 원화 평가금액 (환율을 알 수 없는 종목이 있으면 오류)
+pub fn total_market_value_krw(
+    positions: &[OverseasPosition],
+    rates: &FxRates,
+) -> Result<Money, Box<dyn Error>> {
+    positions
+        .iter()
+        .try_fold(Money::krw(0.0), |total, position| {
+            let value = position
+                .market_value_krw(rates)
+                .ok_or_else(|| format!("{} 환율이 없습니다", position.currency()))?;
+            Ok(Money::krw(total.amount + value.amount))
+        })
+}
+
+/// 단위 수 내림 전 상대 오차 보정 (0.3 / 0.1 = 2.9999999999999996 등)
+const LOT_EPSILON: f64 = 1e-9;
+
+/// 금액 기준 주문 수량 ("AAPL 100만원어치")
+///
+/// `notional`을 `price` 통화로 환산해 통화 소수점 자리수로 반올림한 뒤
+/// 주문 단위(`lot_size`, 미국 1주, 일본/중국 100주 등)로 내림한 정수 수량을 돌려줍니다.
+/// 금액이 가격의 정확한 배수면 부동소수점 오차로 한 단위 적게 계산되지 않습니다.
+/// 한 단위도 살 수 없으면 오류입니다.
+pub fn shares_for_notional(
+    notional: Money,
+    price: Money,
+    lot_size: u64,
+    rates: &FxRates,
+) -> Result<u64, Box<dyn Error>> {
+    if price.amount <= 0.0 {
+        return Err("주문 가격은 0보다 커야 합니다".into());
+    }
+    let budget = rates
+        .convert(notional, price.currency)
+        .ok_or_else(|| format!("{} → {} 환율이 없습니다", notional.currency, price.currency))?
+        .round();
+    let lot_size = lot_size.max(1);
+    let lots = (budget.amount / (price.amount * lot_size as f64) * (1.0 + LOT_EPSILON)).floor();
+    let lots = lots.max(0.0) as u64;
+    if lots == 0 {
+        return Err(
+            format!("{notional}으로는 주당 {price} 종목을 {lot_size}주도 살 수 없습니다").into(),
+        );
+    }
+    Ok(lots * lot_size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rates() -> FxRates {
+        let mut rates = FxRates::new();
+        rates.set(Currency::Usd, 1400.0);
+        rates.set(Currency::Jpy, 9.5);
+        rates.set(Currency::Hkd, 180.0);
+        rates
+    }
+
+    #[test]
+    fn convert_goes_through_krw_for_cross_rates() {
+        let rates = rates();
+        assert_eq!(
+            rates.convert(Money::new(100.0, Currency::Usd), Currency::Krw),
+            Some(Money::krw(140_000.0))
+        );
+        assert_eq!(
+            rates.convert(Money::krw(140_000.0), Currency::Usd),
+            Some(Money::new(100.0, Currency::Usd))
+        );
+        // 1 USD = 1400 / 9.5 JPY
+        let yen = rates
+            .convert(Money::new(1.0, Currency::Usd), Currency::Jpy)
+            .unwrap();
+        assert_eq!(yen.currency, Currency::Jpy);
+        assert!((yen.amount - 1400.0 / 9.5).abs() < 1e-9);
+        let back = rates.convert(yen, Currency::Usd).unwrap();
+        assert!((back.amount - 1.0).abs() < 1e-12);
+        // 같은 통화는 그대로, 환율이 없으면 None
+        assert_eq!(
+            rates.convert(Money::new(5.0, Currency::Hkd), Currency::Hkd),
+            Some(Money::new(5.0, Currency::Hkd))
+        );
+        assert_eq!(
+            rates.convert(Money::new(5.0, Currency::Usd), Currency::Cny),
+            None
+        );
+    }
+
+    #[test]
+    fn display_groups_thousands_and_keeps_sign() {
+        assert_eq!(Money::krw(1_234_567.0).to_string(), "1,234,567 KRW");
+        assert_eq!(Money::krw(999.0).to_string(), "999 KRW");
+        assert_eq!(Money::krw(1000.0).to_string(), "1,000 KRW");
+        assert_eq!(
+            Money::new(1234.5, Currency::Usd).to_string(),
+            "1,234.50 USD"
+        );
+        assert_eq!(Money::krw(-1_500_000.0).to_string(), "-1,500,000 KRW");
+        assert_eq!(Money::new(-0.75, Currency::Usd).to_string(), "-0.75 USD");
+        assert_eq!(Money::new(-0.001, Currency::Usd).to_string(), "0.00 USD");
+        assert_eq!(Money::new(-0.4, Currency::Jpy).to_string(), "0 JPY");
+    }
+
+    #[test]
+    fn shares_for_notional_counts_exact_multiples() {
+        let rates = rates();
+        let usd = |amount| Money::new(amount, Currency::Usd);
+        assert_eq!(
+            shares_for_notional(usd(0.3), usd(0.1), 1, &rates).unwrap(),
+            3
+        );
+        assert_eq!(
+            shares_for_notional(usd(1000.0), usd(250.0), 1, &rates).unwrap(),
+            4
+        );
+        assert_eq!(
+            shares_for_notional(usd(999.99), usd(250.0), 1, &rates).unwrap(),
+            3
+        );
+        // 원화 → 달러 환산: 1,000,000원 = 714.2857 USD → 251.45달러 2주
+        assert_eq!(
+            shares_for_notional(Money::krw(1_000_000.0), usd(251.45), 1, &rates).unwrap(),
+            2
+        );
+    }
+
+    #[test]
+    fn shares_for_notional_rounds_down_to_lot_size() {
+        let rates = rates();
+        let jpy = |amount| Money::new(amount, Currency::Jpy);
+        // 3,000엔 × 100주 단위: 1,000,000엔이면 300주, 899,999엔이면 200주
+        assert_eq!(
+            shares_for_notional(jpy(1_000_000.0), jpy(3000.0), 100, &rates).unwrap(),
+            300
+        );
+        assert_eq!(
+            shares_for_notional(jpy(899_999.0), jpy(3000.0), 100, &rates).unwrap(),
+            200
+        );
+        // 정확히 한 단위
+        assert_eq!(
+            shares_for_notional(jpy(300_000.0), jpy(3000.0), 100, &rates).unwrap(),
+            100
+        );
+        assert!(shares_for_notional(jpy(299_999.0), jpy(3000.0), 100, &rates).is_err());
+        // 단위 0은 1주로 취급
+        assert_eq!(
+            shares_for_notional(jpy(10_000.0), jpy(3000.0), 0, &rates).unwrap(),
+            3
+        );
+        assert!(shares_for_notional(jpy(10_000.0), jpy(0.0), 1, &rates).is_err());
+        assert!(
+            shares_for_notional(jpy(10_000.0), Money::new(1.0, Currency::Cny), 1, &rates).is_err()
+        );
+    }
+}
@@ -0,0 +1,526 @@
+use crate::domestic::quotations::DomesticExtendedQuotations;
+use crate::overseas::{Exchange, OverseasQuotations};
+use crate::provider::KISProvider;
+use crate::types::{Level, MarketDivision, Orderbook, OrderbookTotals};
+use crate::websocket::domestic::models::{
+    FutureOptionQuoteData, StockFuturesQuoteData, StockQuoteData,
+};
+use crate::websocket::overseas::models::{OverseasFutureOptionQuoteData, OverseasQuotesData};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+// ======================================================
+// 호가창 (Order Book)
+// ======================================================
+//
+// REST 호가 조회로 초기 상태를 만든 뒤 실시간 호가(H0STASP0/H0UNASP0/H0NXASP0, HDFSASP0 등)로 갱신합니다.
+// 실시간 호가는 매번 전체 단계를 내려주므로 증분 반영 없이 전체를 교체합니다.
+// 단계/총잔량은 REST 호가 응답(`types::Orderbook`)과 같은 `Level`/`OrderbookTotals`를 씁니다.
+
+/// 실시간 호가로 갱신되는 호가창
+///
+/// 단계는 REST 호가 응답과 같은 [`Level`]을 쓰며, 빈 단계(가격 0)는 뺀 채 최우선 호가부터 담습니다.
+/// [`Self::apply`] 후 각 단계의 `change`와 `totals`의 증감은 직전 호가창 대비 값입니다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LiveOrderBook {
+    pub symbol: String,
+    /// 호가 시각 (HHMMSS, 해외는 현지시간)
+    pub time: String,
+    /// 매도호가 (최우선 호가부터)
+    pub asks: Vec<Level>,
+    /// 매수호가 (최우선 호가부터)
+    pub bids: Vec<Level>,
+    pub totals: OrderbookTotals,
+}
+
+/// 실시간 호가 1건을 반영한 결과 ([`LiveOrderBook::apply`] 반환값)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BookEvent {
+    /// 반영 후 호가창
+    pub book: LiveOrderBook,
+    /// 잔량이 바뀐 매도 단계 (새 단계 포함, 사라진 단계는 잔량 0)
+    pub changed_asks: Vec<Level>,
+    /// 잔량이 바뀐 매수 단계 (새 단계 포함, 사라진 단계는 잔량 0)
+    pub changed_bids: Vec<Level>,
+    /// 총매도잔량 증감
+    pub ask_change: i64,
+    /// 총매수잔량 증감
+    pub bid_change: i64,
+}
+
+impl BookEvent {
+    /// 단계나 총잔량에 변화가 없는 호가
+    pub fn is_unchanged(&self) -> bool {
+        self.changed_asks.is_empty()
+            && self.changed_bids.is_empty()
+            && self.ask_change == 0
+            && self.bid_change == 0
+    }
+}
+
+/// 호가창으로 변환 가능한 실시간 호가 모델
+pub trait BookUpdate {
+    fn to_order_book(&self) -> LiveOrderBook;
+}
+
+impl LiveOrderBook {
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 국내주식 호가 조회(get_orderbook)로 초기화 (KRX/NXT/통합)
+    pub async fn fetch_domestic(
+        provider: &KISProvider,
+        market: MarketDivision,
+        stock_code: &str,
+    ) -> Result<Self, Box<dyn Error>> {
+        let response = provider.get_orderbook(market, stock_code).await?;
+        Ok(Self::from_snapshot(stock_code, &response.body))
+    }
+
+    /// 해외주식 호가 조회(get_overseas_asking_price)로 초기화
+    pub async fn fetch_overseas(
+        provider: &KISProvider,
+        exchange: Exchange,
+        symbol: &str,
+    ) -> Result<Self, Box<dyn Error>> {
+        let response = provider.get_overseas_asking_price(exchange, symbol).await?;
+        Ok(Self::from_snapshot(symbol, &response.body))
+    }
+
+    /// REST 호가 응답(`Orderbook`)에서 생성
+    pub fn from_snapshot(symbol: &str, snapshot: &Orderbook) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            time: snapshot.time.clone(),
+            asks: compact(snapshot.asks.to_vec()),
+            bids: compact(snapshot.bids.to_vec()),
+            totals: snapshot.totals,
+        }
+    }
+
+    /// 실시간 호가 반영
+    ///
+    /// 호가는 매번 전체 단계가 오므로 통째로 교체하고, 같은 가격 단계의 잔량과
+    /// 총잔량을 직전 호가창과 비교해 증감을 채웁니다. 새로 생긴 가격 단계는 잔량 전체가 증감입니다.
+    /// 반영 후 호가창과 바뀐 단계는 [`BookEvent`]로 돌려줍니다.
+    pub fn apply<Q: BookUpdate>(&mut self, quote: &Q) -> BookEvent {
+        let mut next = quote.to_order_book();
+        fill_changes(&mut next.asks, &self.asks);
+        fill_changes(&mut next.bids, &self.bids);
+        next.totals.ask_change = delta(next.totals.ask_quantity, self.totals.ask_quantity);
+        next.totals.bid_change = delta(next.totals.bid_quantity, self.totals.bid_quantity);
+        if next.symbol.is_empty() {
+            next.symbol = std::mem::take(&mut self.symbol);
+        }
+        let changed_asks = changed_levels(&next.asks, &self.asks);
+        let changed_bids = changed_levels(&next.bids, &self.bids);
+        *self = next;
+        BookEvent {
+            book: self.clone(),
+            changed_asks,
+            changed_bids,
+            ask_change: self.totals.ask_change,
+            bid_change: self.totals.bid_change,
+        }
+    }
+
+    /// 최우선 매도호가
+    pub fn best_ask(&self) -> Option<Level> {
+        self.asks.first().copied()
+    }
+
+    /// 최우선 매수호가
+    pub fn best_bid(&self) -> Option<Level> {
+        self.bids.first().copied()
+    }
+
+    /// 매도/매수 최우선 호가 차이
+    pub fn spread(&self) -> Option<f64> {
+        Some(self.best_ask()?.price - self.best_bid()?.price)
+    }
+
+    /// 최우선 호가 중간값
+    pub fn mid_price(&self) -> Option<f64> {
+        Some((self.best_ask()?.price + self.best_bid()?.price) / 2.0)
+    }
+
+    /// 상위 N단계만 남긴 호가창
+    pub fn depth(&self, levels: usize) -> LiveOrderBook {
+        LiveOrderBook {
+            asks: self.asks.iter().take(levels).copied().collect(),
+            bids: self.bids.iter().take(levels).copied().collect(),
+            ..self.clone()
+        }
+    }
+
+    /// 상위 N단계 매도잔량 합계
+    pub fn cumulative_ask_quantity(&self, levels: usize) -> u64 {
+        self.asks.iter().take(levels).map(|l| l.quantity).sum()
+    }
+
+    /// 상위 N단계 매수잔량 합계
+    pub fn cumulative_bid_quantity(&self, levels: usize) -> u64 {
+        self.bids.iter().take(levels).map(|l| l.quantity).sum()
+    }
+
+    /// 상위 N단계 호가 불균형 ((매수-매도)/(매수+매도), -1.0 ~ 1.0)
+    pub fn imbalance(&self, levels: usize) -> Option<f64> {
+        let bid = self.cumulative_bid_quantity(levels) as f64;
+        let ask = self.cumulative_ask_quantity(levels) as f64;
+        if bid + ask == 0.0 {
+            return None;
+        }
+        Some((bid - ask) / (bid + ask))
+    }
+}
+
+impl BookUpdate for StockQuoteData {
+    fn to_order_book(&self) -> LiveOrderBook {
+        let asks = [
+            (&self.askp1, &self.askp_rsqn1),
+            (&self.askp2, &self.askp_rsqn2),
+            (&self.askp3, &self.askp_rsqn3),
+            (&self.askp4, &self.askp_rsqn4),
+            (&self.askp5, &self.askp_rsqn5),
+            (&self.askp6, &self.askp_rsqn6),
+            (&self.askp7, &self.askp_rsqn7),
+            (&self.askp8, &self.askp_rsqn8),
+            (&self.askp9, &self.askp_rsqn9),
+            (&self.askp10, &self.askp_rsqn10),
+        ];
+        let bids = [
+            (&self.bidp1, &self.bidp_rsqn1),
+            (&self.bidp2, &self.bidp_rsqn2),
+            (&self.bidp3, &self.bidp_rsqn3),
+            (&self.bidp4, &self.bidp_rsqn4),
+            (&self.bidp5, &self.bidp_rsqn5),
+            (&self.bidp6, &self.bidp_rsqn6),
+            (&self.bidp7, &self.bidp_rsqn7),
+            (&self.bidp8, &self.bidp_rsqn8),
+            (&self.bidp9, &self.bidp_rsqn9),
+            (&self.bidp10, &self.bidp_rsqn10),
+        ];
+        LiveOrderBook {
+            symbol: self.mksc_shrn_iscd.clone(),
+            time: self.bsop_hour.clone(),
+            asks: compact(asks.iter().map(|(p, q)| level(p, q)).collect()),
+            bids: compact(bids.iter().map(|(p, q)| level(p, q)).collect()),
+            totals: totals(
+                quantity(&self.total_askp_rsqn),
+                quantity(&self.total_bidp_rsqn),
+            ),
+        }
+    }
+}
+
+impl BookUpdate for OverseasQuotesData {
+    fn to_order_book(&self) -> LiveOrderBook {
+        let asks = [
+            (&self.pask1, &self.vask1),
+            (&self.pask2, &self.vask2),
+            (&self.pask3, &self.vask3),
+            (&self.pask4, &self.vask4),
+            (&self.pask5, &self.vask5),
+            (&self.pask6, &self.vask6),
+            (&self.pask7, &self.vask7),
+            (&self.pask8, &self.vask8),
+            (&self.pask9, &self.vask9),
+            (&self.pask10, &self.vask10),
+        ];
+        let bids = [
+            (&self.pbid1, &self.vbid1),
+            (&self.pbid2, &self.vbid2),
+            (&self.pbid3, &self.vbid3),
+            (&self.pbid4, &self.vbid4),
+            (&self.pbid5, &self.vbid5),
+            (&self.pbid6, &self.vbid6),
+            (&self.pbid7, &self.vbid7),
+            (&self.pbid8, &self.vbid8),
+            (&self.pbid9, &self.vbid9),
+            (&self.pbid10, &self.vbid10),
+        ];
+        LiveOrderBook {
+            symbol: self.symb.clone(),
+            time: self.xhms.clone(),
+            asks: compact(asks.iter().map(|(p, q)| level(p, q)).collect()),
+            bids: compact(bids.iter().map(|(p, q)| level(p, q)).collect()),
+            totals: totals(quantity(&self.avol), quantity(&self.bvol)),
+        }
+    }
+}
+
+impl BookUpdate for FutureOptionQuoteData {
+    fn to_order_book(&self) -> LiveOrderBook {
+        let asks = [
+            (&self.askp1, &self.askp_rsqn1),
+            (&self.askp2, &self.askp_rsqn2),
+            (&self.askp3, &self.askp_rsqn3),
+            (&self.askp4, &self.askp_rsqn4),
+            (&self.askp5, &self.askp_rsqn5),
+        ];
+        let bids = [
+            (&self.bidp1, &self.bidp_rsqn1),
+            (&self.bidp2, &self.bidp_rsqn2),
+            (&self.bidp3, &self.bidp_rsqn3),
+            (&self.bidp4, &self.bidp_rsqn4),
+            (&self.bidp5, &self.bidp_rsqn5),
+        ];
+        LiveOrderBook {
+            symbol: self.shrn_iscd.clone(),
+            time: self.bsop_hour.clone(),
+            asks: compact(asks.iter().map(|(p, q)| level(p, q)).collect()),
+            bids: compact(bids.iter().map(|(p, q)| level(p, q)).collect()),
+            totals: totals(
+                quantity(&self.total_askp_rsqn),
+                quantity(&self.total_bidp_rsqn),
+            ),
+        }
+    }
+}
+
+impl BookUpdate for StockFuturesQuoteData {
+    fn to_order_book(&self) -> LiveOrderBook {
+        let asks = [
+            (&self.askp1, &self.askp_rsqn1),
+            (&self.askp2, &self.askp_rsqn2),
+            (&self.askp3, &self.askp_rsqn3),
+            (&self.askp4, &self.askp_rsqn4),
+            (&self.askp5, &self.askp_rsqn5),
+            (&self.askp6, &self.askp_rsqn6),
+            (&self.askp7, &self.askp_rsqn7),
+            (&self.askp8, &self.askp_rsqn8),
+            (&self.askp9, &self.askp_rsqn9),
+            (&self.askp10, &self.askp_rsqn10),
+        ];
+        let bids = [
+            (&self.bidp1, &self.bidp_rsqn1),
+            (&self.bidp2, &self.bidp_rsqn2),
+            (&self.bidp3, &self.bidp_rsqn3),
+            (&self.bidp4, &self.bidp_rsqn4),
+            (&self.bidp5, &self.bidp_rsqn5),
+            (&self.bidp6, &self.bidp_rsqn6),
+            (&self.bidp7, &self.bidp_rsqn7),
+            (&self.bidp8, &self.bidp_rsqn8),
+            (&self.bidp9, &self.bidp_rsqn9),
+            (&self.bidp10, &self.bidp_rsqn10),
+        ];
+        LiveOrderBook {
+            symbol: self.futs_shrn_iscd.clone(),
+            time: self.bsop_hour.clone(),
+            asks: compact(asks.iter().map(|(p, q)| level(p, q)).collect()),
+            bids: compact(bids.iter().map(|(p, q)| level(p, q)).collect()),
+            totals: totals(
+                quantity(&self.total_askp_rsqn),
+                quantity(&self.total_bidp_rsqn),
+            ),
+        }
+    }
+}
+
+impl BookUpdate for OverseasFutureOptionQuoteData {
+    fn to_order_book(&self) -> LiveOrderBook {
+        let asks = [
+            (&self.ask_price_1, &self.ask_qntt_1),
+            (&self.ask_price_2, &self.ask_qntt_2),
+            (&self.ask_price_3, &self.ask_qntt_3),
+            (&self.ask_price_4, &self.ask_qntt_4),
+            (&self.ask_price_5, &self.ask_qntt_5),
+        ];
+        let bids = [
+            (&self.bid_price_1, &self.bid_qntt_1),
+            (&self.bid_price_2, &self.bid_qntt_2),
+            (&self.bid_price_3, &self.bid_qntt_3),
+            (&self.bid_price_4, &self.bid_qntt_4),
+            (&self.bid_price_5, &self.bid_qntt_5),
+        ];
+        let asks: Vec<Level> = compact(asks.iter().map(|(p, q)| level(p, q)).collect());
+        let bids: Vec<Level> = compact(bids.iter().map(|(p, q)| level(p, q)).collect());
+        // 해외선물옵션 호가에는 총잔량이 없어 5단계 합계로 대신합니다
+        LiveOrderBook {
+            symbol: self.series_cd.clone(),
+            time: self.recv_time.clone(),
+            totals: totals(
+                asks.iter().map(|l| l.quantity).sum(),
+                bids.iter().map(|l| l.quantity).sum(),
+            ),
+            asks,
+            bids,
+        }
+    }
+}
+
+fn quantity(text: &str) -> u64 {
+    text.trim()
+        .parse::<f64>()
+        .map(|v| v.max(0.0) as u64)
+        .unwrap_or(0)
+}
+
+fn level(price: &str, quantity_text: &str) -> Level {
+    Level {
+        price: price.trim().parse().unwrap_or(0.0),
+        quantity: quantity(quantity_text),
+        change: 0,
+    }
+}
+
+fn totals(ask_quantity: u64, bid_quantity: u64) -> OrderbookTotals {
+    OrderbookTotals {
+        ask_quantity,
+        bid_quantity,
+        ..Default::default()
+    }
+}
+
+fn delta(current: u64, previous: u64) -> i64 {
+    current as i64 - previous as i64
+}
+
+/// 직전 호가창의 같은 가격 단계와 비교해 잔량 증감 기록
+fn fill_changes(levels: &mut [Level], previous: &[Level]) {
+    for level in levels {
+        let before = previous
+            .iter()
+            .find(|p| p.price == level.price)
+            .map_or(0, |p| p.quantity);
+        level.change = delta(level.quantity, before);
+    }
+}
+
+/// 잔량이 바뀐 단계와 직전 호가창에서 사라진 단계 (사라진 단계는 잔량 0)
+fn changed_levels(levels: &[Level], previous: &[Level]) -> Vec<Level> {
+    let removed = previous
+        .iter()
+        .filter(|p| levels.iter().all(|l| l.price != p.price))
+        .map(|p| Level {
+            price: p.price,
+            quantity: 0,
+            change: -(p.quantity as i64),
+        });
+    levels
+        .iter()
+        .filter(|l| l.change != 0)
+        .copied()
+        .chain(removed)
+        .collect()
+}
+
+/// 가격이 없는(0) 빈 단계 제거
+fn compact(levels: Vec<Level>) -> Vec<Level> {
+    levels.into_iter().filter(|l| l.price > 0.0).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Quote(Vec<(f64, u64)>, Vec<(f64, u64)>);
+
+    impl BookUpdate for Quote {
+        fn to_order_book(&self) -> LiveOrderBook {
+            let levels = |levels: &[(f64, u64)]| {
+                compact(
+                    levels
+                        .iter()
+                        .map(|&(price, quantity)| Level {
+                            price,
+                            quantity,
+                            change: 0,
+                        })
+                        .collect(),
+                )
+            };
+            let asks = levels(&self.0);
+            let bids = levels(&self.1);
+            LiveOrderBook {
+                time: "090000".to_string(),
+                totals: totals(
+                    asks.iter().map(|l| l.quantity).sum(),
+                    bids.iter().map(|l| l.quantity).sum(),
+                ),
+                asks,
+                bids,
+                ..Default::default()
+            }
+        }
+    }
+
+    #[test]
+    fn apply_tracks_level_and_total_changes() {
+        let mut book = LiveOrderBook::new("005930");
+        book.apply(&Quote(
+            vec![(100.0, 10), (101.0, 20)],
+            vec![(99.0, 30), (0.0, 0)],
+        ));
+        assert_eq!(book.symbol, "005930");
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.totals.ask_quantity, 30);
+        assert_eq!(book.totals.ask_change, 30);
+        assert_eq!(book.asks[0].change, 10);
+
+        book.apply(&Quote(
+            vec![(100.0, 4), (101.0, 25)],
+            vec![(99.5, 7), (99.0, 30)],
+        ));
+        let changes: Vec<i64> = book.asks.iter().map(|l| l.change).collect();
+        assert_eq!(changes, [-6, 5]);
+        let changes: Vec<i64> = book.bids.iter().map(|l| l.change).collect();
+        assert_eq!(changes, [7, 0]);
+        assert_eq!(book.totals.ask_change, -1);
+        assert_eq!(book.totals.bid_change, 7);
+        assert_eq!(book.spread(), Some(0.5));
+        assert_eq!(book.mid_price(), Some(99.75));
+    }
+
+    #[test]
+    fn cumulative_quantity_and_imbalance() {
+        let mut book = LiveOrderBook::new("005930");
+        assert_eq!(book.imbalance(5), None);
+
+        book.apply(&Quote(
+            vec![(100.0, 10), (101.0, 30), (102.0, 60)],
+            vec![(99.0, 50), (98.0, 10)],
+        ));
+        assert_eq!(book.cumulative_ask_quantity(2), 40);
+        assert_eq!(book.cumulative_ask_quantity(10), 100);
+        assert_eq!(book.cumulative_bid_quantity(1), 50);
+        // (50 - 10) / (50 + 10)
+        assert_eq!(book.imbalance(1), Some(40.0 / 60.0));
+        // (60 - 100) / (60 + 100)
+        assert_eq!(book.imbalance(5), Some(-0.25));
+        assert_eq!(book.depth(1).asks.len(), 1);
+    }
+
+    #[test]
+    fn apply_returns_changed_levels() {
+        let mut book = LiveOrderBook::new("005930");
+        let event = book.apply(&Quote(vec![(100.0, 10), (101.0, 20)], vec![(99.0, 30)]));
+        assert_eq!(event.changed_asks.len(), 2);
+        assert_eq!((event.ask_change, event.bid_change), (30, 30));
+        assert_eq!(event.book, book);
+
+        // 99.0 매수 단계가 사라지고 98.5가 새로 생김, 101.0은 그대로
+        let event = book.apply(&Quote(vec![(100.0, 4), (101.0, 20)], vec![(98.5, 12)]));
+        let asks: Vec<(f64, u64, i64)> = event
+            .changed_asks
+            .iter()
+            .map(|l| (l.price, l.quantity, l.change))
+            .collect();
+        assert_eq!(asks, [(100.0, 4, -6)]);
+        let bids: Vec<(f64, u64, i64)> = event
+            .changed_bids
+            .iter()
+            .map(|l| (l.price, l.quantity, l.change))
+            .collect();
+        assert_eq!(bids, [(98.5, 12, 12), (99.0, 0, -30)]);
+        assert_eq!((event.ask_change, event.bid_change), (-6, -18));
+        assert_eq!(event.book.best_bid().map(|l| l.price), Some(98.5));
+
+        let event = book.apply(&Quote(vec![(100.0, 4), (101.0, 20)], vec![(98.5, 12)]));
+        assert!(event.is_unchanged());
+    }
+}
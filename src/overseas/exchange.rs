@@ -0,0 +1,214 @@
+use crate::calendar::Market;
+use crate::money::Currency;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// 해외 거래소
+///
+/// 같은 거래소라도 API마다 코드가 다릅니다.
+/// - 시세(EXCD): `NAS`, `NYS`, `AMS`, `HKS`, `TSE`, `SHS`, `SZS`, `HNX`, `HSX`
+/// - 주문/잔고(OVRS_EXCG_CD): `NASD`, `NYSE`, `AMEX`, `SEHK`, `TKSE`, `SHAA`, `SZAA`, `HASE`, `VNSE`
+/// - 상품유형(PRDT_TYPE_CD): `512`, `513`, `529`, `501`, `515`, `551`, `552`, `507`, `508`
+/// - 실시간 tr_key: `D` + 시세코드 + 종목코드 (미국 주간거래는 `R` + `BAQ`/`BAY`/`BAA` + 종목코드)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Exchange {
+    #[default]
+    Nasdaq,
+    Nyse,
+    Amex,
+    HongKong,
+    Tokyo,
+    Shanghai,
+    Shenzhen,
+    Hanoi,
+    HoChiMinh,
+}
+
+impl Exchange {
+    pub const ALL: [Exchange; 9] = [
+        Self::Nasdaq,
+        Self::Nyse,
+        Self::Amex,
+        Self::HongKong,
+        Self::Tokyo,
+        Self::Shanghai,
+        Self::Shenzhen,
+        Self::Hanoi,
+        Self::HoChiMinh,
+    ];
+
+    /// 시세 API 거래소 코드 (EXCD)
+    pub fn quote_code(&self) -> &'static str {
+        match self {
+            Self::Nasdaq => "NAS",
+            Self::Nyse => "NYS",
+            Self::Amex => "AMS",
+            Self::HongKong => "HKS",
+            Self::Tokyo => "TSE",
+            Self::Shanghai => "SHS",
+            Self::Shenzhen => "SZS",
+            Self::Hanoi => "HNX",
+            Self::HoChiMinh => "HSX",
+        }
+    }
+
+    /// 미국 주간거래 시세 코드 (BAQ/BAY/BAA, 미국 외 거래소는 `None`)
+    pub fn daytime_quote_code(&self) -> Option<&'static str> {
+        match self {
+            Self::Nasdaq => Some("BAQ"),
+            Self::Nyse => Some("BAY"),
+            Self::Amex => Some("BAA"),
+            _ => None,
+        }
+    }
+
+    /// 주문/잔고 API 거래소 코드 (OVRS_EXCG_CD)
+    pub fn order_code(&self) -> &'static str {
+        match self {
+            Self::Nasdaq => "NASD",
+            Self::Nyse => "NYSE",
+            Self::Amex => "AMEX",
+            Self::HongKong => "SEHK",
+            Self::Tokyo => "TKSE",
+            Self::Shanghai => "SHAA",
+            Self::Shenzhen => "SZAA",
+            Self::Hanoi => "HASE",
+            Self::HoChiMinh => "VNSE",
+        }
+    }
+
+    /// 상품유형코드 (PRDT_TYPE_CD, 상품기본정보 등)
+    pub fn product_type_code(&self) -> &'static str {
+        match self {
+            Self::Nasdaq => "512",
+            Self::Nyse => "513",
+            Self::Amex => "529",
+            Self::HongKong => "501",
+            Self::Tokyo => "515",
+            Self::Shanghai => "551",
+            Self::Shenzhen => "552",
+            Self::Hanoi => "507",
+            Self::HoChiMinh => "508",
+        }
+    }
+
+    /// 거래통화 (`code()`가 TR_CRCY_CD)
+    pub fn currency(&self) -> Currency {
+        match self {
+            Self::Nasdaq | Self::Nyse | Self::Amex => Currency::Usd,
+            Self::HongKong => Currency::Hkd,
+            Self::Tokyo => Currency::Jpy,
+            Self::Shanghai | Self::Shenzhen => Currency::Cny,
+            Self::Hanoi | Self::HoChiMinh => Currency::Vnd,
+        }
+    }
+
+    /// 국가코드 (NATN_CD, ISO 3166 숫자코드)
+    pub fn nation_code(&self) -> &'static str {
+        match self {
+            Self::Nasdaq | Self::Nyse | Self::Amex => "840",
+            Self::HongKong => "344",
+            Self::Tokyo => "392",
+            Self::Shanghai | Self::Shenzhen => "156",
+            Self::Hanoi | Self::HoChiMinh => "704",
+        }
+    }
+
+    /// 국가 (ISO 3166 2자리 코드)
+    pub fn country(&self) -> &'static str {
+        match self {
+            Self::Nasdaq | Self::Nyse | Self::Amex => "US",
+            Self::HongKong => "HK",
+            Self::Tokyo => "JP",
+            Self::Shanghai | Self::Shenzhen => "CN",
+            Self::Hanoi | Self::HoChiMinh => "VN",
+        }
+    }
+
+    pub fn is_us(&self) -> bool {
+        matches!(self, Self::Nasdaq | Self::Nyse | Self::Amex)
+    }
+
+    /// 실시간 구독 키 (예: `DNASAAPL`)
+    pub fn realtime_key(&self, symbol: &str) -> String {
+        format!("D{}{}", self.quote_code(), symbol)
+    }
+
+    /// 미국 주간거래 실시간 구독 키 (예: `RBAQAAPL`)
+    pub fn daytime_realtime_key(&self, symbol: &str) -> Option<String> {
+        self.daytime_quote_code()
+            .map(|code| format!("R{code}{symbol}"))
+    }
+
+    /// 세션 시간표가 있는 달력 시장 (베트남은 `None`)
+    pub fn calendar_market(&self) -> Option<Market> {
+        match self {
+            Self::Nasdaq | Self::Nyse | Self::Amex => Some(Market::Us),
+            Self::HongKong => Some(Market::HongKong),
+            Self::Tokyo => Some(Market::Japan),
+            Self::Shanghai | Self::Shenzhen => Some(Market::China),
+            Self::Hanoi | Self::HoChiMinh => None,
+        }
+    }
+
+    /// 종목 마스터파일 이름 (예: `NASMST.COD`)
+    pub fn master_file_name(&self) -> String {
+        format!("{}MST.COD", self.quote_code())
+    }
+
+    /// 시세코드, 주문코드, 주간거래코드, 상품유형코드 중 무엇이든 변환
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_ascii_uppercase();
+        Self::ALL.into_iter().find(|exchange| {
+            exchange.quote_code() == code
+                || exchange.order_code() == code
+                || exchange.product_type_code() == code
+                || exchange.daytime_quote_code() == Some(code.as_str())
+        })
+    }
+}
+
+impl fmt::Display for Exchange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.quote_code())
+    }
+}
+
+impl FromStr for Exchange {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        Self::from_code(code).ok_or_else(|| format!("알 수 없는 거래소 코드입니다: {code}"))
+    }
+}
+
+/// 요청 구조체 필드를 시세 코드(EXCD)로 직렬화
+pub mod quote_code {
+    use super::Exchange;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(exchange: &Exchange, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(exchange.quote_code())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Exchange, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(D::Error::custom)
+    }
+}
+
+/// 요청 구조체 필드를 주문 코드(OVRS_EXCG_CD)로 직렬화
+pub mod order_code {
+    use super::Exchange;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub fn serialize<S: Serializer>(exchange: &Exchange, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(exchange.order_code())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Exchange, D::Error> {
+        let code = String::deserialize(deserializer)?;
+        code.parse().map_err(D::Error::custom)
+    }
+}
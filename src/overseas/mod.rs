@@ -1,13 +1,37 @@
-use crate::oauth::Oauth;
+pub mod exchange;
+pub mod order;
+pub mod ranking;
+pub mod routing;
+
+pub use exchange::Exchange;
+pub use order::{
+    AlgoWindow, OverseasOrder, OverseasOrderKind, OverseasRevision, OverseasRevisionAction,
+    PreparedOverseasOrder, PreparedOverseasRevision,
+};
+pub use ranking::{
+    MarketCapRankRequest, MarketCapRankRow, NewHighLowRankRequest, OverseasRankingQuote,
+    OverseasRankingSummary, OverseasRankings, OverseasScreenerRequest, OverseasScreenerRow,
+    PriceChangeRankRow, PriceFluctuationRankRequest, RankDirection, RankingPage, RankingPeriod,
+    RankingWindow, TradeAmountRankRequest, TradeAmountRankRow, TradeGrowthRankRequest,
+    TradeGrowthRankRow, TradeTurnoverRankRequest, TradeTurnoverRankRow, TradeVolumeRankRequest,
+    TradeVolumeRankRow, UpDownRateRankRequest, VolumePowerRankRequest, VolumePowerRankRow,
+    VolumeRange, VolumeSurgeRankRequest, VolumeSurgeRankRow,
+};
+pub use routing::{OrderSide, OverseasOrderRoute, OverseasOrderRouting, RoutedOrder};
+
 use crate::provider::KISProvider;
-use crate::types::{AccountInfo, ContinuationKey};
+use crate::types::{AccountInfo, ContinuationKey, Level, Orderbook, OrderbookTotals};
 use crate::utils::{
-    ApiEndpoint, ApiHeader, ApiResponse, RawApiBody, TrId, call_api, call_get_api, call_post_api,
-    create_hashkey,
+    ApiEndpoint, ApiHeader, ApiResponse, RawApiBody, TrId, call_get_api, call_post_api,
+    create_hashkey, json_num, json_str, request_interval,
 };
 use async_trait::async_trait;
+use futures_util::future::join_all;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
+use std::time::Duration;
 
 
 // ========================================================
@@ -17,13 +41,14 @@ use std::error::Error;
 pub struct OverseasPriceQuery<'a> {
     #[serde(rename = "AUTH")]
     pub auth: &'a str, // "P"
-    #[serde(rename = "EXCD")]
-    pub exchg_code: &'a str, // NAS, NYS, AMS, HKS ...
+    #[serde(rename = "EXCD", with = "exchange::quote_code")]
+    pub exchange: Exchange,
     #[serde(rename = "SYMB")]
     pub symbol: &'a str, // AAPL, TSLA ...
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasPriceOutput {
     pub rsym: String,
     pub open: String,
@@ -40,41 +65,6 @@ pub struct OverseasPriceOutput {
     pub curr: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasPriceResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output: OverseasPriceOutput,
-}
-
-pub async fn get_overseas_price(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    query: OverseasPriceQuery<'_>,
-) -> Result<OverseasPriceOutput, Box<dyn Error>> {
-    let url =
-        "https://openapi.koreainvestment.com:9443/uapi/overseas-price/v1/quotations/price-detail";
-
-    let response: OverseasPriceResponse = call_api(
-        oauth,
-        header,
-        url,
-        "HHDFS76200200",
-        &[
-            ("AUTH", query.auth),
-            ("EXCD", query.exchg_code),
-            ("SYMB", query.symbol),
-        ],
-    )
-    .await?;
-
-    if response.rt_cd != "0" {
-        return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-    }
-    Ok(response.output)
-}
-
 // ========================================================
 // 2. 해외주식 상품기본정보
 // ========================================================
@@ -86,7 +76,8 @@ pub struct OverseasProductInfoQuery<'a> {
     pub product_number: &'a str,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasProductInfoOutput {
     pub std_pdno: String,
     pub prdt_eng_name: String,
@@ -145,40 +136,6 @@ pub struct OverseasProductInfoOutput {
     pub last_rcvg_dtime: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasProductInfoResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output: OverseasProductInfoOutput,
-}
-
-pub async fn get_overseas_product_info(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    query: OverseasProductInfoQuery<'_>,
-) -> Result<OverseasProductInfoOutput, Box<dyn Error>> {
-    let url =
-        "https://openapi.koreainvestment.com:9443/uapi/overseas-price/v1/quotations/search-info";
-
-    let response: OverseasProductInfoResponse = call_api(
-        oauth,
-        header,
-        url,
-        "CTPF1702R",
-        &[
-            ("PRDT_TYPE_CD", query.product_type_code),
-            ("PDNO", query.product_number),
-        ],
-    )
-    .await?;
-
-    if response.rt_cd != "0" {
-        return Err(format!("API 오류: {} ({})", response.msg1, response.msg_cd).into());
-    }
-    Ok(response.output)
-}
-
 // ========================================================
 // 3. 해외주식 종목/지수/환율기간별시세 (일/주/월/년)
 // ========================================================
@@ -213,7 +170,8 @@ pub struct OverseasDailyChartOutput1 {
     pub ovrs_prod_lwpr: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasDailyChartOutput2 {
     pub stck_bsop_date: String,
     pub ovrs_nmix_prpr: String,
@@ -224,44 +182,6 @@ pub struct OverseasDailyChartOutput2 {
     pub mod_yn: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasDailyChartResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output1: OverseasDailyChartOutput1,
-    pub output2: Vec<OverseasDailyChartOutput2>,
-}
-
-pub async fn get_overseas_daily_chartprice(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    q: OverseasDailyChartQuery<'_>,
-) -> Result<(OverseasDailyChartOutput1, Vec<OverseasDailyChartOutput2>), Box<dyn Error>> {
-    let url =
-        "https://openapi.koreainvestment.com:9443/uapi/overseas-price/v1/quotations/inquire-daily-chartprice";
-
-    let resp: OverseasDailyChartResponse = call_api(
-        oauth,
-        header,
-        url,
-        "FHKST03030100",
-        &[
-            ("FID_COND_MRKT_DIV_CODE", q.market_div_code),
-            ("FID_INPUT_ISCD", q.symbol),
-            ("FID_INPUT_DATE_1", q.start_date),
-            ("FID_INPUT_DATE_2", q.end_date),
-            ("FID_PERIOD_DIV_CODE", q.period_div_code),
-        ],
-    )
-    .await?;
-
-    if resp.rt_cd != "0" {
-        return Err(format!("API 오류: {} ({})", resp.msg1, resp.msg_cd).into());
-    }
-    Ok((resp.output1, resp.output2))
-}
-
 // ========================================================
 // 4. 해외주식 기간별 시세 (일/주/월)
 // ========================================================
@@ -269,8 +189,8 @@ pub async fn get_overseas_daily_chartprice(
 pub struct OverseasDailyPriceQuery<'a> {
     #[serde(rename = "AUTH")]
     pub auth: &'a str, // "" (Null)
-    #[serde(rename = "EXCD")]
-    pub exchg_code: &'a str, // NAS, NYS, AMS, HKS ...
+    #[serde(rename = "EXCD", with = "exchange::quote_code")]
+    pub exchange: Exchange,
     #[serde(rename = "SYMB")]
     pub symbol: &'a str, // 종목코드 (ex. TSLA)
     #[serde(rename = "GUBN")]
@@ -284,14 +204,16 @@ pub struct OverseasDailyPriceQuery<'a> {
     pub keyb: Option<&'a str>, // NEXT KEY BUFF
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasDailyPriceOutput1 {
     pub rsym: String,
     pub zdiv: String,
     pub nrec: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasDailyPriceOutput2 {
     pub xymd: String,
     pub clos: String,
@@ -309,56 +231,12 @@ pub struct OverseasDailyPriceOutput2 {
     pub vask: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasDailyPriceResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output1: OverseasDailyPriceOutput1,
-    pub output2: Vec<OverseasDailyPriceOutput2>,
-}
-
-pub async fn get_overseas_daily_price(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    q: OverseasDailyPriceQuery<'_>,
-) -> Result<(OverseasDailyPriceOutput1, Vec<OverseasDailyPriceOutput2>), Box<dyn Error>> {
-    let url =
-        "https://openapi.koreainvestment.com:9443/uapi/overseas-price/v1/quotations/dailyprice";
-
-    let mut params = vec![
-        ("AUTH", q.auth),
-        ("EXCD", q.exchg_code),
-        ("SYMB", q.symbol),
-        ("GUBN", q.gubn),
-        ("BYMD", q.bymd),
-        ("MODP", q.modp),
-    ];
-    if let Some(keyb) = q.keyb {
-        params.push(("KEYB", keyb));
-    }
-
-    let resp: OverseasDailyPriceResponse = call_api(
-        oauth,
-        header,
-        url,
-        "HHDFS76240000",
-        &params,
-    )
-    .await?;
-
-    if resp.rt_cd != "0" {
-        return Err(format!("API 오류: {} ({})", resp.msg1, resp.msg_cd).into());
-    }
-    Ok((resp.output1, resp.output2))
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverseasPeriodQuery<'a> {
     #[serde(rename = "AUTH")]
     pub auth: &'a str,
-    #[serde(rename = "EXCD")]
-    pub exchg_code: &'a str,
+    #[serde(rename = "EXCD", with = "exchange::quote_code")]
+    pub exchange: Exchange,
     #[serde(rename = "SYMB")]
     pub symbol: &'a str,
     #[serde(rename = "GUBN")]
@@ -369,7 +247,8 @@ pub struct OverseasPeriodQuery<'a> {
     pub modp: &'a str, // 수정주가 반영 여부 (0,1)
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasPeriodOutput {
     pub xymd: String,
     pub open: String,
@@ -379,44 +258,6 @@ pub struct OverseasPeriodOutput {
     pub tvol: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasPeriodResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output: Vec<OverseasPeriodOutput>,
-}
-
-pub async fn get_overseas_period_price(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    q: OverseasPeriodQuery<'_>,
-) -> Result<Vec<OverseasPeriodOutput>, Box<dyn Error>> {
-    let url =
-        "https://openapi.koreainvestment.com:9443/uapi/overseas-price/v1/quotations/dailyprice";
-
-    let resp: OverseasPeriodResponse = call_api(
-        oauth,
-        header,
-        url,
-        "HHDFS76240000",
-        &[
-            ("AUTH", q.auth),
-            ("EXCD", q.exchg_code),
-            ("SYMB", q.symbol),
-            ("GUBN", q.gubn),
-            ("BYMD", q.bymd),
-            ("MODP", q.modp),
-        ],
-    )
-    .await?;
-
-    if resp.rt_cd != "0" {
-        return Err(format!("API 오류: {} ({})", resp.msg1, resp.msg_cd).into());
-    }
-    Ok(resp.output)
-}
-
 // ========================================================
 // 5. 해외주식 당일분봉 조회
 // ========================================================
@@ -424,8 +265,8 @@ pub async fn get_overseas_period_price(
 pub struct OverseasTodayMinuteQuery<'a> {
     #[serde(rename = "AUTH")]
     pub auth: &'a str,
-    #[serde(rename = "EXCD")]
-    pub exchg_code: &'a str,
+    #[serde(rename = "EXCD", with = "exchange::quote_code")]
+    pub exchange: Exchange,
     #[serde(rename = "SYMB")]
     pub symbol: &'a str,
     #[serde(rename = "NMIN")]
@@ -472,47 +313,6 @@ pub struct OverseasTodayMinuteOutput2 {
     pub eamt: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasTodayMinuteResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output1: OverseasTodayMinuteOutput1,
-    pub output2: Vec<OverseasTodayMinuteOutput2>,
-}
-
-pub async fn get_overseas_today_minutes(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    q: OverseasTodayMinuteQuery<'_>,
-) -> Result<(OverseasTodayMinuteOutput1, Vec<OverseasTodayMinuteOutput2>), Box<dyn Error>> {
-    let url = "https://openapi.koreainvestment.com:9443/uapi/overseas-price/v1/quotations/inquire-time-itemchartprice";
-
-    let resp: OverseasTodayMinuteResponse = call_api(
-        oauth,
-        header,
-        url,
-        "HHDFS76950200",
-        &[
-            ("AUTH", q.auth),
-            ("EXCD", q.exchg_code),
-            ("SYMB", q.symbol),
-            ("NMIN", q.nmin),
-            ("PINC", q.include_prev_day),
-            ("NEXT", q.next),
-            ("NREC", q.record_count),
-            ("FILL", q.fill),
-            ("KEYB", q.next_key),
-        ],
-    )
-    .await?;
-
-    if resp.rt_cd != "0" {
-        return Err(format!("API 오류: {} ({})", resp.msg1, resp.msg_cd).into());
-    }
-    Ok((resp.output1, resp.output2))
-}
-
 // ========================================================
 // 6. 해외주식 특정일 분봉 조회
 // ========================================================
@@ -520,8 +320,8 @@ pub async fn get_overseas_today_minutes(
 pub struct OverseasByDayMinuteQuery<'a> {
     #[serde(rename = "AUTH")]
     pub auth: &'a str,
-    #[serde(rename = "EXCD")]
-    pub exchg_code: &'a str,
+    #[serde(rename = "EXCD", with = "exchange::quote_code")]
+    pub exchange: Exchange,
     #[serde(rename = "SYMB")]
     pub symbol: &'a str,
     #[serde(rename = "BYMD")]
@@ -530,7 +330,7 @@ pub struct OverseasByDayMinuteQuery<'a> {
     pub nmin: &'a str, // 분봉 간격
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct OverseasByDayMinute {
     #[serde(default)]
     pub tymd: String,
@@ -556,23 +356,6 @@ pub struct OverseasByDayMinute {
     pub eamt: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasByDayMinuteResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output2: Vec<OverseasByDayMinute>,
-}
-
-pub async fn get_overseas_minutes_by_day(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    q: OverseasByDayMinuteQuery<'_>,
-) -> Result<Vec<OverseasByDayMinute>, Box<dyn Error>> {
-    let _ = (oauth, header, q);
-    Err("한국투자 해외주식분봉조회는 BYMD 직접 조회를 지원하지 않습니다. get_overseas_today_minutes에서 PINC/NEXT/KEYB로 현재/전일 포함 및 다음 페이지를 조회하세요.".into())
-}
-
 // ========================================================
 // 7. 해외지수 분봉 조회
 // ========================================================
@@ -588,7 +371,8 @@ pub struct OverseasIndexMinuteQuery<'a> {
     pub include_past: &'a str, // Y/N
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasIndexMinuteOutput1 {
     pub ovrs_nmix_prdy_vrss: String,
     pub prdy_vrss_sign: String,
@@ -603,7 +387,8 @@ pub struct OverseasIndexMinuteOutput1 {
     pub ovrs_prod_lwpr: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
 pub struct OverseasIndexMinuteOutput2 {
     pub stck_bsop_date: String,
     pub stck_cntg_hour: String,
@@ -614,55 +399,436 @@ pub struct OverseasIndexMinuteOutput2 {
     pub cntg_vol: String,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OverseasIndexMinuteResponse {
-    pub rt_cd: String,
-    pub msg_cd: String,
-    pub msg1: String,
-    pub output1: OverseasIndexMinuteOutput1,
-    pub output2: Vec<OverseasIndexMinuteOutput2>,
+// ========================================================
+// 8. 해외주식 시세 (KISProvider)
+// ========================================================
+const OVERSEAS_PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/overseas-price/v1/quotations/price-detail",
+    TrId::new("HHDFS76200200", None),
+);
+const OVERSEAS_PRODUCT_INFO_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/overseas-price/v1/quotations/search-info",
+    TrId::new("CTPF1702R", None),
+);
+const OVERSEAS_DAILY_CHART_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/overseas-price/v1/quotations/inquire-daily-chartprice",
+    TrId::new("FHKST03030100", Some("FHKST03030100")),
+);
+const OVERSEAS_DAILY_PRICE_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
+    "/uapi/overseas-price/v1/quotations/dailyprice",
+    TrId::new("HHDFS76240000", Some("HHDFS76240000")),
+);
+const OVERSEAS_TODAY_MINUTE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/overseas-price/v1/quotations/inquire-time-itemchartprice",
+    TrId::new("HHDFS76950200", None),
+);
+const OVERSEAS_INDEX_MINUTE_ENDPOINT: ApiEndpoint = ApiEndpoint::real_only(
+    "/uapi/overseas-price/v1/quotations/inquire-time-indexchartprice",
+    TrId::new("FHKST03030200", None),
+);
+
+/// 해외주식 시세 조회
+///
+/// 모의투자 미지원 API(현재가상세, 상품기본정보, 분봉, 호가, 복수종목 시세)는
+/// `practice` 모드에서 "모의투자 미지원 API입니다" 오류를 반환합니다.
+#[async_trait]
+pub trait OverseasQuotations {
+    /// 해외주식 현재가상세
+    async fn get_overseas_price(
+        &self,
+        query: OverseasPriceQuery<'_>,
+    ) -> Result<ApiResponse<OverseasPriceOutput>, Box<dyn Error>>;
+    /// 해외주식 상품기본정보
+    async fn get_overseas_product_info(
+        &self,
+        query: OverseasProductInfoQuery<'_>,
+    ) -> Result<ApiResponse<OverseasProductInfoOutput>, Box<dyn Error>>;
+    /// 해외주식 종목/지수/환율기간별시세 (output1 요약, output2 기간별 행)
+    async fn get_overseas_daily_chartprice(
+        &self,
+        query: OverseasDailyChartQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasDailyChartOutput1, Vec<OverseasDailyChartOutput2>)>,
+        Box<dyn Error>,
+    >;
+    /// 해외주식 기간별시세 (KEYB로 다음 페이지 조회)
+    async fn get_overseas_daily_price(
+        &self,
+        query: OverseasDailyPriceQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasDailyPriceOutput1, Vec<OverseasDailyPriceOutput2>)>,
+        Box<dyn Error>,
+    >;
+    /// 해외주식 기간별시세 (OHLCV 행만)
+    async fn get_overseas_period_price(
+        &self,
+        query: OverseasPeriodQuery<'_>,
+    ) -> Result<ApiResponse<Vec<OverseasPeriodOutput>>, Box<dyn Error>>;
+    /// 해외주식 분봉 (당일/전일 포함, NEXT/KEYB로 다음 페이지 조회)
+    async fn get_overseas_today_minutes(
+        &self,
+        query: OverseasTodayMinuteQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasTodayMinuteOutput1, Vec<OverseasTodayMinuteOutput2>)>,
+        Box<dyn Error>,
+    >;
+    /// 특정일 분봉 (KIS 미지원, `get_overseas_today_minutes` 사용)
+    async fn get_overseas_minutes_by_day(
+        &self,
+        query: OverseasByDayMinuteQuery<'_>,
+    ) -> Result<ApiResponse<Vec<OverseasByDayMinute>>, Box<dyn Error>>;
+    /// 해외지수/환율 분봉
+    async fn get_overseas_index_minutes(
+        &self,
+        query: OverseasIndexMinuteQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasIndexMinuteOutput1, Vec<OverseasIndexMinuteOutput2>)>,
+        Box<dyn Error>,
+    >;
+    /// 해외주식 현재가 10호가
+    async fn get_overseas_asking_price(
+        &self,
+        exchange: Exchange,
+        symbol: &str,
+    ) -> Result<ApiResponse<Orderbook>, Box<dyn Error>>;
+    /// 해외주식 복수종목 시세 (최대 10종목, 초과하면 오류)
+    async fn get_overseas_multi_price(
+        &self,
+        request: OverseasMultiPriceRequest<'_>,
+    ) -> Result<ApiResponse<Vec<OverseasMultiPriceRow>>, Box<dyn Error>>;
+    /// 종목 수 제한 없는 복수종목 시세 (모의투자 미지원)
+    ///
+    /// 10종목씩 나눠 동시에 조회하고, (거래소, 종목코드)별 결과를 돌려줍니다.
+    /// 일부 호출이 실패해도 해당 종목만 오류로 채웁니다.
+    ///
+    /// 호출 시작 시각은 이 호출 안에서만 호출 제한 간격만큼 벌립니다.
+    /// 다른 API 호출과 공유하는 제한기가 아니므로 동시에 다른 조회를 하면 초당 호출 제한을 넘을 수 있습니다.
+    async fn batch_overseas_quotes(
+        &self,
+        symbols: &[(Exchange, &str)],
+    ) -> Result<HashMap<(Exchange, String), Result<OverseasMultiPriceRow, String>>, Box<dyn Error>>;
 }
 
-pub async fn get_overseas_index_minutes(
-    oauth: &Oauth,
-    header: &ApiHeader<'_>,
-    q: OverseasIndexMinuteQuery<'_>,
-) -> Result<(OverseasIndexMinuteOutput1, Vec<OverseasIndexMinuteOutput2>), Box<dyn Error>> {
-    let url =
-        "https://openapi.koreainvestment.com:9443/uapi/overseas-price/v1/quotations/inquire-time-indexchartprice";
-
-    let resp: OverseasIndexMinuteResponse = call_api(
-        oauth,
-        header,
-        url,
-        "FHKST03030200",
-        &[
-            ("FID_COND_MRKT_DIV_CODE", q.market_div_code),
-            ("FID_INPUT_ISCD", q.symbol),
-            ("FID_HOUR_CLS_CODE", q.hour_cls_code),
-            ("FID_PW_DATA_INCU_YN", q.include_past),
-        ],
+async fn get_overseas_quotation(
+    provider: &KISProvider,
+    endpoint: ApiEndpoint,
+    query: &[(&str, &str)],
+) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
+    let response = call_get_api::<RawApiBody>(
+        &provider.oauth,
+        &provider.header,
+        provider.practice,
+        endpoint,
+        query,
     )
     .await?;
+    response.body.ensure_success()?;
+    Ok(response)
+}
+
+/// output1 요약 + output2 행 목록 변환
+fn summary_and_rows<S, R>(body: &RawApiBody) -> Result<(S, Vec<R>), serde_json::Error>
+where
+    S: DeserializeOwned + Default,
+    R: DeserializeOwned,
+{
+    Ok((body.output_as("output1")?, body.rows_as("output2")?))
+}
+
+#[async_trait]
+impl OverseasQuotations for KISProvider {
+    async fn get_overseas_price(
+        &self,
+        query: OverseasPriceQuery<'_>,
+    ) -> Result<ApiResponse<OverseasPriceOutput>, Box<dyn Error>> {
+        let response = get_overseas_quotation(
+            self,
+            OVERSEAS_PRICE_ENDPOINT,
+            &[
+                ("AUTH", query.auth),
+                ("EXCD", query.exchange.quote_code()),
+                ("SYMB", query.symbol),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.output_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_product_info(
+        &self,
+        query: OverseasProductInfoQuery<'_>,
+    ) -> Result<ApiResponse<OverseasProductInfoOutput>, Box<dyn Error>> {
+        let response = get_overseas_quotation(
+            self,
+            OVERSEAS_PRODUCT_INFO_ENDPOINT,
+            &[
+                ("PRDT_TYPE_CD", query.product_type_code),
+                ("PDNO", query.product_number),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.output_as("output")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_daily_chartprice(
+        &self,
+        query: OverseasDailyChartQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasDailyChartOutput1, Vec<OverseasDailyChartOutput2>)>,
+        Box<dyn Error>,
+    > {
+        let response = get_overseas_quotation(
+            self,
+            OVERSEAS_DAILY_CHART_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", query.market_div_code),
+                ("FID_INPUT_ISCD", query.symbol),
+                ("FID_INPUT_DATE_1", query.start_date),
+                ("FID_INPUT_DATE_2", query.end_date),
+                ("FID_PERIOD_DIV_CODE", query.period_div_code),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: summary_and_rows(&response.body)?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_daily_price(
+        &self,
+        query: OverseasDailyPriceQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasDailyPriceOutput1, Vec<OverseasDailyPriceOutput2>)>,
+        Box<dyn Error>,
+    > {
+        let mut params = vec![
+            ("AUTH", query.auth),
+            ("EXCD", query.exchange.quote_code()),
+            ("SYMB", query.symbol),
+            ("GUBN", query.gubn),
+            ("BYMD", query.bymd),
+            ("MODP", query.modp),
+        ];
+        if let Some(keyb) = query.keyb {
+            params.push(("KEYB", keyb));
+        }
+        let response = get_overseas_quotation(self, OVERSEAS_DAILY_PRICE_ENDPOINT, &params).await?;
+        Ok(ApiResponse {
+            body: summary_and_rows(&response.body)?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_period_price(
+        &self,
+        query: OverseasPeriodQuery<'_>,
+    ) -> Result<ApiResponse<Vec<OverseasPeriodOutput>>, Box<dyn Error>> {
+        let response = get_overseas_quotation(
+            self,
+            OVERSEAS_DAILY_PRICE_ENDPOINT,
+            &[
+                ("AUTH", query.auth),
+                ("EXCD", query.exchange.quote_code()),
+                ("SYMB", query.symbol),
+                ("GUBN", query.gubn),
+                ("BYMD", query.bymd),
+                ("MODP", query.modp),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_today_minutes(
+        &self,
+        query: OverseasTodayMinuteQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasTodayMinuteOutput1, Vec<OverseasTodayMinuteOutput2>)>,
+        Box<dyn Error>,
+    > {
+        let response = get_overseas_quotation(
+            self,
+            OVERSEAS_TODAY_MINUTE_ENDPOINT,
+            &[
+                ("AUTH", query.auth),
+                ("EXCD", query.exchange.quote_code()),
+                ("SYMB", query.symbol),
+                ("NMIN", query.nmin),
+                ("PINC", query.include_prev_day),
+                ("NEXT", query.next),
+                ("NREC", query.record_count),
+                ("FILL", query.fill),
+                ("KEYB", query.next_key),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: summary_and_rows(&response.body)?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_minutes_by_day(
+        &self,
+        query: OverseasByDayMinuteQuery<'_>,
+    ) -> Result<ApiResponse<Vec<OverseasByDayMinute>>, Box<dyn Error>> {
+        let _ = query;
+        Err("한국투자 해외주식분봉조회는 BYMD 직접 조회를 지원하지 않습니다. get_overseas_today_minutes에서 PINC/NEXT/KEYB로 현재/전일 포함 및 다음 페이지를 조회하세요.".into())
+    }
+
+    async fn get_overseas_index_minutes(
+        &self,
+        query: OverseasIndexMinuteQuery<'_>,
+    ) -> Result<
+        ApiResponse<(OverseasIndexMinuteOutput1, Vec<OverseasIndexMinuteOutput2>)>,
+        Box<dyn Error>,
+    > {
+        let response = get_overseas_quotation(
+            self,
+            OVERSEAS_INDEX_MINUTE_ENDPOINT,
+            &[
+                ("FID_COND_MRKT_DIV_CODE", query.market_div_code),
+                ("FID_INPUT_ISCD", query.symbol),
+                ("FID_HOUR_CLS_CODE", query.hour_cls_code),
+                ("FID_PW_DATA_INCU_YN", query.include_past),
+            ],
+        )
+        .await?;
+        Ok(ApiResponse {
+            body: summary_and_rows(&response.body)?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_asking_price(
+        &self,
+        exchange: Exchange,
+        symbol: &str,
+    ) -> Result<ApiResponse<Orderbook>, Box<dyn Error>> {
+        let response = call_get_api::<RawApiBody>(
+            &self.oauth,
+            &self.header,
+            self.practice,
+            OVERSEAS_ASKING_PRICE_ENDPOINT,
+            &[
+                ("AUTH", ""),
+                ("EXCD", exchange.quote_code()),
+                ("SYMB", symbol),
+            ],
+        )
+        .await?;
+        response.body.ensure_success()?;
+        Ok(ApiResponse {
+            body: parse_overseas_orderbook(&response.body)?,
+            headers: response.headers,
+        })
+    }
+
+    async fn get_overseas_multi_price(
+        &self,
+        request: OverseasMultiPriceRequest<'_>,
+    ) -> Result<ApiResponse<Vec<OverseasMultiPriceRow>>, Box<dyn Error>> {
+        if request.items.len() > MULTI_PRICE_LIMIT {
+            return Err(format!(
+                "복수종목 시세는 최대 {MULTI_PRICE_LIMIT}종목까지 조회할 수 있습니다 ({}종목)",
+                request.items.len()
+            )
+            .into());
+        }
+        let nrec = request.items.len().to_string();
+        let mut pairs: Vec<(String, String)> = vec![
+            ("AUTH".to_string(), "".to_string()),
+            ("NREC".to_string(), nrec),
+        ];
+        for (index, (exchange, symbol)) in request.items.iter().enumerate() {
+            let slot = index + 1;
+            pairs.push((format!("EXCD_{slot:02}"), exchange.quote_code().to_string()));
+            pairs.push((format!("SYMB_{slot:02}"), (*symbol).to_string()));
+        }
+        let refs: Vec<(&str, &str)> = pairs
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str()))
+            .collect();
+        let header = self
+            .header
+            .with_tr_cont(request.continuation.then_some("N"));
+        let response = call_get_api::<RawApiBody>(
+            &self.oauth,
+            &header,
+            self.practice,
+            OVERSEAS_MULTI_PRICE_ENDPOINT,
+            &refs,
+        )
+        .await?;
+        response.body.ensure_success()?;
+        Ok(ApiResponse {
+            body: response.body.rows_as("output2")?,
+            headers: response.headers,
+        })
+    }
 
-    if resp.rt_cd != "0" {
-        return Err(format!("API 오류: {} ({})", resp.msg1, resp.msg_cd).into());
+    async fn batch_overseas_quotes(
+        &self,
+        symbols: &[(Exchange, &str)],
+    ) -> Result<HashMap<(Exchange, String), Result<OverseasMultiPriceRow, String>>, Box<dyn Error>>
+    {
+        if self.practice {
+            return Err("해외주식 복수종목 시세는 모의투자를 지원하지 않습니다".into());
+        }
+        let interval = request_interval(self.practice);
+        // 이 호출 안에서만 호출 시작 시각을 간격만큼 벌립니다 (provider 전역 제한기 아님)
+        let calls = symbols
+            .chunks(MULTI_PRICE_LIMIT)
+            .enumerate()
+            .map(|(index, chunk)| fetch_multi_price_chunk(self, interval * index as u32, chunk));
+
+        let mut quotes = HashMap::new();
+        for (chunk, result) in symbols.chunks(MULTI_PRICE_LIMIT).zip(join_all(calls).await) {
+            for (exchange, symbol) in chunk {
+                let quote = match &result {
+                    Ok(rows) => rows
+                        .iter()
+                        .find(|row| row.matches(*exchange, symbol))
+                        .cloned()
+                        .ok_or_else(|| format!("{exchange} {symbol} 시세가 응답에 없습니다")),
+                    Err(error) => Err(error.clone()),
+                };
+                quotes.insert((*exchange, symbol.to_string()), quote);
+            }
+        }
+        Ok(quotes)
     }
-    Ok((resp.output1, resp.output2))
 }
 
-const OVERSEAS_ORDER_BUY_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
-    "/uapi/overseas-stock/v1/trading/order",
-    TrId::new("TTTT1002U", Some("VTTT1002U")),
-);
-const OVERSEAS_ORDER_SELL_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
-    "/uapi/overseas-stock/v1/trading/order",
-    TrId::new("TTTT1006U", Some("VTTT1001U")),
-);
-const OVERSEAS_ORDER_REVISE_CANCEL_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
-    "/uapi/overseas-stock/v1/trading/order-rvsecncl",
-    TrId::new("TTTT1004U", Some("VTTT1004U")),
-);
+/// 복수종목 시세 1회 호출 (`delay` 후 시작, 오류는 종목별로 나눠 담을 수 있게 문자열로 변환)
+async fn fetch_multi_price_chunk(
+    provider: &KISProvider,
+    delay: Duration,
+    items: &[(Exchange, &str)],
+) -> Result<Vec<OverseasMultiPriceRow>, String> {
+    tokio::time::sleep(delay).await;
+    provider
+        .get_overseas_multi_price(OverseasMultiPriceRequest {
+            items: items.to_vec(),
+            continuation: false,
+        })
+        .await
+        .map(|response| response.body)
+        .map_err(|error| error.to_string())
+}
+
+const OVERSEAS_ORDER_PATH: &str = "/uapi/overseas-stock/v1/trading/order";
+const OVERSEAS_ORDER_REVISE_CANCEL_PATH: &str = "/uapi/overseas-stock/v1/trading/order-rvsecncl";
 const OVERSEAS_BALANCE_ENDPOINT: ApiEndpoint = ApiEndpoint::new(
     "/uapi/overseas-stock/v1/trading/inquire-balance",
     TrId::new("TTTS3012R", Some("VTTS3012R")),
@@ -746,8 +912,11 @@ pub struct OverseasOrderRequest<'a> {
     pub cano: &'a str,
     #[serde(rename = "ACNT_PRDT_CD")]
     pub acnt_prdt_cd: &'a str,
-    #[serde(rename = "OVRS_EXCG_CD")]
-    pub ovrs_excg_cd: &'a str,
+    #[serde(
+        rename = "OVRS_EXCG_CD",
+        serialize_with = "exchange::order_code::serialize"
+    )]
+    pub exchange: Exchange,
     #[serde(rename = "PDNO")]
     pub pdno: &'a str,
     #[serde(rename = "ORD_QTY")]
@@ -778,8 +947,11 @@ pub struct OverseasRevisionRequest<'a> {
     pub cano: &'a str,
     #[serde(rename = "ACNT_PRDT_CD")]
     pub acnt_prdt_cd: &'a str,
-    #[serde(rename = "OVRS_EXCG_CD")]
-    pub ovrs_excg_cd: &'a str,
+    #[serde(
+        rename = "OVRS_EXCG_CD",
+        serialize_with = "exchange::order_code::serialize"
+    )]
+    pub exchange: Exchange,
     #[serde(rename = "PDNO")]
     pub pdno: &'a str,
     #[serde(rename = "ORGN_ODNO")]
@@ -877,8 +1049,11 @@ pub struct OverseasDaytimeOrderRequest<'a> {
     pub cano: &'a str,
     #[serde(rename = "ACNT_PRDT_CD")]
     pub acnt_prdt_cd: &'a str,
-    #[serde(rename = "OVRS_EXCG_CD")]
-    pub ovrs_excg_cd: &'a str,
+    #[serde(
+        rename = "OVRS_EXCG_CD",
+        serialize_with = "exchange::order_code::serialize"
+    )]
+    pub exchange: Exchange,
     #[serde(rename = "PDNO")]
     pub pdno: &'a str,
     #[serde(rename = "ORD_QTY")]
@@ -901,8 +1076,11 @@ pub struct OverseasDaytimeRevisionRequest<'a> {
     pub cano: &'a str,
     #[serde(rename = "ACNT_PRDT_CD")]
     pub acnt_prdt_cd: &'a str,
-    #[serde(rename = "OVRS_EXCG_CD")]
-    pub ovrs_excg_cd: &'a str,
+    #[serde(
+        rename = "OVRS_EXCG_CD",
+        serialize_with = "exchange::order_code::serialize"
+    )]
+    pub exchange: Exchange,
     #[serde(rename = "PDNO")]
     pub pdno: &'a str,
     #[serde(rename = "ORGN_ODNO")]
@@ -1081,12 +1259,50 @@ pub struct OverseasReserveOrderCancelRequest<'a> {
     pub ovrs_rsvn_odno: &'a str,
 }
 
+/// 복수종목 시세 1회 호출당 최대 종목 수
+const MULTI_PRICE_LIMIT: usize = 10;
+
 #[derive(Debug, Clone)]
 pub struct OverseasMultiPriceRequest<'a> {
-    pub items: Vec<(&'a str, &'a str)>,
+    /// (거래소, 종목코드), 최대 10종목
+    pub items: Vec<(Exchange, &'a str)>,
     pub continuation: bool,
 }
 
+/// 복수종목 시세 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverseasMultiPriceRow {
+    /// 실시간조회종목코드 (예: DNASAAPL)
+    pub rsym: String,
+    pub excd: String,
+    pub symb: String,
+    /// 소수점자리수
+    pub zdiv: String,
+    /// 전일종가
+    pub base: String,
+    /// 전일거래량
+    pub pvol: String,
+    pub last: String,
+    pub sign: String,
+    pub diff: String,
+    pub rate: String,
+    pub tvol: String,
+    pub tamt: String,
+    /// 매수가능여부
+    pub ordy: String,
+}
+
+impl OverseasMultiPriceRow {
+    fn matches(&self, exchange: Exchange, symbol: &str) -> bool {
+        if !self.rsym.is_empty() {
+            return self.rsym == exchange.realtime_key(symbol);
+        }
+        self.symb == symbol
+            && (self.excd.is_empty() || Exchange::from_code(&self.excd) == Some(exchange))
+    }
+}
+
 #[async_trait]
 pub trait OverseasTrading {
     async fn place_overseas_buy_order(
@@ -1158,15 +1374,6 @@ pub trait OverseasTrading {
         &self,
         request: OverseasReserveOrderCancelRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>>;
-    async fn get_overseas_asking_price(
-        &self,
-        exchange_code: &str,
-        symbol: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>>;
-    async fn get_overseas_multi_price(
-        &self,
-        request: OverseasMultiPriceRequest<'_>,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>>;
 }
 
 #[async_trait]
@@ -1178,6 +1385,39 @@ pub trait OverseasAnalysis {
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>>;
 }
 
+/// 거래소별 주문 TR_ID (미국/홍콩/일본/상해/심천/베트남)
+fn overseas_order_endpoint(exchange: Exchange, buy: bool) -> ApiEndpoint {
+    let tr_id = match (exchange, buy) {
+        (Exchange::Nasdaq | Exchange::Nyse | Exchange::Amex, true) => {
+            TrId::new("TTTT1002U", Some("VTTT1002U"))
+        }
+        (Exchange::Nasdaq | Exchange::Nyse | Exchange::Amex, false) => {
+            TrId::new("TTTT1006U", Some("VTTT1001U"))
+        }
+        (Exchange::HongKong, true) => TrId::new("TTTS1002U", Some("VTTS1002U")),
+        (Exchange::HongKong, false) => TrId::new("TTTS1001U", Some("VTTS1001U")),
+        (Exchange::Tokyo, true) => TrId::new("TTTS0308U", Some("VTTS0308U")),
+        (Exchange::Tokyo, false) => TrId::new("TTTS0307U", Some("VTTS0307U")),
+        (Exchange::Shanghai, true) => TrId::new("TTTS0202U", Some("VTTS0202U")),
+        (Exchange::Shanghai, false) => TrId::new("TTTS1005U", Some("VTTS1005U")),
+        (Exchange::Shenzhen, true) => TrId::new("TTTS0305U", Some("VTTS0305U")),
+        (Exchange::Shenzhen, false) => TrId::new("TTTS0304U", Some("VTTS0304U")),
+        (Exchange::Hanoi | Exchange::HoChiMinh, true) => TrId::new("TTTS0311U", Some("VTTS0311U")),
+        (Exchange::Hanoi | Exchange::HoChiMinh, false) => TrId::new("TTTS0310U", Some("VTTS0310U")),
+    };
+    ApiEndpoint::new(OVERSEAS_ORDER_PATH, tr_id)
+}
+
+/// 거래소별 정정/취소 TR_ID (미국 / 아시아)
+fn overseas_revise_cancel_endpoint(exchange: Exchange) -> ApiEndpoint {
+    let tr_id = if exchange.is_us() {
+        TrId::new("TTTT1004U", Some("VTTT1004U"))
+    } else {
+        TrId::new("TTTS1003U", Some("VTTS1003U"))
+    };
+    ApiEndpoint::new(OVERSEAS_ORDER_REVISE_CANCEL_PATH, tr_id)
+}
+
 async fn post_overseas_with_hashkey<T: Serialize>(
     provider: &KISProvider,
     endpoint: ApiEndpoint,
@@ -1203,21 +1443,24 @@ impl OverseasTrading for KISProvider {
         &self,
         request: OverseasOrderRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
-        post_overseas_with_hashkey(self, OVERSEAS_ORDER_BUY_ENDPOINT, &request).await
+        let endpoint = overseas_order_endpoint(request.exchange, true);
+        post_overseas_with_hashkey(self, endpoint, &request).await
     }
 
     async fn place_overseas_sell_order(
         &self,
         request: OverseasOrderRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
-        post_overseas_with_hashkey(self, OVERSEAS_ORDER_SELL_ENDPOINT, &request).await
+        let endpoint = overseas_order_endpoint(request.exchange, false);
+        post_overseas_with_hashkey(self, endpoint, &request).await
     }
 
     async fn revise_or_cancel_overseas_order(
         &self,
         request: OverseasRevisionRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
-        post_overseas_with_hashkey(self, OVERSEAS_ORDER_REVISE_CANCEL_ENDPOINT, &request).await
+        let endpoint = overseas_revise_cancel_endpoint(request.exchange);
+        post_overseas_with_hashkey(self, endpoint, &request).await
     }
 
     async fn inquire_overseas_balance(
@@ -1225,10 +1468,7 @@ impl OverseasTrading for KISProvider {
         request: OverseasBalanceRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -1343,10 +1583,7 @@ impl OverseasTrading for KISProvider {
         request: OverseasPeriodProfitRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -1376,10 +1613,7 @@ impl OverseasTrading for KISProvider {
         request: OverseasNccsRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -1404,10 +1638,7 @@ impl OverseasTrading for KISProvider {
         request: OverseasPeriodTransactionRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let response = call_get_api::<RawApiBody>(
             &self.oauth,
             &header,
@@ -1470,10 +1701,7 @@ impl OverseasTrading for KISProvider {
         request: OverseasReserveOrderListRequest<'_>,
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         let continuation = request.continuation.cloned().unwrap_or_default();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.map(|_| "N"));
+        let header = self.header.with_tr_cont(request.continuation.map(|_| "N"));
         let endpoint = match request.market {
             OverseasReserveListMarket::Usa => OVERSEAS_RESERVE_ORDER_LIST_USA_ENDPOINT,
             OverseasReserveListMarket::Asia => OVERSEAS_RESERVE_ORDER_LIST_ASIA_ENDPOINT,
@@ -1506,57 +1734,6 @@ impl OverseasTrading for KISProvider {
     ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
         post_overseas_with_hashkey(self, OVERSEAS_RESERVE_ORDER_CANCEL_USA_ENDPOINT, &request).await
     }
-
-    async fn get_overseas_asking_price(
-        &self,
-        exchange_code: &str,
-        symbol: &str,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
-        let response = call_get_api::<RawApiBody>(
-            &self.oauth,
-            &self.header,
-            self.practice,
-            OVERSEAS_ASKING_PRICE_ENDPOINT,
-            &[("AUTH", ""), ("EXCD", exchange_code), ("SYMB", symbol)],
-        )
-        .await?;
-        response.body.ensure_success()?;
-        Ok(response)
-    }
-
-    async fn get_overseas_multi_price(
-        &self,
-        request: OverseasMultiPriceRequest<'_>,
-    ) -> Result<ApiResponse<RawApiBody>, Box<dyn Error>> {
-        let nrec = request.items.len().min(10).to_string();
-        let mut pairs: Vec<(String, String)> = vec![
-            ("AUTH".to_string(), "".to_string()),
-            ("NREC".to_string(), nrec),
-        ];
-        for (index, (exchange, symbol)) in request.items.iter().take(10).enumerate() {
-            let slot = index + 1;
-            pairs.push((format!("EXCD_{slot:02}"), (*exchange).to_string()));
-            pairs.push((format!("SYMB_{slot:02}"), (*symbol).to_string()));
-        }
-        let refs: Vec<(&str, &str)> = pairs
-            .iter()
-            .map(|(key, value)| (key.as_str(), value.as_str()))
-            .collect();
-        let header = self
-            .header
-            .clone()
-            .with_tr_cont(request.continuation.then_some("N"));
-        let response = call_get_api::<RawApiBody>(
-            &self.oauth,
-            &header,
-            self.practice,
-            OVERSEAS_MULTI_PRICE_ENDPOINT,
-            &refs,
-        )
-        .await?;
-        response.body.ensure_success()?;
-        Ok(response)
-    }
 }
 
 #[async_trait]
@@ -1578,3 +1755,73 @@ impl OverseasAnalysis for KISProvider {
         Ok(response)
     }
 }
+
+/// 해외주식 호가 응답(output1: 합계, output2: 단계별)을 `Orderbook`으로 변환
+///
+/// 미국 외 거래소는 1단계만 내려오므로 나머지 단계는 0으로 채워집니다.
+fn parse_overseas_orderbook(body: &RawApiBody) -> Result<Orderbook, Box<dyn Error>> {
+    let summary = body
+        .output("output1")
+        .ok_or("호가 응답에 output1이 없습니다")?;
+    let output = body
+        .output("output2")
+        .ok_or("호가 응답에 output2가 없습니다")?;
+    let level = |side: &str, i: usize| Level {
+        price: json_num(output, &format!("p{side}{i}")),
+        quantity: json_num(output, &format!("v{side}{i}")),
+        change: json_num(output, &format!("d{side}{i}")),
+    };
+    Ok(Orderbook {
+        time: json_str(summary, "dhms").to_string(),
+        asks: std::array::from_fn(|i| level("ask", i + 1)),
+        bids: std::array::from_fn(|i| level("bid", i + 1)),
+        totals: OrderbookTotals {
+            ask_quantity: json_num(summary, "avol"),
+            bid_quantity: json_num(summary, "bvol"),
+            ask_change: json_num(summary, "advl"),
+            bid_change: json_num(summary, "bdvl"),
+        },
+        expected: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revise_cancel_tr_id_follows_exchange() {
+        for exchange in Exchange::ALL {
+            let tr_id = overseas_revise_cancel_endpoint(exchange).tr_id;
+            let expected = if exchange.is_us() {
+                ("TTTT1004U", "VTTT1004U")
+            } else {
+                ("TTTS1003U", "VTTS1003U")
+            };
+            assert_eq!(
+                (tr_id.real, tr_id.practice.unwrap()),
+                expected,
+                "{exchange}"
+            );
+        }
+    }
+
+    #[test]
+    fn revision_request_serializes_order_exchange_code() {
+        let request = OverseasRevisionRequest {
+            cano: "12345678",
+            acnt_prdt_cd: "01",
+            exchange: Exchange::HongKong,
+            pdno: "00700",
+            orgn_odno: "0030138295",
+            rvse_cncl_dvsn_cd: "02",
+            ord_qty: "100",
+            ovrs_ord_unpr: "0",
+            mgco_aptm_odno: "",
+            ord_svr_dvsn_cd: "0",
+        };
+        let body = serde_json::to_value(&request).unwrap();
+        assert_eq!(body["OVRS_EXCG_CD"], "SEHK");
+        assert_eq!(body["PDNO"], "00700");
+    }
+}
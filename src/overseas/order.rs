@@ -0,0 +1,538 @@
+use super::{Exchange, OrderSide, OverseasOrderRequest, OverseasRevisionRequest};
+use crate::types::AccountInfo;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+
+/// 알고리즘 주문(TWAP/VWAP) 실행 구간
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AlgoWindow {
+    /// 지금부터 정규장 종료까지 (ALGO_ORD_TMD_DVSN_CD `02`)
+    UntilClose,
+    /// 시작/종료 시각 직접 입력 (HHMMSS, ALGO_ORD_TMD_DVSN_CD `00`)
+    Between { start: String, end: String },
+}
+
+/// 해외주식 주문 유형
+///
+/// | 유형 | ORD_DVSN | 거래소 |
+/// |---|---|---|
+/// | 지정가 | `00` | 전체 |
+/// | 단주지정가 | `50` | 홍콩 (매도) |
+/// | MOO (장개시시장가) | `31` | 미국 (매도) |
+/// | LOO (장개시지정가) | `32` | 미국 |
+/// | MOC (장마감시장가) | `33` | 미국 (매도) |
+/// | LOC (장마감지정가) | `34` | 미국 |
+/// | TWAP | `35` | 미국 |
+/// | VWAP | `36` | 미국 |
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OverseasOrderKind {
+    Limit { price: f64 },
+    OddLotLimit { price: f64 },
+    MarketOnOpen,
+    LimitOnOpen { price: f64 },
+    MarketOnClose,
+    LimitOnClose { price: f64 },
+    Twap { price: f64, window: AlgoWindow },
+    Vwap { price: f64, window: AlgoWindow },
+}
+
+impl OverseasOrderKind {
+    /// 주문구분 (ORD_DVSN)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Limit { .. } => "00",
+            Self::OddLotLimit { .. } => "50",
+            Self::MarketOnOpen => "31",
+            Self::LimitOnOpen { .. } => "32",
+            Self::MarketOnClose => "33",
+            Self::LimitOnClose { .. } => "34",
+            Self::Twap { .. } => "35",
+            Self::Vwap { .. } => "36",
+        }
+    }
+
+    /// 주문 단가 (시장가 유형은 `None`)
+    pub fn price(&self) -> Option<f64> {
+        match self {
+            Self::Limit { price }
+            | Self::OddLotLimit { price }
+            | Self::LimitOnOpen { price }
+            | Self::LimitOnClose { price }
+            | Self::Twap { price, .. }
+            | Self::Vwap { price, .. } => Some(*price),
+            Self::MarketOnOpen | Self::MarketOnClose => None,
+        }
+    }
+
+    pub fn algo_window(&self) -> Option<&AlgoWindow> {
+        match self {
+            Self::Twap { window, .. } | Self::Vwap { window, .. } => Some(window),
+            _ => None,
+        }
+    }
+
+    /// 거래소/매매구분에서 쓸 수 있는 유형인지 검사
+    pub fn validate(&self, exchange: Exchange, side: OrderSide) -> Result<(), String> {
+        let name = match self {
+            Self::Limit { .. } => "지정가",
+            Self::OddLotLimit { .. } => "단주지정가",
+            Self::MarketOnOpen => "MOO",
+            Self::LimitOnOpen { .. } => "LOO",
+            Self::MarketOnClose => "MOC",
+            Self::LimitOnClose { .. } => "LOC",
+            Self::Twap { .. } => "TWAP",
+            Self::Vwap { .. } => "VWAP",
+        };
+        let exchange_ok = match self {
+            Self::Limit { .. } => true,
+            Self::OddLotLimit { .. } => exchange == Exchange::HongKong,
+            _ => exchange.is_us(),
+        };
+        if !exchange_ok {
+            return Err(format!(
+                "{exchange} 거래소는 {name} 주문을 지원하지 않습니다"
+            ));
+        }
+        let sell_only = matches!(
+            self,
+            Self::OddLotLimit { .. } | Self::MarketOnOpen | Self::MarketOnClose
+        );
+        if sell_only && side == OrderSide::Buy {
+            return Err(format!("{name} 주문은 매도만 가능합니다"));
+        }
+        if let Some(price) = self.price()
+            && !is_positive(price)
+        {
+            return Err(format!("{name} 주문 단가는 0보다 커야 합니다"));
+        }
+        if let Some(AlgoWindow::Between { start, end }) = self.algo_window() {
+            let valid = |time: &str| time.len() == 6 && time.bytes().all(|b| b.is_ascii_digit());
+            if !valid(start) || !valid(end) {
+                return Err(format!("{name} 시작/종료 시각은 HHMMSS 형식이어야 합니다"));
+            }
+            if start >= end {
+                return Err(format!("{name} 시작 시각이 종료 시각보다 앞서야 합니다"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 타입으로 검증하는 해외주식 주문
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverseasOrder {
+    pub exchange: Exchange,
+    pub side: OrderSide,
+    pub symbol: String,
+    pub quantity: u64,
+    pub kind: OverseasOrderKind,
+}
+
+impl OverseasOrder {
+    pub fn buy(exchange: Exchange, symbol: &str, quantity: u64, kind: OverseasOrderKind) -> Self {
+        Self {
+            exchange,
+            side: OrderSide::Buy,
+            symbol: symbol.to_string(),
+            quantity,
+            kind,
+        }
+    }
+
+    pub fn sell(exchange: Exchange, symbol: &str, quantity: u64, kind: OverseasOrderKind) -> Self {
+        Self {
+            exchange,
+            side: OrderSide::Sell,
+            symbol: symbol.to_string(),
+            quantity,
+            kind,
+        }
+    }
+
+    /// 지정가 주문
+    pub fn limit(
+        exchange: Exchange,
+        side: OrderSide,
+        symbol: &str,
+        quantity: u64,
+        price: f64,
+    ) -> Self {
+        Self {
+            exchange,
+            side,
+            symbol: symbol.to_string(),
+            quantity,
+            kind: OverseasOrderKind::Limit { price },
+        }
+    }
+
+    /// 주문 검증 (모의투자는 지정가만 가능)
+    pub fn validate(&self, practice: bool) -> Result<(), Box<dyn Error>> {
+        if self.symbol.trim().is_empty() {
+            return Err("종목코드가 비어 있습니다".into());
+        }
+        if self.quantity == 0 {
+            return Err("주문 수량은 0보다 커야 합니다".into());
+        }
+        if practice && !matches!(self.kind, OverseasOrderKind::Limit { .. }) {
+            return Err("모의투자는 지정가 주문만 가능합니다".into());
+        }
+        self.kind.validate(self.exchange, self.side)?;
+        Ok(())
+    }
+
+    /// 검증 후 요청 문자열 준비
+    pub fn prepare(&self, practice: bool) -> Result<PreparedOverseasOrder, Box<dyn Error>> {
+        self.validate(practice)?;
+        let (start_time, end_time, algo_ord_tmd_dvsn_cd) = match self.kind.algo_window() {
+            Some(AlgoWindow::UntilClose) => (None, None, Some("02")),
+            Some(AlgoWindow::Between { start, end }) => {
+                (Some(start.clone()), Some(end.clone()), Some("00"))
+            }
+            None => (None, None, None),
+        };
+        Ok(PreparedOverseasOrder {
+            exchange: self.exchange,
+            side: self.side,
+            symbol: self.symbol.trim().to_string(),
+            quantity: self.quantity.to_string(),
+            price: format_price(self.kind.price().unwrap_or(0.0)),
+            ord_dvsn: self.kind.code(),
+            start_time,
+            end_time,
+            algo_ord_tmd_dvsn_cd,
+        })
+    }
+}
+
+/// 검증을 마친 해외주식 주문 (요청 구조체가 빌려 쓸 문자열 보관)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedOverseasOrder {
+    pub exchange: Exchange,
+    pub side: OrderSide,
+    pub symbol: String,
+    pub quantity: String,
+    pub price: String,
+    pub ord_dvsn: &'static str,
+    pub start_time: Option<String>,
+    pub end_time: Option<String>,
+    pub algo_ord_tmd_dvsn_cd: Option<&'static str>,
+}
+
+impl PreparedOverseasOrder {
+    /// 해외주식 주문 요청 (매도는 SLL_TYPE `00` 자동 지정)
+    pub fn request<'a>(&'a self, account: &'a AccountInfo) -> OverseasOrderRequest<'a> {
+        OverseasOrderRequest {
+            cano: &account.cano,
+            acnt_prdt_cd: &account.acnt_prdt_cd,
+            exchange: self.exchange,
+            pdno: &self.symbol,
+            ord_qty: &self.quantity,
+            ovrs_ord_unpr: &self.price,
+            ctac_tlno: "",
+            mgco_aptm_odno: "",
+            sll_type: match self.side {
+                OrderSide::Buy => "",
+                OrderSide::Sell => "00",
+            },
+            ord_svr_dvsn_cd: "0",
+            ord_dvsn: self.ord_dvsn,
+            start_time: self.start_time.as_deref(),
+            end_time: self.end_time.as_deref(),
+            algo_ord_tmd_dvsn_cd: self.algo_ord_tmd_dvsn_cd,
+        }
+    }
+}
+
+/// 정정/취소 구분
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum OverseasRevisionAction {
+    /// 정정 (RVSE_CNCL_DVSN_CD `01`)
+    Revise { quantity: u64, price: f64 },
+    /// 취소 (RVSE_CNCL_DVSN_CD `02`)
+    Cancel { quantity: u64 },
+}
+
+/// 타입으로 검증하는 해외주식 정정/취소
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OverseasRevision {
+    pub exchange: Exchange,
+    pub symbol: String,
+    /// 원주문번호
+    pub original_order_no: String,
+    pub action: OverseasRevisionAction,
+}
+
+impl OverseasRevision {
+    pub fn revise(
+        exchange: Exchange,
+        symbol: &str,
+        original_order_no: &str,
+        quantity: u64,
+        price: f64,
+    ) -> Self {
+        Self {
+            exchange,
+            symbol: symbol.to_string(),
+            original_order_no: original_order_no.to_string(),
+            action: OverseasRevisionAction::Revise { quantity, price },
+        }
+    }
+
+    pub fn cancel(
+        exchange: Exchange,
+        symbol: &str,
+        original_order_no: &str,
+        quantity: u64,
+    ) -> Self {
+        Self {
+            exchange,
+            symbol: symbol.to_string(),
+            original_order_no: original_order_no.to_string(),
+            action: OverseasRevisionAction::Cancel { quantity },
+        }
+    }
+
+    /// 검증 후 요청 문자열 준비
+    pub fn prepare(&self) -> Result<PreparedOverseasRevision, Box<dyn Error>> {
+        if self.original_order_no.trim().is_empty() {
+            return Err("원주문번호가 비어 있습니다".into());
+        }
+        let (code, quantity, price) = match self.action {
+            OverseasRevisionAction::Revise { quantity, price } => {
+                if !is_positive(price) {
+                    return Err("정정 단가는 0보다 커야 합니다".into());
+                }
+                ("01", quantity, price)
+            }
+            OverseasRevisionAction::Cancel { quantity } => ("02", quantity, 0.0),
+        };
+        if quantity == 0 {
+            return Err("정정/취소 수량은 0보다 커야 합니다".into());
+        }
+        Ok(PreparedOverseasRevision {
+            exchange: self.exchange,
+            symbol: self.symbol.trim().to_string(),
+            original_order_no: self.original_order_no.trim().to_string(),
+            rvse_cncl_dvsn_cd: code,
+            quantity: quantity.to_string(),
+            price: format_price(price),
+        })
+    }
+}
+
+/// 검증을 마친 해외주식 정정/취소
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedOverseasRevision {
+    pub exchange: Exchange,
+    pub symbol: String,
+    pub original_order_no: String,
+    pub rvse_cncl_dvsn_cd: &'static str,
+    pub quantity: String,
+    pub price: String,
+}
+
+impl PreparedOverseasRevision {
+    pub fn request<'a>(&'a self, account: &'a AccountInfo) -> OverseasRevisionRequest<'a> {
+        OverseasRevisionRequest {
+            cano: &account.cano,
+            acnt_prdt_cd: &account.acnt_prdt_cd,
+            exchange: self.exchange,
+            pdno: &self.symbol,
+            orgn_odno: &self.original_order_no,
+            rvse_cncl_dvsn_cd: self.rvse_cncl_dvsn_cd,
+            ord_qty: &self.quantity,
+            ovrs_ord_unpr: &self.price,
+            mgco_aptm_odno: "",
+            ord_svr_dvsn_cd: "0",
+        }
+    }
+}
+
+fn is_positive(price: f64) -> bool {
+    price.is_finite() && price > 0.0
+}
+
+/// 주문 단가 문자열 (소수점 4자리까지, 뒤쪽 0 제거)
+fn format_price(price: f64) -> String {
+    let text = format!("{price:.4}");
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn between(start: &str, end: &str) -> AlgoWindow {
+        AlgoWindow::Between {
+            start: start.to_string(),
+            end: end.to_string(),
+        }
+    }
+
+    #[test]
+    fn kind_validation_matrix() {
+        use OrderSide::{Buy, Sell};
+        use OverseasOrderKind::*;
+        let twap = Twap {
+            price: 10.0,
+            window: AlgoWindow::UntilClose,
+        };
+        let table = [
+            (Limit { price: 10.0 }, Exchange::Tokyo, Buy, true),
+            (Limit { price: 10.0 }, Exchange::Hanoi, Sell, true),
+            (OddLotLimit { price: 10.0 }, Exchange::HongKong, Sell, true),
+            (OddLotLimit { price: 10.0 }, Exchange::HongKong, Buy, false),
+            (OddLotLimit { price: 10.0 }, Exchange::Nasdaq, Sell, false),
+            (MarketOnOpen, Exchange::Nasdaq, Sell, true),
+            (MarketOnOpen, Exchange::Nasdaq, Buy, false),
+            (MarketOnClose, Exchange::Nyse, Sell, true),
+            (MarketOnClose, Exchange::Nyse, Buy, false),
+            (MarketOnClose, Exchange::HongKong, Sell, false),
+            (LimitOnOpen { price: 10.0 }, Exchange::Amex, Buy, true),
+            (LimitOnOpen { price: 10.0 }, Exchange::Tokyo, Buy, false),
+            (LimitOnClose { price: 10.0 }, Exchange::Nasdaq, Buy, true),
+            (
+                LimitOnClose { price: 10.0 },
+                Exchange::Shanghai,
+                Sell,
+                false,
+            ),
+            (twap.clone(), Exchange::Nasdaq, Buy, true),
+            (twap, Exchange::HongKong, Buy, false),
+            (Limit { price: 0.0 }, Exchange::Nasdaq, Buy, false),
+            (Limit { price: f64::NAN }, Exchange::Nasdaq, Buy, false),
+        ];
+        for (kind, exchange, side, ok) in table {
+            assert_eq!(
+                kind.validate(exchange, side).is_ok(),
+                ok,
+                "{kind:?} {exchange} {side:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn algo_window_must_be_hhmmss_and_ordered() {
+        let vwap = |window| OverseasOrderKind::Vwap {
+            price: 10.0,
+            window,
+        };
+        let validate = |window| vwap(window).validate(Exchange::Nasdaq, OrderSide::Buy);
+        assert!(validate(between("100000", "153000")).is_ok());
+        assert!(validate(between("1000", "153000")).is_err());
+        assert!(validate(between("10:00:", "153000")).is_err());
+        assert!(validate(between("153000", "100000")).is_err());
+        assert!(validate(between("100000", "100000")).is_err());
+    }
+
+    #[test]
+    fn order_validation() {
+        let limit = OverseasOrder::limit(Exchange::Nasdaq, OrderSide::Buy, "AAPL", 1, 190.5);
+        assert!(limit.validate(false).is_ok());
+        assert!(limit.validate(true).is_ok());
+
+        let moc = OverseasOrder::sell(
+            Exchange::Nasdaq,
+            "AAPL",
+            1,
+            OverseasOrderKind::MarketOnClose,
+        );
+        assert!(moc.validate(false).is_ok());
+        // 모의투자는 지정가만 가능
+        assert!(moc.validate(true).is_err());
+
+        let empty = OverseasOrder::limit(Exchange::Nasdaq, OrderSide::Buy, " ", 1, 190.5);
+        assert!(empty.validate(false).is_err());
+        let zero = OverseasOrder::limit(Exchange::Nasdaq, OrderSide::Buy, "AAPL", 0, 190.5);
+        assert!(zero.validate(false).is_err());
+        assert!(zero.prepare(false).is_err());
+    }
+
+    #[test]
+    fn price_is_trimmed_to_four_decimals() {
+        assert_eq!(format_price(190.5), "190.5");
+        assert_eq!(format_price(190.0), "190");
+        assert_eq!(format_price(0.12345), "0.1235");
+        assert_eq!(format_price(0.0001), "0.0001");
+        assert_eq!(format_price(0.0), "0");
+    }
+
+    #[test]
+    fn sell_request_sets_sll_type() {
+        let account = AccountInfo::new("12345678", "01");
+        let order = OverseasOrder::sell(
+            Exchange::HongKong,
+            " 00700 ",
+            100,
+            OverseasOrderKind::OddLotLimit { price: 320.2 },
+        );
+        let prepared = order.prepare(false).unwrap();
+        let body = serde_json::to_value(prepared.request(&account)).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "CANO": "12345678",
+                "ACNT_PRDT_CD": "01",
+                "OVRS_EXCG_CD": "SEHK",
+                "PDNO": "00700",
+                "ORD_QTY": "100",
+                "OVRS_ORD_UNPR": "320.2",
+                "CTAC_TLNO": "",
+                "MGCO_APTM_ODNO": "",
+                "SLL_TYPE": "00",
+                "ORD_SVR_DVSN_CD": "0",
+                "ORD_DVSN": "50",
+            })
+        );
+
+        let buy = OverseasOrder::limit(Exchange::Tokyo, OrderSide::Buy, "7203", 100, 2500.0);
+        let prepared = buy.prepare(false).unwrap();
+        let body = serde_json::to_value(prepared.request(&account)).unwrap();
+        assert_eq!(body["OVRS_EXCG_CD"], "TKSE");
+        assert_eq!(body["SLL_TYPE"], "");
+        assert_eq!(body["OVRS_ORD_UNPR"], "2500");
+    }
+
+    #[test]
+    fn market_kinds_send_zero_price() {
+        let account = AccountInfo::new("12345678", "01");
+        let order = OverseasOrder::sell(Exchange::Nyse, "IBM", 3, OverseasOrderKind::MarketOnOpen);
+        let prepared = order.prepare(false).unwrap();
+        let body = serde_json::to_value(prepared.request(&account)).unwrap();
+        assert_eq!(body["OVRS_ORD_UNPR"], "0");
+        assert_eq!(body["ORD_DVSN"], "31");
+        assert_eq!(body.get("ALGO_ORD_TMD_DVSN_CD"), None);
+    }
+
+    #[test]
+    fn algo_orders_set_time_division() {
+        let account = AccountInfo::new("12345678", "01");
+        let request = |window| {
+            let order = OverseasOrder::buy(
+                Exchange::Nasdaq,
+                "AAPL",
+                10,
+                OverseasOrderKind::Twap {
+                    price: 190.25,
+                    window,
+                },
+            );
+            let prepared = order.prepare(false).unwrap();
+            serde_json::to_value(prepared.request(&account)).unwrap()
+        };
+
+        let until_close = request(AlgoWindow::UntilClose);
+        assert_eq!(until_close["ORD_DVSN"], "35");
+        assert_eq!(until_close["ALGO_ORD_TMD_DVSN_CD"], "02");
+        assert_eq!(until_close.get("START_TIME"), None);
+        assert_eq!(until_close.get("END_TIME"), None);
+
+        let window = request(between("100000", "120000"));
+        assert_eq!(window["ALGO_ORD_TMD_DVSN_CD"], "00");
+        assert_eq!(window["START_TIME"], "100000");
+        assert_eq!(window["END_TIME"], "120000");
+        assert_eq!(window["SLL_TYPE"], Value::from(""));
+    }
+}
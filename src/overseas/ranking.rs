@@ -0,0 +1,720 @@
+use super::{Exchange, OverseasAnalysis, OverseasAnalysisEndpoint};
+use crate::provider::KISProvider;
+use crate::utils::ApiResponse;
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::ops::RangeInclusive;
+
+/// 거래량 조건 (VOL_RANG)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum VolumeRange {
+    #[default]
+    All,
+    Over100,
+    Over1K,
+    Over10K,
+    Over100K,
+    Over1M,
+    Over10M,
+}
+
+impl VolumeRange {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::All => "0",
+            Self::Over100 => "1",
+            Self::Over1K => "2",
+            Self::Over10K => "3",
+            Self::Over100K => "4",
+            Self::Over1M => "5",
+            Self::Over10M => "6",
+        }
+    }
+}
+
+/// 집계 기간 (NDAY)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RankingPeriod {
+    #[default]
+    Today,
+    Days2,
+    Days3,
+    Days5,
+    Days10,
+    Days20,
+    Days30,
+    Days60,
+    Days120,
+    Year,
+}
+
+impl RankingPeriod {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Today => "0",
+            Self::Days2 => "1",
+            Self::Days3 => "2",
+            Self::Days5 => "3",
+            Self::Days10 => "4",
+            Self::Days20 => "5",
+            Self::Days30 => "6",
+            Self::Days60 => "7",
+            Self::Days120 => "8",
+            Self::Year => "9",
+        }
+    }
+}
+
+/// 비교 기준 시점, N분 전 (MIXN)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RankingWindow {
+    #[default]
+    Minutes1,
+    Minutes2,
+    Minutes3,
+    Minutes5,
+    Minutes10,
+    Minutes15,
+    Minutes20,
+    Minutes30,
+    Minutes60,
+    Minutes120,
+}
+
+impl RankingWindow {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Minutes1 => "0",
+            Self::Minutes2 => "1",
+            Self::Minutes3 => "2",
+            Self::Minutes5 => "3",
+            Self::Minutes10 => "4",
+            Self::Minutes15 => "5",
+            Self::Minutes20 => "6",
+            Self::Minutes30 => "7",
+            Self::Minutes60 => "8",
+            Self::Minutes120 => "9",
+        }
+    }
+}
+
+/// 순위 방향 (GUBN)
+///
+/// 상승률/급등락 순위에서는 상승·하락, 신고/신저가 순위에서는 신고가·신저가를 뜻합니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum RankDirection {
+    #[default]
+    Up,
+    Down,
+}
+
+impl RankDirection {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Up => "1",
+            Self::Down => "0",
+        }
+    }
+}
+
+type Query = Vec<(&'static str, String)>;
+
+fn base_query(exchange: Exchange, volume_range: VolumeRange) -> Query {
+    vec![
+        ("AUTH", String::new()),
+        ("EXCD", exchange.quote_code().to_string()),
+        ("VOL_RANG", volume_range.code().to_string()),
+        ("KEYB", String::new()),
+    ]
+}
+
+fn price_query(query: &mut Query, min: Option<f64>, max: Option<f64>) {
+    let format = |price: Option<f64>| price.map(|p| p.to_string()).unwrap_or_default();
+    query.push(("PRC1", format(min)));
+    query.push(("PRC2", format(max)));
+}
+
+/// 거래량순위 (trade-vol)
+#[derive(Debug, Clone, Default)]
+pub struct TradeVolumeRankRequest {
+    pub exchange: Exchange,
+    pub period: RankingPeriod,
+    pub volume_range: VolumeRange,
+    /// 현재가 하한 (`None`이면 제한 없음)
+    pub price_min: Option<f64>,
+    /// 현재가 상한 (`None`이면 제한 없음)
+    pub price_max: Option<f64>,
+}
+
+impl TradeVolumeRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("NDAY", self.period.code().to_string()));
+        price_query(&mut query, self.price_min, self.price_max);
+        query
+    }
+}
+
+/// 거래대금순위 (trade-pbmn)
+#[derive(Debug, Clone, Default)]
+pub struct TradeAmountRankRequest {
+    pub exchange: Exchange,
+    pub period: RankingPeriod,
+    pub volume_range: VolumeRange,
+    pub price_min: Option<f64>,
+    pub price_max: Option<f64>,
+}
+
+impl TradeAmountRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("NDAY", self.period.code().to_string()));
+        price_query(&mut query, self.price_min, self.price_max);
+        query
+    }
+}
+
+/// 상승률/하락률 (updown-rate)
+#[derive(Debug, Clone, Default)]
+pub struct UpDownRateRankRequest {
+    pub exchange: Exchange,
+    pub period: RankingPeriod,
+    pub direction: RankDirection,
+    pub volume_range: VolumeRange,
+}
+
+impl UpDownRateRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("NDAY", self.period.code().to_string()));
+        query.push(("GUBN", self.direction.code().to_string()));
+        query
+    }
+}
+
+/// 가격 급등락 (price-fluct)
+#[derive(Debug, Clone, Default)]
+pub struct PriceFluctuationRankRequest {
+    pub exchange: Exchange,
+    pub window: RankingWindow,
+    pub direction: RankDirection,
+    pub volume_range: VolumeRange,
+}
+
+impl PriceFluctuationRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("MIXN", self.window.code().to_string()));
+        query.push(("GUBN", self.direction.code().to_string()));
+        query
+    }
+}
+
+/// 거래량 급증 (volume-surge)
+#[derive(Debug, Clone, Default)]
+pub struct VolumeSurgeRankRequest {
+    pub exchange: Exchange,
+    pub window: RankingWindow,
+    pub volume_range: VolumeRange,
+}
+
+impl VolumeSurgeRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("MIXN", self.window.code().to_string()));
+        query
+    }
+}
+
+/// 신고/신저가 (new-highlow)
+#[derive(Debug, Clone, Default)]
+pub struct NewHighLowRankRequest {
+    pub exchange: Exchange,
+    pub window: RankingWindow,
+    /// `Up`: 신고가, `Down`: 신저가
+    pub direction: RankDirection,
+    /// `false`: 일시 돌파, `true`: 돌파 유지
+    pub sustained: bool,
+    pub volume_range: VolumeRange,
+}
+
+impl NewHighLowRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("MIXN", self.window.code().to_string()));
+        query.push(("GUBN", self.direction.code().to_string()));
+        query.push(("GUBN2", if self.sustained { "1" } else { "0" }.to_string()));
+        query
+    }
+}
+
+/// 매수체결강도 상위 (volume-power)
+#[derive(Debug, Clone, Default)]
+pub struct VolumePowerRankRequest {
+    pub exchange: Exchange,
+    pub period: RankingPeriod,
+    pub volume_range: VolumeRange,
+}
+
+impl VolumePowerRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("NDAY", self.period.code().to_string()));
+        query
+    }
+}
+
+/// 거래증가율 (trade-growth)
+#[derive(Debug, Clone, Default)]
+pub struct TradeGrowthRankRequest {
+    pub exchange: Exchange,
+    pub period: RankingPeriod,
+    pub volume_range: VolumeRange,
+}
+
+impl TradeGrowthRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("NDAY", self.period.code().to_string()));
+        query
+    }
+}
+
+/// 거래회전율 (trade-turnover)
+#[derive(Debug, Clone, Default)]
+pub struct TradeTurnoverRankRequest {
+    pub exchange: Exchange,
+    pub period: RankingPeriod,
+    pub volume_range: VolumeRange,
+}
+
+impl TradeTurnoverRankRequest {
+    fn query(&self) -> Query {
+        let mut query = base_query(self.exchange, self.volume_range);
+        query.push(("NDAY", self.period.code().to_string()));
+        query
+    }
+}
+
+/// 시가총액순위 (market-cap)
+#[derive(Debug, Clone, Default)]
+pub struct MarketCapRankRequest {
+    pub exchange: Exchange,
+    pub volume_range: VolumeRange,
+}
+
+impl MarketCapRankRequest {
+    fn query(&self) -> Query {
+        base_query(self.exchange, self.volume_range)
+    }
+}
+
+/// 순위/조건검색 응답 요약 (output1)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverseasRankingSummary {
+    /// 소수점자리수
+    pub zdiv: String,
+    /// 거래상태정보
+    pub stat: String,
+    /// 현재조회종목수
+    pub crec: String,
+    /// 전체조회종목수
+    pub trec: String,
+    /// 요청레코드수
+    pub nrec: String,
+}
+
+/// 순위/조건검색 결과
+#[derive(Debug, Clone, Default)]
+pub struct RankingPage<R> {
+    pub summary: OverseasRankingSummary,
+    pub rows: Vec<R>,
+}
+
+/// 순위 응답 공통 필드
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverseasRankingQuote {
+    /// 실시간조회종목코드 (예: DNASAAPL)
+    pub rsym: String,
+    pub excd: String,
+    pub symb: String,
+    pub name: String,
+    pub ename: String,
+    pub last: String,
+    pub sign: String,
+    pub diff: String,
+    pub rate: String,
+    pub tvol: String,
+    pub tamt: String,
+    pub pask: String,
+    pub pbid: String,
+    pub rank: String,
+    /// 매매가능여부
+    pub e_ordyn: String,
+}
+
+/// 거래량순위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TradeVolumeRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// 기간 평균거래량
+    pub a_tvol: String,
+}
+
+/// 거래대금순위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TradeAmountRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// 기간 평균거래대금
+    pub a_tamt: String,
+}
+
+/// 기준가 대비 등락 행 (상승률/하락률, 가격 급등락, 신고/신저가)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PriceChangeRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// 기준가 (N일 전 또는 N분 전)
+    pub n_base: String,
+    pub n_diff: String,
+    pub n_rate: String,
+}
+
+/// 거래량 급증 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumeSurgeRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// N분 전 거래량
+    pub n_tvol: String,
+    /// 거래량 증가량
+    pub n_diff: String,
+    /// 거래량 증가율
+    pub n_rate: String,
+}
+
+/// 매수체결강도 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VolumePowerRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// 체결강도
+    pub tpow: String,
+    /// 기간 체결강도
+    pub powx: String,
+}
+
+/// 거래증가율 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TradeGrowthRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// 기간 평균거래량
+    pub n_tvol: String,
+    /// 거래량 증가율
+    pub n_rate: String,
+}
+
+/// 거래회전율 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TradeTurnoverRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// 기간 평균거래량
+    pub n_tvol: String,
+    /// 상장주식수
+    pub shar: String,
+    /// 회전율
+    pub tover: String,
+}
+
+/// 시가총액순위 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MarketCapRankRow {
+    #[serde(flatten)]
+    pub quote: OverseasRankingQuote,
+    /// 상장주식수
+    pub shar: String,
+    /// 시가총액
+    pub tomv: String,
+    /// 시가총액 비중
+    pub grav: String,
+}
+
+/// 해외주식 조건검색 (HHDFS76410000)
+///
+/// `None`인 조건은 검색에 쓰지 않습니다. 범위는 양 끝을 포함합니다.
+#[derive(Debug, Clone, Default)]
+pub struct OverseasScreenerRequest {
+    pub exchange: Exchange,
+    /// 현재가
+    pub price: Option<RangeInclusive<f64>>,
+    /// 등락율 (%)
+    pub change_rate: Option<RangeInclusive<f64>>,
+    /// 시가총액 (천 단위)
+    pub market_cap: Option<RangeInclusive<f64>>,
+    /// 발행주식수 (천주)
+    pub shares: Option<RangeInclusive<f64>>,
+    /// 거래량
+    pub volume: Option<RangeInclusive<f64>>,
+    /// 거래대금 (천 단위)
+    pub amount: Option<RangeInclusive<f64>>,
+    pub eps: Option<RangeInclusive<f64>>,
+    pub per: Option<RangeInclusive<f64>>,
+}
+
+impl OverseasScreenerRequest {
+    fn query(&self) -> Result<Vec<(String, String)>, Box<dyn Error>> {
+        let filters = [
+            ("PRICECUR", "현재가", &self.price),
+            ("RATE", "등락율", &self.change_rate),
+            ("VALX", "시가총액", &self.market_cap),
+            ("SHAR", "발행주식수", &self.shares),
+            ("VOLUME", "거래량", &self.volume),
+            ("AMT", "거래대금", &self.amount),
+            ("EPS", "EPS", &self.eps),
+            ("PER", "PER", &self.per),
+        ];
+        let mut query = vec![
+            ("AUTH".to_string(), String::new()),
+            ("EXCD".to_string(), self.exchange.quote_code().to_string()),
+            ("KEYB".to_string(), String::new()),
+        ];
+        for (key, name, range) in filters {
+            let (used, start, end) = match range {
+                Some(range) if range.start() > range.end() => {
+                    return Err(format!(
+                        "{name} 조건의 시작값이 종료값보다 큽니다 ({} > {})",
+                        range.start(),
+                        range.end()
+                    )
+                    .into());
+                }
+                Some(range) => ("1", range.start().to_string(), range.end().to_string()),
+                None => ("", String::new(), String::new()),
+            };
+            query.push((format!("CO_YN_{key}"), used.to_string()));
+            query.push((format!("CO_ST_{key}"), start));
+            query.push((format!("CO_EN_{key}"), end));
+        }
+        Ok(query)
+    }
+}
+
+/// 조건검색 결과 행
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OverseasScreenerRow {
+    pub rsym: String,
+    pub excd: String,
+    pub symb: String,
+    pub name: String,
+    pub ename: String,
+    pub last: String,
+    pub sign: String,
+    pub diff: String,
+    pub rate: String,
+    pub tvol: String,
+    pub popen: String,
+    pub phigh: String,
+    pub plow: String,
+    /// 발행주식수 (천주)
+    pub shar: String,
+    /// 시가총액 (천 단위)
+    pub valx: String,
+    /// 거래대금 (천 단위)
+    pub avol: String,
+    pub eps: String,
+    pub per: String,
+    pub rank: String,
+    /// 매매가능여부
+    pub e_ordyn: String,
+}
+
+#[async_trait]
+pub trait OverseasRankings {
+    /// 거래량순위
+    async fn get_overseas_trade_volume_rank(
+        &self,
+        request: &TradeVolumeRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeVolumeRankRow>>, Box<dyn Error>>;
+    /// 거래대금순위
+    async fn get_overseas_trade_amount_rank(
+        &self,
+        request: &TradeAmountRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeAmountRankRow>>, Box<dyn Error>>;
+    /// 상승률/하락률
+    async fn get_overseas_updown_rate_rank(
+        &self,
+        request: &UpDownRateRankRequest,
+    ) -> Result<ApiResponse<RankingPage<PriceChangeRankRow>>, Box<dyn Error>>;
+    /// 가격 급등락
+    async fn get_overseas_price_fluctuation_rank(
+        &self,
+        request: &PriceFluctuationRankRequest,
+    ) -> Result<ApiResponse<RankingPage<PriceChangeRankRow>>, Box<dyn Error>>;
+    /// 거래량 급증
+    async fn get_overseas_volume_surge_rank(
+        &self,
+        request: &VolumeSurgeRankRequest,
+    ) -> Result<ApiResponse<RankingPage<VolumeSurgeRankRow>>, Box<dyn Error>>;
+    /// 신고/신저가
+    async fn get_overseas_new_high_low_rank(
+        &self,
+        request: &NewHighLowRankRequest,
+    ) -> Result<ApiResponse<RankingPage<PriceChangeRankRow>>, Box<dyn Error>>;
+    /// 매수체결강도 상위
+    async fn get_overseas_volume_power_rank(
+        &self,
+        request: &VolumePowerRankRequest,
+    ) -> Result<ApiResponse<RankingPage<VolumePowerRankRow>>, Box<dyn Error>>;
+    /// 거래증가율
+    async fn get_overseas_trade_growth_rank(
+        &self,
+        request: &TradeGrowthRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeGrowthRankRow>>, Box<dyn Error>>;
+    /// 거래회전율
+    async fn get_overseas_trade_turnover_rank(
+        &self,
+        request: &TradeTurnoverRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeTurnoverRankRow>>, Box<dyn Error>>;
+    /// 시가총액순위
+    async fn get_overseas_market_cap_rank(
+        &self,
+        request: &MarketCapRankRequest,
+    ) -> Result<ApiResponse<RankingPage<MarketCapRankRow>>, Box<dyn Error>>;
+    /// 조건검색
+    async fn screen_overseas_stocks(
+        &self,
+        request: &OverseasScreenerRequest,
+    ) -> Result<ApiResponse<RankingPage<OverseasScreenerRow>>, Box<dyn Error>>;
+}
+
+async fn get_ranking<K: AsRef<str>, R: DeserializeOwned>(
+    provider: &KISProvider,
+    endpoint: OverseasAnalysisEndpoint,
+    query: Vec<(K, String)>,
+) -> Result<ApiResponse<RankingPage<R>>, Box<dyn Error>> {
+    let refs: Vec<(&str, &str)> = query
+        .iter()
+        .map(|(key, value)| (key.as_ref(), value.as_str()))
+        .collect();
+    let response = provider.get_overseas_analysis_raw(endpoint, &refs).await?;
+    Ok(ApiResponse {
+        body: RankingPage {
+            summary: response.body.output_as("output1")?,
+            rows: response.body.rows_as("output2")?,
+        },
+        headers: response.headers,
+    })
+}
+
+#[async_trait]
+impl OverseasRankings for KISProvider {
+    async fn get_overseas_trade_volume_rank(
+        &self,
+        request: &TradeVolumeRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeVolumeRankRow>>, Box<dyn Error>> {
+        get_ranking(self, OverseasAnalysisEndpoint::TradeVolume, request.query()).await
+    }
+
+    async fn get_overseas_trade_amount_rank(
+        &self,
+        request: &TradeAmountRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeAmountRankRow>>, Box<dyn Error>> {
+        get_ranking(
+            self,
+            OverseasAnalysisEndpoint::TradeAmountRank,
+            request.query(),
+        )
+        .await
+    }
+
+    async fn get_overseas_updown_rate_rank(
+        &self,
+        request: &UpDownRateRankRequest,
+    ) -> Result<ApiResponse<RankingPage<PriceChangeRankRow>>, Box<dyn Error>> {
+        get_ranking(self, OverseasAnalysisEndpoint::UpDownRate, request.query()).await
+    }
+
+    async fn get_overseas_price_fluctuation_rank(
+        &self,
+        request: &PriceFluctuationRankRequest,
+    ) -> Result<ApiResponse<RankingPage<PriceChangeRankRow>>, Box<dyn Error>> {
+        get_ranking(
+            self,
+            OverseasAnalysisEndpoint::PriceFluctuation,
+            request.query(),
+        )
+        .await
+    }
+
+    async fn get_overseas_volume_surge_rank(
+        &self,
+        request: &VolumeSurgeRankRequest,
+    ) -> Result<ApiResponse<RankingPage<VolumeSurgeRankRow>>, Box<dyn Error>> {
+        get_ranking(self, OverseasAnalysisEndpoint::VolumeSurge, request.query()).await
+    }
+
+    async fn get_overseas_new_high_low_rank(
+        &self,
+        request: &NewHighLowRankRequest,
+    ) -> Result<ApiResponse<RankingPage<PriceChangeRankRow>>, Box<dyn Error>> {
+        get_ranking(self, OverseasAnalysisEndpoint::NewHighLow, request.query()).await
+    }
+
+    async fn get_overseas_volume_power_rank(
+        &self,
+        request: &VolumePowerRankRequest,
+    ) -> Result<ApiResponse<RankingPage<VolumePowerRankRow>>, Box<dyn Error>> {
+        get_ranking(self, OverseasAnalysisEndpoint::VolumePower, request.query()).await
+    }
+
+    async fn get_overseas_trade_growth_rank(
+        &self,
+        request: &TradeGrowthRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeGrowthRankRow>>, Box<dyn Error>> {
+        get_ranking(self, OverseasAnalysisEndpoint::TradeGrowth, request.query()).await
+    }
+
+    async fn get_overseas_trade_turnover_rank(
+        &self,
+        request: &TradeTurnoverRankRequest,
+    ) -> Result<ApiResponse<RankingPage<TradeTurnoverRankRow>>, Box<dyn Error>> {
+        get_ranking(
+            self,
+            OverseasAnalysisEndpoint::TradeTurnover,
+            request.query(),
+        )
+        .await
+    }
+
+    async fn get_overseas_market_cap_rank(
+        &self,
+        request: &MarketCapRankRequest,
+    ) -> Result<ApiResponse<RankingPage<MarketCapRankRow>>, Box<dyn Error>> {
+        get_ranking(self, OverseasAnalysisEndpoint::MarketCap, request.query()).await
+    }
+
+    async fn screen_overseas_stocks(
+        &self,
+        request: &OverseasScreenerRequest,
+    ) -> Result<ApiResponse<RankingPage<OverseasScreenerRow>>, Box<dyn Error>> {
+        let query = request.query()?;
+        get_ranking(self, OverseasAnalysisEndpoint::ConditionSearch, query).await
+    }
+}
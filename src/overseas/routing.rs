@@ -0,0 +1,340 @@
+use super::{
+    Exchange, OverseasDaytimeOrderRequest, OverseasOrder, OverseasOrderRequest, OverseasTrading,
+};
+use crate::calendar::{MarketCalendar, Session, SessionWindow};
+use crate::provider::KISProvider;
+use crate::types::AccountInfo;
+use crate::utils::{ApiResponse, RawApiBody};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fmt;
+use std::time::SystemTime;
+
+/// 주문 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+/// 해외주식 주문 경로
+///
+/// 미국 주식은 같은 종목이라도 세션에 따라 주문 API가 다릅니다.
+/// - 정규장/프리마켓/애프터마켓: 해외주식 주문 (TTTT1002U / TTTT1006U)
+/// - 주간거래 (한국시간 10:00~18:00, 서머타임 09:00~17:00): 미국주간주문 (TTTS6036U / TTTS6037U)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum OverseasOrderRoute {
+    Regular,
+    PreMarket,
+    AfterMarket,
+    Daytime,
+}
+
+impl OverseasOrderRoute {
+    /// 세션으로 주문 경로 결정 (주문을 받지 않는 세션이면 `None`)
+    pub fn for_session(exchange: Exchange, session: Session) -> Option<Self> {
+        match session {
+            Session::Regular | Session::ClosingAuction => Some(Self::Regular),
+            Session::PreOpenAuction if !exchange.is_us() => Some(Self::Regular),
+            Session::PreMarket if exchange.is_us() => Some(Self::PreMarket),
+            Session::AfterMarket if exchange.is_us() => Some(Self::AfterMarket),
+            Session::Daytime if exchange.is_us() => Some(Self::Daytime),
+            _ => None,
+        }
+    }
+
+    /// 시각 `t`의 주문 경로와 세션
+    ///
+    /// 세션 시간표가 없는 거래소(베트남)는 시각과 관계없이 정규 주문으로 보냅니다.
+    pub fn at(
+        calendar: &MarketCalendar,
+        exchange: Exchange,
+        t: SystemTime,
+    ) -> Result<(Self, Option<SessionWindow>), Box<dyn Error>> {
+        let Some(market) = exchange.calendar_market() else {
+            return Ok((Self::Regular, None));
+        };
+        calendar
+            .session_at(market, t)
+            .and_then(|window| {
+                Self::for_session(exchange, window.session).map(|route| (route, Some(window)))
+            })
+            .ok_or_else(|| format!("{exchange} 주문을 받는 세션이 아닙니다").into())
+    }
+
+    /// 경로별 허용 주문구분 (ORD_DVSN)
+    ///
+    /// 정규장 외 세션은 지정가(`00`)만 받습니다. 정규장에서는 `OverseasOrderKind`의
+    /// 미국 LOO/LOC/TWAP/VWAP(매수·매도), MOO/MOC(매도)와 홍콩 단주지정가(매도)를 추가로 받습니다.
+    pub fn allowed_ord_dvsn(&self, exchange: Exchange, side: OrderSide) -> &'static [&'static str] {
+        match (self, exchange, side) {
+            (Self::Regular, _, OrderSide::Buy) if exchange.is_us() => {
+                &["00", "32", "34", "35", "36"]
+            }
+            (Self::Regular, _, OrderSide::Sell) if exchange.is_us() => {
+                &["00", "31", "32", "33", "34", "35", "36"]
+            }
+            (Self::Regular, Exchange::HongKong, OrderSide::Sell) => &["00", "50"],
+            _ => &["00"],
+        }
+    }
+}
+
+impl fmt::Display for OverseasOrderRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Regular => "정규장",
+            Self::PreMarket => "프리마켓",
+            Self::AfterMarket => "애프터마켓",
+            Self::Daytime => "주간거래",
+        })
+    }
+}
+
+/// 경로를 자동 선택한 주문 결과
+#[derive(Debug, Clone)]
+pub struct RoutedOrder {
+    pub route: OverseasOrderRoute,
+    /// 주문 시점 세션 (세션 시간표가 없는 거래소는 `None`)
+    pub session: Option<SessionWindow>,
+    pub response: ApiResponse<RawApiBody>,
+}
+
+#[async_trait]
+pub trait OverseasOrderRouting {
+    /// 현재 세션에 맞는 주문 API로 해외주식 주문
+    ///
+    /// 주간거래 중이면 미국주간주문으로, 그 외에는 해외주식 주문으로 보냅니다.
+    /// `ord_dvsn`이 비어 있으면 지정가(`00`)로 채우고, 경로가 받지 않는 주문구분이거나
+    /// 주문을 받는 세션이 없으면 주문하지 않고 오류를 돌려줍니다.
+    async fn place_overseas_order(
+        &self,
+        calendar: &MarketCalendar,
+        side: OrderSide,
+        request: OverseasOrderRequest<'_>,
+    ) -> Result<RoutedOrder, Box<dyn Error>>;
+
+    /// 타입으로 검증한 주문을 현재 세션에 맞는 주문 API로 전송
+    async fn submit_overseas_order(
+        &self,
+        calendar: &MarketCalendar,
+        account: &AccountInfo,
+        order: &OverseasOrder,
+    ) -> Result<RoutedOrder, Box<dyn Error>>;
+}
+
+#[async_trait]
+impl OverseasOrderRouting for KISProvider {
+    async fn place_overseas_order(
+        &self,
+        calendar: &MarketCalendar,
+        side: OrderSide,
+        mut request: OverseasOrderRequest<'_>,
+    ) -> Result<RoutedOrder, Box<dyn Error>> {
+        let (route, session) =
+            OverseasOrderRoute::at(calendar, request.exchange, SystemTime::now())?;
+        if request.ord_dvsn.is_empty() {
+            request.ord_dvsn = "00";
+        }
+        if !route
+            .allowed_ord_dvsn(request.exchange, side)
+            .contains(&request.ord_dvsn)
+        {
+            return Err(format!(
+                "{} {route} 주문에서는 주문구분 {}을(를) 사용할 수 없습니다",
+                request.exchange, request.ord_dvsn
+            )
+            .into());
+        }
+
+        let response = match (route, side) {
+            (OverseasOrderRoute::Daytime, side) => {
+                let daytime = OverseasDaytimeOrderRequest {
+                    cano: request.cano,
+                    acnt_prdt_cd: request.acnt_prdt_cd,
+                    exchange: request.exchange,
+                    pdno: request.pdno,
+                    ord_qty: request.ord_qty,
+                    ovrs_ord_unpr: request.ovrs_ord_unpr,
+                    ctac_tlno: request.ctac_tlno,
+                    mgco_aptm_odno: request.mgco_aptm_odno,
+                    ord_svr_dvsn_cd: request.ord_svr_dvsn_cd,
+                    ord_dvsn: request.ord_dvsn,
+                };
+                match side {
+                    OrderSide::Buy => self.place_overseas_daytime_buy_order(daytime).await?,
+                    OrderSide::Sell => self.place_overseas_daytime_sell_order(daytime).await?,
+                }
+            }
+            (_, OrderSide::Buy) => self.place_overseas_buy_order(request).await?,
+            (_, OrderSide::Sell) => self.place_overseas_sell_order(request).await?,
+        };
+        Ok(RoutedOrder {
+            route,
+            session,
+            response,
+        })
+    }
+
+    async fn submit_overseas_order(
+        &self,
+        calendar: &MarketCalendar,
+        account: &AccountInfo,
+        order: &OverseasOrder,
+    ) -> Result<RoutedOrder, Box<dyn Error>> {
+        let prepared = order.prepare(self.practice)?;
+        self.place_overseas_order(calendar, order.side, prepared.request(account))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calendar::Date;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn utc(year: i32, month: u32, day: u32, hour: u64, minute: u64) -> SystemTime {
+        let days = Date::new(year, month, day).unwrap().days() as u64;
+        UNIX_EPOCH + Duration::from_secs(days * 86_400 + hour * 3_600 + minute * 60)
+    }
+
+    #[test]
+    fn route_for_each_session() {
+        use Session::*;
+        let table = [
+            (Exchange::Nasdaq, Daytime, Some(OverseasOrderRoute::Daytime)),
+            (
+                Exchange::Nasdaq,
+                PreMarket,
+                Some(OverseasOrderRoute::PreMarket),
+            ),
+            (
+                Exchange::Nyse,
+                Session::Regular,
+                Some(OverseasOrderRoute::Regular),
+            ),
+            (
+                Exchange::Amex,
+                ClosingAuction,
+                Some(OverseasOrderRoute::Regular),
+            ),
+            (
+                Exchange::Nasdaq,
+                AfterMarket,
+                Some(OverseasOrderRoute::AfterMarket),
+            ),
+            (Exchange::Nasdaq, PreOpenAuction, None),
+            (Exchange::Nasdaq, AfterHoursSingle, None),
+            (
+                Exchange::HongKong,
+                PreOpenAuction,
+                Some(OverseasOrderRoute::Regular),
+            ),
+            (
+                Exchange::HongKong,
+                Session::Regular,
+                Some(OverseasOrderRoute::Regular),
+            ),
+            (
+                Exchange::HongKong,
+                ClosingAuction,
+                Some(OverseasOrderRoute::Regular),
+            ),
+            (Exchange::HongKong, Session::Daytime, None),
+            (Exchange::HongKong, Session::AfterMarket, None),
+            (
+                Exchange::Tokyo,
+                Session::Regular,
+                Some(OverseasOrderRoute::Regular),
+            ),
+            (Exchange::Tokyo, Session::PreMarket, None),
+            (Exchange::Tokyo, AfterHoursClose, None),
+        ];
+        for (exchange, session, route) in table {
+            assert_eq!(
+                OverseasOrderRoute::for_session(exchange, session),
+                route,
+                "{exchange} {session:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn route_at_us_and_asian_times() {
+        let calendar = MarketCalendar::new();
+        let route =
+            |exchange, t| OverseasOrderRoute::at(&calendar, exchange, t).map(|(route, _)| route);
+        // 2025-01-06(월), 미국 동부 표준시(UTC-5)
+        let table = [
+            // 10:00 ET 정규장
+            (utc(2025, 1, 6, 15, 0), OverseasOrderRoute::Regular),
+            // 05:00 ET 프리마켓
+            (utc(2025, 1, 6, 10, 0), OverseasOrderRoute::PreMarket),
+            // 17:00 ET 애프터마켓
+            (utc(2025, 1, 6, 22, 0), OverseasOrderRoute::AfterMarket),
+            // 22:00 ET = 한국시간 1/7 12:00 주간거래
+            (utc(2025, 1, 7, 3, 0), OverseasOrderRoute::Daytime),
+        ];
+        for (t, expected) in table {
+            assert_eq!(route(Exchange::Nasdaq, t).unwrap(), expected);
+        }
+        // 토요일은 주문을 받는 세션이 없음
+        assert!(route(Exchange::Nasdaq, utc(2025, 1, 11, 15, 0)).is_err());
+
+        // 홍콩 09:15 개장 전 단일가, 10:00 정규장, 일본 10:00 정규장
+        assert_eq!(
+            route(Exchange::HongKong, utc(2025, 1, 6, 1, 15)).unwrap(),
+            OverseasOrderRoute::Regular
+        );
+        assert_eq!(
+            route(Exchange::HongKong, utc(2025, 1, 6, 2, 0)).unwrap(),
+            OverseasOrderRoute::Regular
+        );
+        assert_eq!(
+            route(Exchange::Tokyo, utc(2025, 1, 6, 1, 0)).unwrap(),
+            OverseasOrderRoute::Regular
+        );
+        // 홍콩 장 마감 후
+        assert!(route(Exchange::HongKong, utc(2025, 1, 6, 10, 0)).is_err());
+
+        // 세션 시간표가 없는 베트남은 항상 정규 주문
+        let (route, session) =
+            OverseasOrderRoute::at(&calendar, Exchange::Hanoi, utc(2025, 1, 11, 0, 0)).unwrap();
+        assert_eq!((route, session), (OverseasOrderRoute::Regular, None));
+    }
+
+    #[test]
+    fn allowed_ord_dvsn_per_route() {
+        use OverseasOrderRoute::*;
+        let table: [(OverseasOrderRoute, Exchange, OrderSide, &[&str]); 9] = [
+            (
+                Regular,
+                Exchange::Nasdaq,
+                OrderSide::Buy,
+                &["00", "32", "34", "35", "36"],
+            ),
+            (
+                Regular,
+                Exchange::Nyse,
+                OrderSide::Sell,
+                &["00", "31", "32", "33", "34", "35", "36"],
+            ),
+            (Daytime, Exchange::Nasdaq, OrderSide::Buy, &["00"]),
+            (Daytime, Exchange::Nasdaq, OrderSide::Sell, &["00"]),
+            (PreMarket, Exchange::Amex, OrderSide::Sell, &["00"]),
+            (AfterMarket, Exchange::Nasdaq, OrderSide::Buy, &["00"]),
+            (Regular, Exchange::HongKong, OrderSide::Sell, &["00", "50"]),
+            (Regular, Exchange::HongKong, OrderSide::Buy, &["00"]),
+            (Regular, Exchange::Tokyo, OrderSide::Sell, &["00"]),
+        ];
+        for (route, exchange, side, expected) in table {
+            assert_eq!(
+                route.allowed_ord_dvsn(exchange, side),
+                expected,
+                "{exchange} {route} {side:?}"
+            );
+        }
+    }
+}
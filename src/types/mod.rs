@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum CustType {
@@ -14,6 +16,108 @@ pub enum MarketType {
     Overseas,
 }
 
+/// 국내 시세 조회 시장 분류 (FID_COND_MRKT_DIV_CODE)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MarketDivision {
+    /// KRX 주식/ETF (J)
+    #[default]
+    Krx,
+    /// 넥스트레이드 (NX)
+    Nxt,
+    /// KRX+NXT 통합 (UN)
+    Unified,
+    /// ETN (E)
+    Etn,
+    /// ELW (W)
+    Elw,
+    /// 업종/지수 (U)
+    Index,
+}
+
+impl MarketDivision {
+    /// 주식 시세 API 대부분이 지원하는 분류 (KRX/NXT/통합)
+    pub const STOCK: &'static [MarketDivision] = &[Self::Krx, Self::Nxt, Self::Unified];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Krx => "J",
+            Self::Nxt => "NX",
+            Self::Unified => "UN",
+            Self::Etn => "E",
+            Self::Elw => "W",
+            Self::Index => "U",
+        }
+    }
+
+    /// `api`가 이 시장 분류를 지원하지 않으면 오류
+    pub fn ensure_supported(
+        &self,
+        api: &str,
+        supported: &[MarketDivision],
+    ) -> Result<(), Box<dyn Error>> {
+        if supported.contains(self) {
+            return Ok(());
+        }
+        let codes: Vec<&str> = supported.iter().map(|division| division.code()).collect();
+        Err(format!(
+            "{api}는 시장 분류 {}를 지원하지 않습니다 (지원: {})",
+            self.code(),
+            codes.join(", ")
+        )
+        .into())
+    }
+}
+
+impl fmt::Display for MarketDivision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl FromStr for MarketDivision {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        match code {
+            "J" => Ok(Self::Krx),
+            "NX" => Ok(Self::Nxt),
+            "UN" => Ok(Self::Unified),
+            "E" => Ok(Self::Etn),
+            "W" => Ok(Self::Elw),
+            "U" => Ok(Self::Index),
+            _ => Err(format!("알 수 없는 시장 분류 코드: {code}")),
+        }
+    }
+}
+
+/// 수정주가 반영 여부
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PriceAdjustment {
+    /// 수정주가 (액면분할/권리락 등 반영)
+    #[default]
+    Adjusted,
+    /// 원주가
+    Unadjusted,
+}
+
+impl PriceAdjustment {
+    /// 국내 기간별 시세 `FID_ORG_ADJ_PRC` (0: 수정주가, 1: 원주가)
+    pub(crate) fn domestic_code(self) -> &'static str {
+        match self {
+            Self::Adjusted => "0",
+            Self::Unadjusted => "1",
+        }
+    }
+
+    /// 해외 기간별 시세 `MODP` (1: 수정주가, 0: 원주가)
+    pub(crate) fn overseas_code(self) -> &'static str {
+        match self {
+            Self::Adjusted => "1",
+            Self::Unadjusted => "0",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountInfo {
     pub cano: String,
@@ -45,3 +149,56 @@ pub struct ContinuationKey {
     pub fk: String,
     pub nk: String,
 }
+
+// ======================================================
+// 호가 조회 공통 응답 (국내 inquire-asking-price-exp-ccn / 해외 inquire-asking-price)
+// ======================================================
+
+/// 호가 1단계 (가격이 없는 단계는 0)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct Level {
+    pub price: f64,
+    pub quantity: u64,
+    /// 직전 대비 잔량 증감
+    pub change: i64,
+}
+
+/// 총잔량
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct OrderbookTotals {
+    pub ask_quantity: u64,
+    pub bid_quantity: u64,
+    pub ask_change: i64,
+    pub bid_change: i64,
+}
+
+/// 예상 체결 (동시호가 구간)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExpectedExecution {
+    /// 예상체결가
+    pub price: f64,
+    /// 예상체결량
+    pub quantity: u64,
+    /// 예상거래량
+    pub volume: u64,
+    /// 예상체결 전일대비
+    pub change: f64,
+    /// 예상체결 전일대비부호
+    pub sign: String,
+    /// 예상체결 전일대비율
+    pub change_rate: f64,
+}
+
+/// 10단계 호가
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Orderbook {
+    /// 호가 시각 (국내 HHMMSS, 해외 현지 YYYYMMDDHHMMSS)
+    pub time: String,
+    /// 매도호가 (1호가부터)
+    pub asks: [Level; 10],
+    /// 매수호가 (1호가부터)
+    pub bids: [Level; 10],
+    pub totals: OrderbookTotals,
+    /// 예상체결 정보 (국내 동시호가 구간에서만 제공)
+    pub expected: Option<ExpectedExecution>,
+}
@@ -8,6 +8,7 @@ use serde_json::Value;
 use std::collections::BTreeMap;
 use std::error::Error;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 static HTTP_CLIENT: OnceLock<Client> = OnceLock::new();
 
@@ -143,6 +144,57 @@ impl RawApiBody {
             Err(format!("API 오류: {} ({})", self.msg1, self.msg_cd).into())
         }
     }
+
+    /// output 블록 조회 (배열이면 첫 행)
+    pub fn output(&self, key: &str) -> Option<&Value> {
+        match self.extra.get(key)? {
+            Value::Array(rows) => rows.first(),
+            output => Some(output),
+        }
+    }
+
+    /// output 블록을 행 목록으로 조회 (객체 하나면 1행)
+    pub fn rows(&self, key: &str) -> Vec<&Value> {
+        match self.extra.get(key) {
+            Some(Value::Array(rows)) => rows.iter().collect(),
+            Some(Value::Null) | None => Vec::new(),
+            Some(output) => vec![output],
+        }
+    }
+
+    /// output 블록을 타입 모델 목록으로 변환
+    pub fn rows_as<T: DeserializeOwned>(&self, key: &str) -> Result<Vec<T>, serde_json::Error> {
+        self.rows(key)
+            .into_iter()
+            .map(|row| T::deserialize(row))
+            .collect()
+    }
+
+    /// output 블록(배열이면 첫 행)을 타입 모델로 변환 (없으면 기본값)
+    pub fn output_as<T: DeserializeOwned + Default>(
+        &self,
+        key: &str,
+    ) -> Result<T, serde_json::Error> {
+        self.output(key)
+            .map(T::deserialize)
+            .transpose()
+            .map(Option::unwrap_or_default)
+    }
+}
+
+/// JSON 객체의 문자열 필드 (없으면 빈 문자열)
+pub(crate) fn json_str<'a>(value: &'a Value, key: &str) -> &'a str {
+    value.get(key).and_then(Value::as_str).unwrap_or("")
+}
+
+/// JSON 객체의 숫자 문자열 필드 파싱 (없거나 잘못된 값이면 기본값)
+pub(crate) fn json_num<T: std::str::FromStr + Default>(value: &Value, key: &str) -> T {
+    json_str(value, key).trim().parse().unwrap_or_default()
+}
+
+/// 연속/일괄 호출 사이 최소 간격 (실전 초당 20건, 모의투자 초당 2건 제한 기준)
+pub(crate) fn request_interval(practice: bool) -> Duration {
+    Duration::from_millis(if practice { 500 } else { 60 })
 }
 
 fn build_headers(
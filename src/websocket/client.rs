@@ -0,0 +1,643 @@
+use super::oauth::ApproveOauth;
+use super::record::RealtimeRecorder;
+use super::stream::{Fanout, FanoutDispatch, RealtimeSubscription, SubscriptionOptions};
+use super::{RawRealtimeData, RealtimeData, RealtimeInfoType};
+use crate::provider::KISProvider;
+use crate::types::CustType;
+#[cfg(feature = "ex")]
+use dotenv::dotenv;
+use futures_util::{Sink, SinkExt, stream::StreamExt};
+use serde_json::json;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::sync::Arc;
+use tokio::sync::{Notify, mpsc, oneshot};
+use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+
+const REAL_WS_DOMAIN: &str = "ws://ops.koreainvestment.com:21000";
+const PRACTICE_WS_DOMAIN: &str = "ws://ops.koreainvestment.com:31000";
+/// 수신 task와 전달 task 사이 대기열 크기 (레코드 수)
+const DELIVERY_BACKLOG: usize = 1024;
+/// 웹소켓 연결 하나에 등록할 수 있는 실시간 구독 수
+pub const REALTIME_REGISTRATION_LIMIT: usize = 41;
+
+/// 실시간 데이터 관련 오류
+#[derive(Debug)]
+pub enum RealtimeError {
+    ConnectionError(String),
+    AuthError(String),
+    MessageError(String),
+    EnvError(String),
+    OverflowError(String),
+    InvalidInput(String),
+    LimitError(String),
+}
+
+impl RealtimeError {
+    pub(crate) fn registration_limit() -> Self {
+        Self::LimitError(format!(
+            "연결당 실시간 등록은 최대 {REALTIME_REGISTRATION_LIMIT}건입니다"
+        ))
+    }
+}
+
+impl Error for RealtimeError {}
+
+impl fmt::Display for RealtimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ConnectionError(msg) => write!(f, "연결 오류: {}", msg),
+            Self::AuthError(msg) => write!(f, "인증 오류: {}", msg),
+            Self::MessageError(msg) => write!(f, "메시지 오류: {}", msg),
+            Self::EnvError(msg) => write!(f, "환경 변수 오류: {}", msg),
+            Self::OverflowError(msg) => write!(f, "버퍼 초과: {}", msg),
+            Self::InvalidInput(msg) => write!(f, "잘못된 입력: {}", msg),
+            Self::LimitError(msg) => write!(f, "구독 한도 초과: {}", msg),
+        }
+    }
+}
+
+impl From<reqwest::Error> for RealtimeError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::ConnectionError(error.to_string())
+    }
+}
+
+/// 국내/해외 공통 실시간 클라이언트
+///
+/// 실전/모의 여부에 따라 접속키 발급 도메인과 웹소켓 포트(실전 21000, 모의 31000)를 고릅니다.
+#[derive(Debug, Clone)]
+pub struct RealtimeClient {
+    approval_key: String,
+    cust_type: CustType,
+    practice: bool,
+    recorder: Option<RealtimeRecorder>,
+}
+
+impl RealtimeClient {
+    /// 앱키/시크릿으로 실전 접속키를 발급받아 클라이언트 생성
+    pub async fn new(
+        app_key: String,
+        app_secret: String,
+        cust_type: CustType,
+    ) -> Result<Self, RealtimeError> {
+        Self::with_practice(app_key, app_secret, cust_type, false).await
+    }
+
+    /// 실전/모의 여부를 지정해 클라이언트 생성
+    pub async fn with_practice(
+        app_key: String,
+        app_secret: String,
+        cust_type: CustType,
+        practice: bool,
+    ) -> Result<Self, RealtimeError> {
+        let approval = ApproveOauth::issue(app_key, app_secret, practice)
+            .await
+            .map_err(|error| RealtimeError::AuthError(error.to_string()))?;
+        Ok(Self {
+            approval_key: approval.approval_key,
+            cust_type,
+            practice,
+            recorder: None,
+        })
+    }
+
+    /// 기존 Provider의 앱키와 실전/모의 설정으로 클라이언트 생성
+    pub async fn from_provider(provider: &KISProvider) -> Result<Self, RealtimeError> {
+        Self::with_practice(
+            provider.oauth.app_key.clone(),
+            provider.oauth.app_secret.clone(),
+            provider.oauth.cust_type,
+            provider.practice,
+        )
+        .await
+    }
+
+    /// 환경 변수에서 실전 클라이언트 생성
+    pub async fn from_env(cust_type: CustType) -> Result<Self, RealtimeError> {
+        #[cfg(feature = "ex")]
+        dotenv().ok();
+
+        let app_key = env::var("PUB_KEY")
+            .map_err(|_| RealtimeError::EnvError("APP_KEY not set in .env file".to_string()))?;
+        let app_secret = env::var("SCREST_KEY")
+            .map_err(|_| RealtimeError::EnvError("APP_SECRET not set in .env file".to_string()))?;
+        Self::new(app_key, app_secret, cust_type).await
+    }
+
+    pub fn practice(&self) -> bool {
+        self.practice
+    }
+
+    /// 이후 시작하는 모든 구독의 수신 데이터를 기록
+    pub fn with_recorder(mut self, recorder: RealtimeRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    fn ws_domain(&self) -> &'static str {
+        if self.practice {
+            PRACTICE_WS_DOMAIN
+        } else {
+            REAL_WS_DOMAIN
+        }
+    }
+
+    /// 실시간 데이터 스트림 시작 (콜백)
+    pub async fn start_stream<T: RealtimeData + Send + 'static>(
+        &self,
+        symbol: &str,
+        r#type: impl Into<RealtimeInfoType>,
+        callback: impl FnMut(T) + Send + 'static,
+    ) -> Result<StreamController, RealtimeError> {
+        self.connect(
+            symbol,
+            r#type.into(),
+            CallbackDispatch {
+                callback,
+                _data: PhantomData,
+            },
+        )
+        .await
+    }
+
+    /// 실시간 구독 시작 (`futures::Stream` 기반)
+    ///
+    /// 반환된 구독에서 [`RealtimeSubscription::stream`]을 여러 번 호출하면
+    /// 하나의 웹소켓 구독을 여러 task가 나눠 받을 수 있습니다.
+    pub async fn subscribe<T: RealtimeData + Clone + Send + Sync + 'static>(
+        &self,
+        symbol: &str,
+        r#type: impl Into<RealtimeInfoType>,
+        options: SubscriptionOptions,
+    ) -> Result<RealtimeSubscription<T>, RealtimeError> {
+        let fanout = Arc::new(Fanout::new());
+        let controller = self
+            .connect(
+                symbol,
+                r#type.into(),
+                FanoutDispatch {
+                    fanout: fanout.clone(),
+                },
+            )
+            .await?;
+        Ok(RealtimeSubscription::new(fanout, controller, options))
+    }
+
+    async fn connect(
+        &self,
+        symbol: &str,
+        r#type: RealtimeInfoType,
+        dispatch: impl Dispatch,
+    ) -> Result<StreamController, RealtimeError> {
+        let controller = self.open(r#type, dispatch).await?;
+        if let Err(error) = controller.register(symbol).await {
+            let _ = controller.stop().await;
+            return Err(error);
+        }
+        Ok(controller)
+    }
+
+    /// 등록 없이 웹소켓 연결만 열기
+    ///
+    /// 종목은 [`StreamController::register`]로 같은 연결에 등록/해제합니다.
+    pub(crate) async fn open(
+        &self,
+        r#type: RealtimeInfoType,
+        mut dispatch: impl Dispatch,
+    ) -> Result<StreamController, RealtimeError> {
+        let tr_code = r#type.get_tr_code();
+        let url = format!("{}/tryitout/{tr_code}", self.ws_domain());
+        let (ws_stream, _) = connect_async(url)
+            .await
+            .map_err(|e| RealtimeError::ConnectionError(e.to_string()))?;
+
+        let (mut write, mut read) = ws_stream.split();
+        let mut registrations = Registrations {
+            approval_key: self.approval_key.clone(),
+            cust_type: self.cust_type,
+            tr_code,
+            symbols: Vec::new(),
+        };
+        let recorder = self.recorder.clone();
+        let (tx, mut rx) = mpsc::channel::<ControlMessage>(32);
+
+        // 소비자가 느려도 읽기 루프가 멈추지 않도록 전달은 별도 task에서 처리합니다
+        let (record_tx, mut record_rx) = mpsc::channel::<String>(DELIVERY_BACKLOG);
+        let stopped = Arc::new(Notify::new());
+        let delivery_stopped = stopped.clone();
+        tokio::spawn(async move {
+            loop {
+                let record = tokio::select! {
+                    _ = delivery_stopped.notified() => break,
+                    record = record_rx.recv() => match record {
+                        Some(record) => record,
+                        None => break,
+                    },
+                };
+                let delivered = tokio::select! {
+                    _ = delivery_stopped.notified() => break,
+                    delivered = dispatch.dispatch(&record) => delivered,
+                };
+                if !delivered {
+                    break;
+                }
+            }
+            dispatch.close();
+        });
+
+        tokio::spawn(async move {
+            'read: loop {
+                tokio::select! {
+                    Some(control) = rx.recv() => {
+                        if registrations.apply(control, &mut write).await {
+                            stopped.notify_one();
+                            break;
+                        }
+                    }
+                    message = read.next() => {
+                        let Some(message) = message else {
+                            break;
+                        };
+                        match message {
+                            Ok(Message::Text(text)) => {
+                                if text.starts_with('{') {
+                                    // PINGPONG은 그대로 돌려보내야 연결이 유지됩니다
+                                    if text.contains("PINGPONG") {
+                                        let _ = write.send(Message::Text(text)).await;
+                                    } else {
+                                        println!("수신된 JSON: {}", text);
+                                    }
+                                } else if let Some(frame) = RealtimeFrame::parse(&text) {
+                                    for record in frame.records() {
+                                        if let Some(recorder) = &recorder
+                                            && let Err(error) = recorder.record(frame.tr_id, registrations.key_of(&record), &record)
+                                        {
+                                            println!("실시간 기록 실패: {}", error);
+                                        }
+                                        // 전달 대기열까지 가득 차면 여기서 기다리며, 기다리는 동안에도 등록/중지 요청은 받습니다
+                                        let permit = loop {
+                                            tokio::select! {
+                                                Some(control) = rx.recv() => {
+                                                    if registrations.apply(control, &mut write).await {
+                                                        stopped.notify_one();
+                                                        break 'read;
+                                                    }
+                                                }
+                                                permit = record_tx.reserve() => match permit {
+                                                    Ok(permit) => break permit,
+                                                    Err(_) => break 'read,
+                                                },
+                                            }
+                                        };
+                                        permit.send(record);
+                                    }
+                                } else {
+                                    println!("데이터 파싱 실패: {}", text);
+                                }
+                            }
+                            Ok(Message::Ping(payload)) => {
+                                let _ = write.send(Message::Pong(payload)).await;
+                            }
+                            Ok(Message::Close(_)) => break,
+                            Ok(other) => {
+                                println!("WebSocket에서 텍스트가 아닌 메시지 수신: {:?}", other);
+                            }
+                            Err(error) => {
+                                println!("WebSocket 에러: {:?}", error);
+                                break;
+                            }
+                        }
+                    }
+                }
+            }
+            for symbol in std::mem::take(&mut registrations.symbols) {
+                let _ = write.send(registrations.message("2", &symbol)).await;
+            }
+            let _ = write.close().await;
+            println!("WebSocket 연결 종료");
+        });
+
+        Ok(StreamController { tx })
+    }
+
+    /// 실시간 데이터 스트림 시작 (채널 반환)
+    ///
+    /// 버퍼(100건)가 가득 차면 새 데이터를 버립니다. 정책 지정은 [`Self::subscribe`]를 사용하세요.
+    pub async fn start_stream_channel<T: RealtimeData + Send + 'static>(
+        &self,
+        symbol: &str,
+        r#type: impl Into<RealtimeInfoType>,
+    ) -> Result<(mpsc::Receiver<T>, StreamController), RealtimeError> {
+        let (data_tx, data_rx) = mpsc::channel::<T>(100);
+        let controller = self
+            .start_stream(symbol, r#type, move |data: T| {
+                let _ = data_tx.try_send(data);
+            })
+            .await?;
+        Ok((data_rx, controller))
+    }
+
+    pub async fn start_raw(
+        &self,
+        symbol: &str,
+        r#type: impl Into<RealtimeInfoType>,
+        callback: impl FnMut(RawRealtimeData) + Send + 'static,
+    ) -> Result<StreamController, RealtimeError> {
+        self.start_stream(symbol, r#type, callback).await
+    }
+
+    pub async fn start_raw_channel(
+        &self,
+        symbol: &str,
+        r#type: impl Into<RealtimeInfoType>,
+    ) -> Result<(mpsc::Receiver<RawRealtimeData>, StreamController), RealtimeError> {
+        self.start_stream_channel::<RawRealtimeData>(symbol, r#type)
+            .await
+    }
+}
+
+/// 수신 task가 파싱 전 레코드를 넘기는 대상
+pub(crate) trait Dispatch: Send + 'static {
+    /// 레코드 1건 전달, `false`를 돌려주면 구독 해제
+    fn dispatch(&mut self, record: &str) -> impl Future<Output = bool> + Send;
+
+    /// 연결 종료 시 호출
+    fn close(&mut self) {}
+}
+
+struct CallbackDispatch<T, F> {
+    callback: F,
+    _data: PhantomData<fn() -> T>,
+}
+
+impl<T: RealtimeData + Send + 'static, F: FnMut(T) + Send + 'static> Dispatch
+    for CallbackDispatch<T, F>
+{
+    async fn dispatch(&mut self, record: &str) -> bool {
+        match T::from_delimited_string(record) {
+            Some(data) => (self.callback)(data),
+            None => println!("데이터 파싱 실패: {}", record),
+        }
+        true
+    }
+}
+
+/// 실시간 수신 프레임 (`암호화여부|TR_ID|데이터건수|데이터`)
+pub(crate) struct RealtimeFrame<'a> {
+    pub tr_id: &'a str,
+    pub count: usize,
+    pub payload: &'a str,
+}
+
+impl<'a> RealtimeFrame<'a> {
+    pub fn parse(text: &'a str) -> Option<Self> {
+        let mut parts = text.splitn(4, '|');
+        let _encrypted = parts.next()?;
+        let tr_id = parts.next()?;
+        let count = parts.next()?.parse().unwrap_or(1);
+        let payload = parts.next()?;
+        Some(Self {
+            tr_id,
+            count,
+            payload,
+        })
+    }
+
+    /// 한 프레임에 여러 건이 묶여 오면 필드 수를 건수로 나눠 건별로 분리
+    pub fn records(&self) -> Vec<String> {
+        if self.count <= 1 {
+            return vec![self.payload.to_string()];
+        }
+        let fields: Vec<&str> = self.payload.split('^').collect();
+        if !fields.len().is_multiple_of(self.count) {
+            return vec![self.payload.to_string()];
+        }
+        fields
+            .chunks(fields.len() / self.count)
+            .map(|chunk| chunk.join("^"))
+            .collect()
+    }
+}
+
+/// 연결 하나에 등록된 구독 종목
+struct Registrations {
+    approval_key: String,
+    cust_type: CustType,
+    tr_code: &'static str,
+    symbols: Vec<String>,
+}
+
+impl Registrations {
+    fn message(&self, tr_type: &str, symbol: &str) -> Message {
+        let message = json!({
+            "header": {
+                "approval_key": self.approval_key,
+                "custtype": self.cust_type,    // P: 개인, B: 법인
+                "tr_type": tr_type,            // 1: 등록, 2: 해제
+                "content-type": "utf-8"
+            },
+            "body": {
+                "input": {
+                    "tr_id": self.tr_code,
+                    "tr_key": symbol
+                }
+            }
+        });
+        Message::Text(message.to_string().into())
+    }
+
+    /// 레코드를 기록할 종목 (첫 필드가 등록 종목이 아니면 빈 문자열)
+    ///
+    /// 한 연결에 여러 종목이 등록되어 있으면 어느 종목의 데이터인지 추측하지 않습니다.
+    fn key_of(&self, record: &str) -> &str {
+        let first = record.split('^').next().unwrap_or_default();
+        self.symbols
+            .iter()
+            .find(|symbol| *symbol == first)
+            .map_or("", String::as_str)
+    }
+
+    async fn register<S>(&mut self, symbol: String, write: &mut S) -> Result<(), RealtimeError>
+    where
+        S: Sink<Message> + Unpin,
+        S::Error: fmt::Display,
+    {
+        if self.symbols.contains(&symbol) {
+            return Ok(());
+        }
+        if self.symbols.len() >= REALTIME_REGISTRATION_LIMIT {
+            return Err(RealtimeError::registration_limit());
+        }
+        write
+            .send(self.message("1", &symbol))
+            .await
+            .map_err(|e| RealtimeError::MessageError(e.to_string()))?;
+        self.symbols.push(symbol);
+        Ok(())
+    }
+
+    async fn unregister<S>(&mut self, symbol: String, write: &mut S) -> Result<(), RealtimeError>
+    where
+        S: Sink<Message> + Unpin,
+        S::Error: fmt::Display,
+    {
+        let Some(index) = self
+            .symbols
+            .iter()
+            .position(|registered| *registered == symbol)
+        else {
+            return Ok(());
+        };
+        write
+            .send(self.message("2", &symbol))
+            .await
+            .map_err(|e| RealtimeError::MessageError(e.to_string()))?;
+        self.symbols.remove(index);
+        Ok(())
+    }
+
+    /// 제어 메시지 처리, 중지 요청이면 `true`
+    async fn apply<S>(&mut self, control: ControlMessage, write: &mut S) -> bool
+    where
+        S: Sink<Message> + Unpin,
+        S::Error: fmt::Display,
+    {
+        match control {
+            ControlMessage::Stop => return true,
+            ControlMessage::Register { symbol, reply } => {
+                let _ = reply.send(self.register(symbol, write).await);
+            }
+            ControlMessage::Unregister { symbol, reply } => {
+                let _ = reply.send(self.unregister(symbol, write).await);
+            }
+        }
+        false
+    }
+}
+
+type ControlReply = oneshot::Sender<Result<(), RealtimeError>>;
+
+/// 스트림 제어 메시지
+pub(crate) enum ControlMessage {
+    Stop,
+    /// 같은 연결에 종목 등록 (tr_type 1)
+    Register {
+        symbol: String,
+        reply: ControlReply,
+    },
+    /// 같은 연결에서 종목 해제 (tr_type 2)
+    Unregister {
+        symbol: String,
+        reply: ControlReply,
+    },
+}
+
+/// 스트림 컨트롤러
+#[derive(Clone)]
+pub struct StreamController {
+    pub(crate) tx: mpsc::Sender<ControlMessage>,
+}
+
+impl StreamController {
+    /// 구독 해제 후 스트림 중지
+    pub async fn stop(&self) -> Result<(), RealtimeError> {
+        self.tx
+            .send(ControlMessage::Stop)
+            .await
+            .map_err(|e| RealtimeError::MessageError(e.to_string()))
+    }
+
+    /// 연결에 종목 등록 ([`REALTIME_REGISTRATION_LIMIT`]건을 넘으면 오류)
+    pub(crate) async fn register(&self, symbol: &str) -> Result<(), RealtimeError> {
+        let symbol = symbol.to_string();
+        self.request(|reply| ControlMessage::Register { symbol, reply })
+            .await
+    }
+
+    /// 연결은 유지한 채 종목 등록 해제
+    pub(crate) async fn unregister(&self, symbol: &str) -> Result<(), RealtimeError> {
+        let symbol = symbol.to_string();
+        self.request(|reply| ControlMessage::Unregister { symbol, reply })
+            .await
+    }
+
+    async fn request(
+        &self,
+        control: impl FnOnce(ControlReply) -> ControlMessage,
+    ) -> Result<(), RealtimeError> {
+        let (reply, response) = oneshot::channel();
+        self.tx
+            .send(control(reply))
+            .await
+            .map_err(|e| RealtimeError::MessageError(e.to_string()))?;
+        response.await.map_err(|_| {
+            RealtimeError::ConnectionError("웹소켓 연결이 종료되었습니다".to_string())
+        })?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::sink;
+
+    fn registrations() -> Registrations {
+        Registrations {
+            approval_key: "key".to_string(),
+            cust_type: CustType::P,
+            tr_code: "H0STCNT0",
+            symbols: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn registration_limit_is_enforced_per_connection() {
+        let mut registrations = registrations();
+        let mut write = sink::drain();
+        for index in 0..REALTIME_REGISTRATION_LIMIT {
+            let symbol = format!("{index:06}");
+            assert!(registrations.register(symbol, &mut write).await.is_ok());
+        }
+        // 이미 등록된 종목은 한도와 관계없이 성공
+        assert!(
+            registrations
+                .register("000000".to_string(), &mut write)
+                .await
+                .is_ok()
+        );
+        let error = registrations
+            .register("005930".to_string(), &mut write)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, RealtimeError::LimitError(_)));
+
+        registrations
+            .unregister("000000".to_string(), &mut write)
+            .await
+            .unwrap();
+        assert!(
+            registrations
+                .register("005930".to_string(), &mut write)
+                .await
+                .is_ok()
+        );
+        assert_eq!(registrations.symbols.len(), REALTIME_REGISTRATION_LIMIT);
+    }
+
+    #[tokio::test]
+    async fn records_are_keyed_by_registered_symbol() {
+        let mut registrations = registrations();
+        let mut write = sink::drain();
+        for symbol in ["005930", "000660"] {
+            registrations
+                .register(symbol.to_string(), &mut write)
+                .await
+                .unwrap();
+        }
+        assert_eq!(registrations.key_of("000660^093000^201000"), "000660");
+        assert_eq!(registrations.key_of("HTSID^0000000001"), "");
+    }
+}
@@ -1,43 +1,14 @@
-use crate::types::CustType;
-use crate::utils::http_client;
-#[cfg(feature = "ex")]
-use dotenv::dotenv;
-use futures_util::{SinkExt, stream::StreamExt};
-use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
-use serde::Deserialize;
-use serde_json::json;
-use std::env;
-use std::error::Error;
-use std::fmt;
-use tokio::sync::mpsc;
-use tokio_tungstenite::{connect_async, tungstenite::protocol::Message};
+use crate::websocket::{RawRealtimeData, RealtimeClient, RealtimeError};
 
-pub struct DomesticRealtimeClient {
-    approval_key: String,
-    cust_type: CustType,
-}
-
-#[derive(Deserialize, Debug)]
-struct TokenResponse {
-    approval_key: String,
-}
+pub mod models;
 
-pub trait RealtimeData: Sized {
-    fn from_delimited_string(text: &str) -> Option<Self>;
-}
-
-#[derive(Debug, Clone)]
-pub struct RawDomesticRealtimeData {
-    pub payload: String,
-}
+pub use crate::websocket::{RealtimeData, StreamController};
 
-impl RealtimeData for RawDomesticRealtimeData {
-    fn from_delimited_string(text: &str) -> Option<Self> {
-        Some(Self {
-            payload: text.to_string(),
-        })
-    }
-}
+/// 국내 실시간 데이터 클라이언트 (해외와 같은 [`RealtimeClient`] 사용)
+pub type DomesticRealtimeClient = RealtimeClient;
+/// 국내 실시간 데이터 관련 오류
+pub type DomesticRealtimeError = RealtimeError;
+pub type RawDomesticRealtimeData = RawRealtimeData;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum DomesticRealtimeInfoType {
@@ -198,190 +169,3 @@ impl DomesticRealtimeInfoType {
         }
     }
 }
-
-impl DomesticRealtimeClient {
-    pub async fn new(
-        app_key: String,
-        app_secret: String,
-        cust_type: CustType,
-    ) -> Result<Self, Box<dyn Error>> {
-        let url = "https://openapi.koreainvestment.com:9443/oauth2/Approval";
-        let body = json!({
-            "grant_type": "client_credentials",
-            "appkey": app_key,
-            "secretkey": app_secret
-        });
-
-        let mut headers = HeaderMap::new();
-        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-
-        let response = http_client()
-            .post(url)
-            .headers(headers)
-            .json(&body)
-            .send()
-            .await?;
-        let approval_response: TokenResponse = response.json().await?;
-        Ok(Self {
-            approval_key: approval_response.approval_key,
-            cust_type,
-        })
-    }
-
-    pub async fn from_env(cust_type: CustType) -> Result<Self, DomesticRealtimeError> {
-        #[cfg(feature = "ex")]
-        dotenv().ok();
-
-        let app_key = env::var("PUB_KEY").map_err(|_| {
-            DomesticRealtimeError::EnvError("APP_KEY not set in .env file".to_string())
-        })?;
-        let app_secret = env::var("SCREST_KEY").map_err(|_| {
-            DomesticRealtimeError::EnvError("APP_SECRET not set in .env file".to_string())
-        })?;
-        Self::new(app_key, app_secret, cust_type)
-            .await
-            .map_err(|error| DomesticRealtimeError::AuthError(error.to_string()))
-    }
-
-    async fn start_stream<T: RealtimeData + Send + 'static>(
-        &self,
-        symbol: &str,
-        r#type: DomesticRealtimeInfoType,
-        mut callback: impl FnMut(T) + Send + 'static,
-    ) -> Result<StreamController, DomesticRealtimeError> {
-        let tr_code = r#type.get_tr_code();
-        let url = format!("ws://ops.koreainvestment.com:21000/tryitout/{tr_code}");
-        let (ws_stream, _) = connect_async(url)
-            .await
-            .map_err(|e| DomesticRealtimeError::ConnectionError(e.to_string()))?;
-
-        let (mut write, mut read) = ws_stream.split();
-        let request_data = json!({
-            "header": {
-                "approval_key": self.approval_key,
-                "custtype": self.cust_type,
-                "tr_type": "1",
-                "content-type": "utf-8"
-            },
-            "body": {
-                "input": {
-                    "tr_id": tr_code,
-                    "tr_key": symbol
-                }
-            }
-        });
-
-        write
-            .send(Message::Text(request_data.to_string().into()))
-            .await
-            .map_err(|e| DomesticRealtimeError::MessageError(e.to_string()))?;
-
-        let (tx, mut rx) = mpsc::channel::<ControlMessage>(32);
-        tokio::spawn(async move {
-            while let Some(message) = read.next().await {
-                if let Ok(ControlMessage::Stop) = rx.try_recv() {
-                    break;
-                }
-
-                match message {
-                    Ok(Message::Text(text)) => {
-                        if text.starts_with('{') {
-                            println!("수신된 JSON: {}", text);
-                        } else if let Some(data) = T::from_delimited_string(&text) {
-                            callback(data);
-                        } else {
-                            println!("데이터 파싱 실패: {}", text);
-                        }
-                    }
-                    Ok(other) => {
-                        println!("WebSocket에서 텍스트가 아닌 메시지 수신: {:?}", other);
-                    }
-                    Err(error) => {
-                        println!("WebSocket 에러: {:?}", error);
-                        break;
-                    }
-                }
-            }
-            println!("WebSocket 연결 종료");
-        });
-
-        Ok(StreamController { tx })
-    }
-
-    pub async fn start_stream_channel<T: RealtimeData + Send + 'static>(
-        &self,
-        symbol: &str,
-        r#type: DomesticRealtimeInfoType,
-    ) -> Result<(mpsc::Receiver<T>, StreamController), DomesticRealtimeError> {
-        let (data_tx, data_rx) = mpsc::channel::<T>(100);
-        let controller = self
-            .start_stream(symbol, r#type, move |data: T| {
-                let _ = data_tx.try_send(data);
-            })
-            .await?;
-        Ok((data_rx, controller))
-    }
-
-    pub async fn start_raw(
-        &self,
-        symbol: &str,
-        r#type: DomesticRealtimeInfoType,
-        callback: impl FnMut(RawDomesticRealtimeData) + Send + 'static,
-    ) -> Result<StreamController, DomesticRealtimeError> {
-        self.start_stream(symbol, r#type, callback).await
-    }
-
-    pub async fn start_raw_channel(
-        &self,
-        symbol: &str,
-        r#type: DomesticRealtimeInfoType,
-    ) -> Result<(mpsc::Receiver<RawDomesticRealtimeData>, StreamController), DomesticRealtimeError>
-    {
-        self.start_stream_channel::<RawDomesticRealtimeData>(symbol, r#type)
-            .await
-    }
-}
-
-enum ControlMessage {
-    Stop,
-}
-
-pub struct StreamController {
-    tx: mpsc::Sender<ControlMessage>,
-}
-
-impl StreamController {
-    pub async fn stop(&self) -> Result<(), DomesticRealtimeError> {
-        self.tx
-            .send(ControlMessage::Stop)
-            .await
-            .map_err(|e| DomesticRealtimeError::MessageError(e.to_string()))
-    }
-}
-
-#[derive(Debug)]
-pub enum DomesticRealtimeError {
-    ConnectionError(String),
-    AuthError(String),
-    MessageError(String),
-    EnvError(String),
-}
-
-impl Error for DomesticRealtimeError {}
-
-impl fmt::Display for DomesticRealtimeError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            Self::ConnectionError(msg) => write!(f, "연결 오류: {}", msg),
-            Self::AuthError(msg) => write!(f, "인증 오류: {}", msg),
-            Self::MessageError(msg) => write!(f, "메시지 오류: {}", msg),
-            Self::EnvError(msg) => write!(f, "환경 변수 오류: {}", msg),
-        }
-    }
-}
-
-impl From<reqwest::Error> for DomesticRealtimeError {
-    fn from(error: reqwest::Error) -> Self {
-        Self::ConnectionError(error.to_string())
-    }
-}
@@ -0,0 +1,1293 @@
+use super::RealtimeData;
+use serde::{Deserialize, Serialize};
+
+// 국내 실시간 모델
+//
+// 지수선물/상품선물/KRX야간선물은 체결 필드 배치가 같아 `IndexFuturesTradeData`를,
+// 지수옵션/KRX야간옵션은 `IndexOptionTradeData`를 함께 사용합니다.
+// 지수·상품·야간 선물옵션 호가는 5단계라 `FutureOptionQuoteData` 하나로 파싱하고,
+// 주식선물 호가는 10단계라 `StockFuturesQuoteData`를 따로 둡니다.
+// 주식 호가(KRX/통합/NXT)는 10단계 `StockQuoteData`를, 주식 체결은 `StockTradeData`를 함께 사용합니다.
+
+//1) 지수선물 실시간체결가 (H0IFCNT0)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexFuturesTradeData {
+    /// 선물단축종목코드
+    pub futs_shrn_iscd: String,
+    /// 영업시간 (HHMMSS)
+    pub bsop_hour: String,
+    /// 선물전일대비
+    pub futs_prdy_vrss: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 선물전일대비율
+    pub futs_prdy_ctrt: String,
+    /// 선물현재가
+    pub futs_prpr: String,
+    /// 선물시가
+    pub futs_oprc: String,
+    /// 선물최고가
+    pub futs_hgpr: String,
+    /// 선물최저가
+    pub futs_lwpr: String,
+    /// 최종거래량
+    pub last_cnqn: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+    /// HTS이론가
+    pub hts_thpr: String,
+    /// 시장베이시스
+    pub mrkt_basis: String,
+    /// 괴리율
+    pub dprt: String,
+    /// 근월물약정가
+    pub nmsc_fctn_stpl_prc: String,
+    /// 원월물약정가
+    pub fmsc_fctn_stpl_prc: String,
+    /// 스프레드
+    pub spead_prc: String,
+    /// HTS미결제약정수량
+    pub hts_otst_stpl_qty: String,
+    /// 미결제약정수량증감
+    pub otst_stpl_qty_icdc: String,
+    /// 시가시간
+    pub oprc_hour: String,
+    /// 시가대비현재가부호
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비지수현재가
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가시간
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비지수현재가
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가시간
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비지수현재가
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수비율
+    pub shnu_rate: String,
+    /// 체결강도
+    pub cttr: String,
+    /// 괴리도
+    pub esdg: String,
+    /// 미결제약정직전수량증감
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론베이시스
+    pub thpr_basis: String,
+    /// 선물매도호가1
+    pub futs_askp1: String,
+    /// 선물매수호가1
+    pub futs_bidp1: String,
+    /// 매도호가잔량1
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    pub bidp_rsqn1: String,
+    /// 매도체결건수
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    pub ntby_cntg_csnu: String,
+    /// 총매도수량
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    pub shnu_cntg_smtn: String,
+    /// 총매도호가잔량
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    pub total_bidp_rsqn: String,
+    /// 전일거래량대비등락율
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 협의대량거래량
+    pub dscs_bltr_acml_qty: String,
+    /// 실시간상한가
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    pub dynm_prc_limt_yn: String,
+}
+
+impl RealtimeData for IndexFuturesTradeData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 50 {
+            return None;
+        }
+
+        Some(Self {
+            futs_shrn_iscd: fields[0].to_string(),
+            bsop_hour: fields[1].to_string(),
+            futs_prdy_vrss: fields[2].to_string(),
+            prdy_vrss_sign: fields[3].to_string(),
+            futs_prdy_ctrt: fields[4].to_string(),
+            futs_prpr: fields[5].to_string(),
+            futs_oprc: fields[6].to_string(),
+            futs_hgpr: fields[7].to_string(),
+            futs_lwpr: fields[8].to_string(),
+            last_cnqn: fields[9].to_string(),
+            acml_vol: fields[10].to_string(),
+            acml_tr_pbmn: fields[11].to_string(),
+            hts_thpr: fields[12].to_string(),
+            mrkt_basis: fields[13].to_string(),
+            dprt: fields[14].to_string(),
+            nmsc_fctn_stpl_prc: fields[15].to_string(),
+            fmsc_fctn_stpl_prc: fields[16].to_string(),
+            spead_prc: fields[17].to_string(),
+            hts_otst_stpl_qty: fields[18].to_string(),
+            otst_stpl_qty_icdc: fields[19].to_string(),
+            oprc_hour: fields[20].to_string(),
+            oprc_vrss_prpr_sign: fields[21].to_string(),
+            oprc_vrss_nmix_prpr: fields[22].to_string(),
+            hgpr_hour: fields[23].to_string(),
+            hgpr_vrss_prpr_sign: fields[24].to_string(),
+            hgpr_vrss_nmix_prpr: fields[25].to_string(),
+            lwpr_hour: fields[26].to_string(),
+            lwpr_vrss_prpr_sign: fields[27].to_string(),
+            lwpr_vrss_nmix_prpr: fields[28].to_string(),
+            shnu_rate: fields[29].to_string(),
+            cttr: fields[30].to_string(),
+            esdg: fields[31].to_string(),
+            otst_stpl_rgbf_qty_icdc: fields[32].to_string(),
+            thpr_basis: fields[33].to_string(),
+            futs_askp1: fields[34].to_string(),
+            futs_bidp1: fields[35].to_string(),
+            askp_rsqn1: fields[36].to_string(),
+            bidp_rsqn1: fields[37].to_string(),
+            seln_cntg_csnu: fields[38].to_string(),
+            shnu_cntg_csnu: fields[39].to_string(),
+            ntby_cntg_csnu: fields[40].to_string(),
+            seln_cntg_smtn: fields[41].to_string(),
+            shnu_cntg_smtn: fields[42].to_string(),
+            total_askp_rsqn: fields[43].to_string(),
+            total_bidp_rsqn: fields[44].to_string(),
+            prdy_vol_vrss_acml_vol_rate: fields[45].to_string(),
+            dscs_bltr_acml_qty: fields[46].to_string(),
+            dynm_mxpr: fields[47].to_string(),
+            dynm_llam: fields[48].to_string(),
+            dynm_prc_limt_yn: fields[49].to_string(),
+        })
+    }
+}
+
+/// 상품선물 실시간체결가 (H0CFCNT0)
+pub type CommodityFuturesTradeData = IndexFuturesTradeData;
+/// KRX야간선물 실시간체결가 (H0MFCNT0)
+pub type KrxNightFuturesTradeData = IndexFuturesTradeData;
+
+//2) 주식선물 실시간체결가 (H0ZFCNT0)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockFuturesTradeData {
+    /// 선물단축종목코드
+    pub futs_shrn_iscd: String,
+    /// 영업시간 (HHMMSS)
+    pub bsop_hour: String,
+    /// 현재가
+    pub stck_prpr: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 선물전일대비율
+    pub futs_prdy_ctrt: String,
+    /// 시가
+    pub stck_oprc: String,
+    /// 최고가
+    pub stck_hgpr: String,
+    /// 최저가
+    pub stck_lwpr: String,
+    /// 최종거래량
+    pub last_cnqn: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+    /// HTS이론가
+    pub hts_thpr: String,
+    /// 시장베이시스
+    pub mrkt_basis: String,
+    /// 괴리율
+    pub dprt: String,
+    /// 근월물약정가
+    pub nmsc_fctn_stpl_prc: String,
+    /// 원월물약정가
+    pub fmsc_fctn_stpl_prc: String,
+    /// 스프레드
+    pub spead_prc: String,
+    /// HTS미결제약정수량
+    pub hts_otst_stpl_qty: String,
+    /// 미결제약정수량증감
+    pub otst_stpl_qty_icdc: String,
+    /// 시가시간
+    pub oprc_hour: String,
+    /// 시가대비현재가부호
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비현재가
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비현재가
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비현재가
+    pub lwpr_vrss_prpr: String,
+    /// 매수비율
+    pub shnu_rate: String,
+    /// 체결강도
+    pub cttr: String,
+    /// 괴리도
+    pub esdg: String,
+    /// 미결제약정직전수량증감
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론베이시스
+    pub thpr_basis: String,
+    /// 매도호가1
+    pub askp1: String,
+    /// 매수호가1
+    pub bidp1: String,
+    /// 매도호가잔량1
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    pub bidp_rsqn1: String,
+    /// 매도체결건수
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    pub ntby_cntg_csnu: String,
+    /// 총매도수량
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    pub shnu_cntg_smtn: String,
+    /// 총매도호가잔량
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    pub total_bidp_rsqn: String,
+    /// 전일거래량대비등락율
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 실시간상한가
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    pub dynm_prc_limt_yn: String,
+}
+
+impl RealtimeData for StockFuturesTradeData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 49 {
+            return None;
+        }
+
+        Some(Self {
+            futs_shrn_iscd: fields[0].to_string(),
+            bsop_hour: fields[1].to_string(),
+            stck_prpr: fields[2].to_string(),
+            prdy_vrss_sign: fields[3].to_string(),
+            prdy_vrss: fields[4].to_string(),
+            futs_prdy_ctrt: fields[5].to_string(),
+            stck_oprc: fields[6].to_string(),
+            stck_hgpr: fields[7].to_string(),
+            stck_lwpr: fields[8].to_string(),
+            last_cnqn: fields[9].to_string(),
+            acml_vol: fields[10].to_string(),
+            acml_tr_pbmn: fields[11].to_string(),
+            hts_thpr: fields[12].to_string(),
+            mrkt_basis: fields[13].to_string(),
+            dprt: fields[14].to_string(),
+            nmsc_fctn_stpl_prc: fields[15].to_string(),
+            fmsc_fctn_stpl_prc: fields[16].to_string(),
+            spead_prc: fields[17].to_string(),
+            hts_otst_stpl_qty: fields[18].to_string(),
+            otst_stpl_qty_icdc: fields[19].to_string(),
+            oprc_hour: fields[20].to_string(),
+            oprc_vrss_prpr_sign: fields[21].to_string(),
+            oprc_vrss_prpr: fields[22].to_string(),
+            hgpr_hour: fields[23].to_string(),
+            hgpr_vrss_prpr_sign: fields[24].to_string(),
+            hgpr_vrss_prpr: fields[25].to_string(),
+            lwpr_hour: fields[26].to_string(),
+            lwpr_vrss_prpr_sign: fields[27].to_string(),
+            lwpr_vrss_prpr: fields[28].to_string(),
+            shnu_rate: fields[29].to_string(),
+            cttr: fields[30].to_string(),
+            esdg: fields[31].to_string(),
+            otst_stpl_rgbf_qty_icdc: fields[32].to_string(),
+            thpr_basis: fields[33].to_string(),
+            askp1: fields[34].to_string(),
+            bidp1: fields[35].to_string(),
+            askp_rsqn1: fields[36].to_string(),
+            bidp_rsqn1: fields[37].to_string(),
+            seln_cntg_csnu: fields[38].to_string(),
+            shnu_cntg_csnu: fields[39].to_string(),
+            ntby_cntg_csnu: fields[40].to_string(),
+            seln_cntg_smtn: fields[41].to_string(),
+            shnu_cntg_smtn: fields[42].to_string(),
+            total_askp_rsqn: fields[43].to_string(),
+            total_bidp_rsqn: fields[44].to_string(),
+            prdy_vol_vrss_acml_vol_rate: fields[45].to_string(),
+            dynm_mxpr: fields[46].to_string(),
+            dynm_llam: fields[47].to_string(),
+            dynm_prc_limt_yn: fields[48].to_string(),
+        })
+    }
+}
+
+//3) 지수옵션 실시간체결가 (H0IOCNT0)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IndexOptionTradeData {
+    /// 옵션단축종목코드
+    pub optn_shrn_iscd: String,
+    /// 영업시간 (HHMMSS)
+    pub bsop_hour: String,
+    /// 옵션현재가
+    pub optn_prpr: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 옵션전일대비
+    pub optn_prdy_vrss: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// 옵션시가
+    pub optn_oprc: String,
+    /// 옵션최고가
+    pub optn_hgpr: String,
+    /// 옵션최저가
+    pub optn_lwpr: String,
+    /// 최종거래량
+    pub last_cnqn: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+    /// HTS이론가
+    pub hts_thpr: String,
+    /// HTS미결제약정수량
+    pub hts_otst_stpl_qty: String,
+    /// 미결제약정수량증감
+    pub otst_stpl_qty_icdc: String,
+    /// 시가시간
+    pub oprc_hour: String,
+    /// 시가대비현재가부호
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비지수현재가
+    pub oprc_vrss_nmix_prpr: String,
+    /// 최고가시간
+    pub hgpr_hour: String,
+    /// 최고가대비현재가부호
+    pub hgpr_vrss_prpr_sign: String,
+    /// 최고가대비지수현재가
+    pub hgpr_vrss_nmix_prpr: String,
+    /// 최저가시간
+    pub lwpr_hour: String,
+    /// 최저가대비현재가부호
+    pub lwpr_vrss_prpr_sign: String,
+    /// 최저가대비지수현재가
+    pub lwpr_vrss_nmix_prpr: String,
+    /// 매수비율
+    pub shnu_rate: String,
+    /// 프리미엄값
+    pub prmm_val: String,
+    /// 내재가치값
+    pub invl_val: String,
+    /// 시간가치값
+    pub tmvl_val: String,
+    /// 델타
+    pub delta: String,
+    /// 감마
+    pub gama: String,
+    /// 베가
+    pub vega: String,
+    /// 세타
+    pub theta: String,
+    /// 로우
+    pub rho: String,
+    /// HTS내재변동성
+    pub hts_ints_vltl: String,
+    /// 괴리도
+    pub esdg: String,
+    /// 미결제약정직전수량증감
+    pub otst_stpl_rgbf_qty_icdc: String,
+    /// 이론베이시스
+    pub thpr_basis: String,
+    /// 역사적변동성
+    pub unas_hist_vltl: String,
+    /// 체결강도
+    pub cttr: String,
+    /// 괴리율
+    pub dprt: String,
+    /// 시장베이시스
+    pub mrkt_basis: String,
+    /// 옵션매도호가1
+    pub optn_askp1: String,
+    /// 옵션매수호가1
+    pub optn_bidp1: String,
+    /// 매도호가잔량1
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    pub bidp_rsqn1: String,
+    /// 매도체결건수
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    pub ntby_cntg_csnu: String,
+    /// 총매도수량
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    pub shnu_cntg_smtn: String,
+    /// 총매도호가잔량
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    pub total_bidp_rsqn: String,
+    /// 전일거래량대비등락율
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 평균변동성
+    pub avrg_vltl: String,
+    /// 협의대량누적거래량
+    pub dscs_lrqn_vol: String,
+    /// 실시간상한가
+    pub dynm_mxpr: String,
+    /// 실시간하한가
+    pub dynm_llam: String,
+    /// 실시간가격제한구분
+    pub dynm_prc_limt_yn: String,
+}
+
+impl RealtimeData for IndexOptionTradeData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 58 {
+            return None;
+        }
+
+        Some(Self {
+            optn_shrn_iscd: fields[0].to_string(),
+            bsop_hour: fields[1].to_string(),
+            optn_prpr: fields[2].to_string(),
+            prdy_vrss_sign: fields[3].to_string(),
+            optn_prdy_vrss: fields[4].to_string(),
+            prdy_ctrt: fields[5].to_string(),
+            optn_oprc: fields[6].to_string(),
+            optn_hgpr: fields[7].to_string(),
+            optn_lwpr: fields[8].to_string(),
+            last_cnqn: fields[9].to_string(),
+            acml_vol: fields[10].to_string(),
+            acml_tr_pbmn: fields[11].to_string(),
+            hts_thpr: fields[12].to_string(),
+            hts_otst_stpl_qty: fields[13].to_string(),
+            otst_stpl_qty_icdc: fields[14].to_string(),
+            oprc_hour: fields[15].to_string(),
+            oprc_vrss_prpr_sign: fields[16].to_string(),
+            oprc_vrss_nmix_prpr: fields[17].to_string(),
+            hgpr_hour: fields[18].to_string(),
+            hgpr_vrss_prpr_sign: fields[19].to_string(),
+            hgpr_vrss_nmix_prpr: fields[20].to_string(),
+            lwpr_hour: fields[21].to_string(),
+            lwpr_vrss_prpr_sign: fields[22].to_string(),
+            lwpr_vrss_nmix_prpr: fields[23].to_string(),
+            shnu_rate: fields[24].to_string(),
+            prmm_val: fields[25].to_string(),
+            invl_val: fields[26].to_string(),
+            tmvl_val: fields[27].to_string(),
+            delta: fields[28].to_string(),
+            gama: fields[29].to_string(),
+            vega: fields[30].to_string(),
+            theta: fields[31].to_string(),
+            rho: fields[32].to_string(),
+            hts_ints_vltl: fields[33].to_string(),
+            esdg: fields[34].to_string(),
+            otst_stpl_rgbf_qty_icdc: fields[35].to_string(),
+            thpr_basis: fields[36].to_string(),
+            unas_hist_vltl: fields[37].to_string(),
+            cttr: fields[38].to_string(),
+            dprt: fields[39].to_string(),
+            mrkt_basis: fields[40].to_string(),
+            optn_askp1: fields[41].to_string(),
+            optn_bidp1: fields[42].to_string(),
+            askp_rsqn1: fields[43].to_string(),
+            bidp_rsqn1: fields[44].to_string(),
+            seln_cntg_csnu: fields[45].to_string(),
+            shnu_cntg_csnu: fields[46].to_string(),
+            ntby_cntg_csnu: fields[47].to_string(),
+            seln_cntg_smtn: fields[48].to_string(),
+            shnu_cntg_smtn: fields[49].to_string(),
+            total_askp_rsqn: fields[50].to_string(),
+            total_bidp_rsqn: fields[51].to_string(),
+            prdy_vol_vrss_acml_vol_rate: fields[52].to_string(),
+            avrg_vltl: fields[53].to_string(),
+            dscs_lrqn_vol: fields[54].to_string(),
+            dynm_mxpr: fields[55].to_string(),
+            dynm_llam: fields[56].to_string(),
+            dynm_prc_limt_yn: fields[57].to_string(),
+        })
+    }
+}
+
+/// KRX야간옵션 실시간체결가 (H0EUCNT0)
+pub type KrxNightOptionTradeData = IndexOptionTradeData;
+
+//4) 선물옵션 실시간호가 (5단계)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FutureOptionQuoteData {
+    /// 단축종목코드
+    pub shrn_iscd: String,
+    /// 영업시간 (HHMMSS)
+    pub bsop_hour: String,
+    /// 매도호가1
+    pub askp1: String,
+    /// 매도호가2
+    pub askp2: String,
+    /// 매도호가3
+    pub askp3: String,
+    /// 매도호가4
+    pub askp4: String,
+    /// 매도호가5
+    pub askp5: String,
+    /// 매수호가1
+    pub bidp1: String,
+    /// 매수호가2
+    pub bidp2: String,
+    /// 매수호가3
+    pub bidp3: String,
+    /// 매수호가4
+    pub bidp4: String,
+    /// 매수호가5
+    pub bidp5: String,
+    /// 매도호가건수1
+    pub askp_csnu1: String,
+    /// 매도호가건수2
+    pub askp_csnu2: String,
+    /// 매도호가건수3
+    pub askp_csnu3: String,
+    /// 매도호가건수4
+    pub askp_csnu4: String,
+    /// 매도호가건수5
+    pub askp_csnu5: String,
+    /// 매수호가건수1
+    pub bidp_csnu1: String,
+    /// 매수호가건수2
+    pub bidp_csnu2: String,
+    /// 매수호가건수3
+    pub bidp_csnu3: String,
+    /// 매수호가건수4
+    pub bidp_csnu4: String,
+    /// 매수호가건수5
+    pub bidp_csnu5: String,
+    /// 매도호가잔량1
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    pub askp_rsqn5: String,
+    /// 매수호가잔량1
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    pub bidp_rsqn5: String,
+    /// 총매도호가건수
+    pub total_askp_csnu: String,
+    /// 총매수호가건수
+    pub total_bidp_csnu: String,
+    /// 총매도호가잔량
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    pub total_bidp_rsqn: String,
+    /// 총매도호가잔량증감
+    pub total_askp_rsqn_icdc: String,
+    /// 총매수호가잔량증감
+    pub total_bidp_rsqn_icdc: String,
+}
+
+impl RealtimeData for FutureOptionQuoteData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 38 {
+            return None;
+        }
+
+        Some(Self {
+            shrn_iscd: fields[0].to_string(),
+            bsop_hour: fields[1].to_string(),
+            askp1: fields[2].to_string(),
+            askp2: fields[3].to_string(),
+            askp3: fields[4].to_string(),
+            askp4: fields[5].to_string(),
+            askp5: fields[6].to_string(),
+            bidp1: fields[7].to_string(),
+            bidp2: fields[8].to_string(),
+            bidp3: fields[9].to_string(),
+            bidp4: fields[10].to_string(),
+            bidp5: fields[11].to_string(),
+            askp_csnu1: fields[12].to_string(),
+            askp_csnu2: fields[13].to_string(),
+            askp_csnu3: fields[14].to_string(),
+            askp_csnu4: fields[15].to_string(),
+            askp_csnu5: fields[16].to_string(),
+            bidp_csnu1: fields[17].to_string(),
+            bidp_csnu2: fields[18].to_string(),
+            bidp_csnu3: fields[19].to_string(),
+            bidp_csnu4: fields[20].to_string(),
+            bidp_csnu5: fields[21].to_string(),
+            askp_rsqn1: fields[22].to_string(),
+            askp_rsqn2: fields[23].to_string(),
+            askp_rsqn3: fields[24].to_string(),
+            askp_rsqn4: fields[25].to_string(),
+            askp_rsqn5: fields[26].to_string(),
+            bidp_rsqn1: fields[27].to_string(),
+            bidp_rsqn2: fields[28].to_string(),
+            bidp_rsqn3: fields[29].to_string(),
+            bidp_rsqn4: fields[30].to_string(),
+            bidp_rsqn5: fields[31].to_string(),
+            total_askp_csnu: fields[32].to_string(),
+            total_bidp_csnu: fields[33].to_string(),
+            total_askp_rsqn: fields[34].to_string(),
+            total_bidp_rsqn: fields[35].to_string(),
+            total_askp_rsqn_icdc: fields[36].to_string(),
+            total_bidp_rsqn_icdc: fields[37].to_string(),
+        })
+    }
+}
+
+/// 지수선물 실시간호가 (H0IFASP0)
+pub type IndexFuturesQuoteData = FutureOptionQuoteData;
+/// 지수옵션 실시간호가 (H0IOASP0)
+pub type IndexOptionQuoteData = FutureOptionQuoteData;
+/// 상품선물 실시간호가 (H0CFASP0)
+pub type CommodityFuturesQuoteData = FutureOptionQuoteData;
+/// KRX야간선물 실시간호가 (H0MFASP0)
+pub type KrxNightFuturesQuoteData = FutureOptionQuoteData;
+/// KRX야간옵션 실시간호가 (H0EUASP0)
+pub type KrxNightOptionQuoteData = FutureOptionQuoteData;
+
+//4-1) 주식선물 실시간호가 (H0ZFASP0, 10단계)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockFuturesQuoteData {
+    /// 선물단축종목코드
+    pub futs_shrn_iscd: String,
+    /// 영업시간 (HHMMSS)
+    pub bsop_hour: String,
+    /// 매도호가1
+    pub askp1: String,
+    /// 매도호가2
+    pub askp2: String,
+    /// 매도호가3
+    pub askp3: String,
+    /// 매도호가4
+    pub askp4: String,
+    /// 매도호가5
+    pub askp5: String,
+    /// 매도호가6
+    pub askp6: String,
+    /// 매도호가7
+    pub askp7: String,
+    /// 매도호가8
+    pub askp8: String,
+    /// 매도호가9
+    pub askp9: String,
+    /// 매도호가10
+    pub askp10: String,
+    /// 매수호가1
+    pub bidp1: String,
+    /// 매수호가2
+    pub bidp2: String,
+    /// 매수호가3
+    pub bidp3: String,
+    /// 매수호가4
+    pub bidp4: String,
+    /// 매수호가5
+    pub bidp5: String,
+    /// 매수호가6
+    pub bidp6: String,
+    /// 매수호가7
+    pub bidp7: String,
+    /// 매수호가8
+    pub bidp8: String,
+    /// 매수호가9
+    pub bidp9: String,
+    /// 매수호가10
+    pub bidp10: String,
+    /// 매도호가건수1
+    pub askp_csnu1: String,
+    /// 매도호가건수2
+    pub askp_csnu2: String,
+    /// 매도호가건수3
+    pub askp_csnu3: String,
+    /// 매도호가건수4
+    pub askp_csnu4: String,
+    /// 매도호가건수5
+    pub askp_csnu5: String,
+    /// 매도호가건수6
+    pub askp_csnu6: String,
+    /// 매도호가건수7
+    pub askp_csnu7: String,
+    /// 매도호가건수8
+    pub askp_csnu8: String,
+    /// 매도호가건수9
+    pub askp_csnu9: String,
+    /// 매도호가건수10
+    pub askp_csnu10: String,
+    /// 매수호가건수1
+    pub bidp_csnu1: String,
+    /// 매수호가건수2
+    pub bidp_csnu2: String,
+    /// 매수호가건수3
+    pub bidp_csnu3: String,
+    /// 매수호가건수4
+    pub bidp_csnu4: String,
+    /// 매수호가건수5
+    pub bidp_csnu5: String,
+    /// 매수호가건수6
+    pub bidp_csnu6: String,
+    /// 매수호가건수7
+    pub bidp_csnu7: String,
+    /// 매수호가건수8
+    pub bidp_csnu8: String,
+    /// 매수호가건수9
+    pub bidp_csnu9: String,
+    /// 매수호가건수10
+    pub bidp_csnu10: String,
+    /// 매도호가잔량1
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    pub askp_rsqn5: String,
+    /// 매도호가잔량6
+    pub askp_rsqn6: String,
+    /// 매도호가잔량7
+    pub askp_rsqn7: String,
+    /// 매도호가잔량8
+    pub askp_rsqn8: String,
+    /// 매도호가잔량9
+    pub askp_rsqn9: String,
+    /// 매도호가잔량10
+    pub askp_rsqn10: String,
+    /// 매수호가잔량1
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    pub bidp_rsqn5: String,
+    /// 매수호가잔량6
+    pub bidp_rsqn6: String,
+    /// 매수호가잔량7
+    pub bidp_rsqn7: String,
+    /// 매수호가잔량8
+    pub bidp_rsqn8: String,
+    /// 매수호가잔량9
+    pub bidp_rsqn9: String,
+    /// 매수호가잔량10
+    pub bidp_rsqn10: String,
+    /// 총매도호가건수
+    pub total_askp_csnu: String,
+    /// 총매수호가건수
+    pub total_bidp_csnu: String,
+    /// 총매도호가잔량
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    pub total_bidp_rsqn: String,
+    /// 총매도호가잔량증감
+    pub total_askp_rsqn_icdc: String,
+    /// 총매수호가잔량증감
+    pub total_bidp_rsqn_icdc: String,
+}
+
+impl RealtimeData for StockFuturesQuoteData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 68 {
+            return None;
+        }
+
+        Some(Self {
+            futs_shrn_iscd: fields[0].to_string(),
+            bsop_hour: fields[1].to_string(),
+            askp1: fields[2].to_string(),
+            askp2: fields[3].to_string(),
+            askp3: fields[4].to_string(),
+            askp4: fields[5].to_string(),
+            askp5: fields[6].to_string(),
+            askp6: fields[7].to_string(),
+            askp7: fields[8].to_string(),
+            askp8: fields[9].to_string(),
+            askp9: fields[10].to_string(),
+            askp10: fields[11].to_string(),
+            bidp1: fields[12].to_string(),
+            bidp2: fields[13].to_string(),
+            bidp3: fields[14].to_string(),
+            bidp4: fields[15].to_string(),
+            bidp5: fields[16].to_string(),
+            bidp6: fields[17].to_string(),
+            bidp7: fields[18].to_string(),
+            bidp8: fields[19].to_string(),
+            bidp9: fields[20].to_string(),
+            bidp10: fields[21].to_string(),
+            askp_csnu1: fields[22].to_string(),
+            askp_csnu2: fields[23].to_string(),
+            askp_csnu3: fields[24].to_string(),
+            askp_csnu4: fields[25].to_string(),
+            askp_csnu5: fields[26].to_string(),
+            askp_csnu6: fields[27].to_string(),
+            askp_csnu7: fields[28].to_string(),
+            askp_csnu8: fields[29].to_string(),
+            askp_csnu9: fields[30].to_string(),
+            askp_csnu10: fields[31].to_string(),
+            bidp_csnu1: fields[32].to_string(),
+            bidp_csnu2: fields[33].to_string(),
+            bidp_csnu3: fields[34].to_string(),
+            bidp_csnu4: fields[35].to_string(),
+            bidp_csnu5: fields[36].to_string(),
+            bidp_csnu6: fields[37].to_string(),
+            bidp_csnu7: fields[38].to_string(),
+            bidp_csnu8: fields[39].to_string(),
+            bidp_csnu9: fields[40].to_string(),
+            bidp_csnu10: fields[41].to_string(),
+            askp_rsqn1: fields[42].to_string(),
+            askp_rsqn2: fields[43].to_string(),
+            askp_rsqn3: fields[44].to_string(),
+            askp_rsqn4: fields[45].to_string(),
+            askp_rsqn5: fields[46].to_string(),
+            askp_rsqn6: fields[47].to_string(),
+            askp_rsqn7: fields[48].to_string(),
+            askp_rsqn8: fields[49].to_string(),
+            askp_rsqn9: fields[50].to_string(),
+            askp_rsqn10: fields[51].to_string(),
+            bidp_rsqn1: fields[52].to_string(),
+            bidp_rsqn2: fields[53].to_string(),
+            bidp_rsqn3: fields[54].to_string(),
+            bidp_rsqn4: fields[55].to_string(),
+            bidp_rsqn5: fields[56].to_string(),
+            bidp_rsqn6: fields[57].to_string(),
+            bidp_rsqn7: fields[58].to_string(),
+            bidp_rsqn8: fields[59].to_string(),
+            bidp_rsqn9: fields[60].to_string(),
+            bidp_rsqn10: fields[61].to_string(),
+            total_askp_csnu: fields[62].to_string(),
+            total_bidp_csnu: fields[63].to_string(),
+            total_askp_rsqn: fields[64].to_string(),
+            total_bidp_rsqn: fields[65].to_string(),
+            total_askp_rsqn_icdc: fields[66].to_string(),
+            total_bidp_rsqn_icdc: fields[67].to_string(),
+        })
+    }
+}
+
+//5) 국내주식 실시간호가 (10단계)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockQuoteData {
+    /// 유가증권단축종목코드
+    pub mksc_shrn_iscd: String,
+    /// 영업시간 (HHMMSS)
+    pub bsop_hour: String,
+    /// 시간구분코드 (0: 장중, A: 장후예상, B: 장전예상, C: 9시이후 예상/VI, D: 시간외단일가 예상)
+    pub hour_cls_code: String,
+    /// 매도호가1
+    pub askp1: String,
+    /// 매도호가2
+    pub askp2: String,
+    /// 매도호가3
+    pub askp3: String,
+    /// 매도호가4
+    pub askp4: String,
+    /// 매도호가5
+    pub askp5: String,
+    /// 매도호가6
+    pub askp6: String,
+    /// 매도호가7
+    pub askp7: String,
+    /// 매도호가8
+    pub askp8: String,
+    /// 매도호가9
+    pub askp9: String,
+    /// 매도호가10
+    pub askp10: String,
+    /// 매수호가1
+    pub bidp1: String,
+    /// 매수호가2
+    pub bidp2: String,
+    /// 매수호가3
+    pub bidp3: String,
+    /// 매수호가4
+    pub bidp4: String,
+    /// 매수호가5
+    pub bidp5: String,
+    /// 매수호가6
+    pub bidp6: String,
+    /// 매수호가7
+    pub bidp7: String,
+    /// 매수호가8
+    pub bidp8: String,
+    /// 매수호가9
+    pub bidp9: String,
+    /// 매수호가10
+    pub bidp10: String,
+    /// 매도호가잔량1
+    pub askp_rsqn1: String,
+    /// 매도호가잔량2
+    pub askp_rsqn2: String,
+    /// 매도호가잔량3
+    pub askp_rsqn3: String,
+    /// 매도호가잔량4
+    pub askp_rsqn4: String,
+    /// 매도호가잔량5
+    pub askp_rsqn5: String,
+    /// 매도호가잔량6
+    pub askp_rsqn6: String,
+    /// 매도호가잔량7
+    pub askp_rsqn7: String,
+    /// 매도호가잔량8
+    pub askp_rsqn8: String,
+    /// 매도호가잔량9
+    pub askp_rsqn9: String,
+    /// 매도호가잔량10
+    pub askp_rsqn10: String,
+    /// 매수호가잔량1
+    pub bidp_rsqn1: String,
+    /// 매수호가잔량2
+    pub bidp_rsqn2: String,
+    /// 매수호가잔량3
+    pub bidp_rsqn3: String,
+    /// 매수호가잔량4
+    pub bidp_rsqn4: String,
+    /// 매수호가잔량5
+    pub bidp_rsqn5: String,
+    /// 매수호가잔량6
+    pub bidp_rsqn6: String,
+    /// 매수호가잔량7
+    pub bidp_rsqn7: String,
+    /// 매수호가잔량8
+    pub bidp_rsqn8: String,
+    /// 매수호가잔량9
+    pub bidp_rsqn9: String,
+    /// 매수호가잔량10
+    pub bidp_rsqn10: String,
+    /// 총매도호가잔량
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    pub total_bidp_rsqn: String,
+    /// 시간외총매도호가잔량
+    pub ovtm_total_askp_rsqn: String,
+    /// 시간외총매수호가잔량
+    pub ovtm_total_bidp_rsqn: String,
+    /// 예상체결가
+    pub antc_cnpr: String,
+    /// 예상체결량
+    pub antc_cnqn: String,
+    /// 예상거래량
+    pub antc_vol: String,
+    /// 예상체결대비
+    pub antc_cntg_vrss: String,
+    /// 예상체결대비부호
+    pub antc_cntg_vrss_sign: String,
+    /// 예상체결전일대비율
+    pub antc_cntg_prdy_ctrt: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 총매도호가잔량증감
+    pub total_askp_rsqn_icdc: String,
+    /// 총매수호가잔량증감
+    pub total_bidp_rsqn_icdc: String,
+    /// 시간외총매도호가증감
+    pub ovtm_total_askp_icdc: String,
+    /// 시간외총매수호가증감
+    pub ovtm_total_bidp_icdc: String,
+    /// 주식매매구분코드
+    pub stck_deal_cls_code: String,
+    /// KRX 중간가 (통합/NXT만 제공)
+    pub kmid_prc: String,
+    /// KRX 중간가잔량합계수량 (통합/NXT만 제공)
+    pub kmid_total_rsqn: String,
+    /// KRX 중간가 매수매도구분 (통합/NXT만 제공)
+    pub kmid_cls_code: String,
+    /// NXT 중간가 (통합/NXT만 제공)
+    pub nmid_prc: String,
+    /// NXT 중간가잔량합계수량 (통합/NXT만 제공)
+    pub nmid_total_rsqn: String,
+    /// NXT 중간가 매수매도구분 (통합/NXT만 제공)
+    pub nmid_cls_code: String,
+}
+
+impl RealtimeData for StockQuoteData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 59 {
+            return None;
+        }
+        // KRX 호가에는 중간가 필드가 없으므로 빈 값으로 채웁니다
+        let optional = |index: usize| fields.get(index).unwrap_or(&"").to_string();
+
+        Some(Self {
+            mksc_shrn_iscd: fields[0].to_string(),
+            bsop_hour: fields[1].to_string(),
+            hour_cls_code: fields[2].to_string(),
+            askp1: fields[3].to_string(),
+            askp2: fields[4].to_string(),
+            askp3: fields[5].to_string(),
+            askp4: fields[6].to_string(),
+            askp5: fields[7].to_string(),
+            askp6: fields[8].to_string(),
+            askp7: fields[9].to_string(),
+            askp8: fields[10].to_string(),
+            askp9: fields[11].to_string(),
+            askp10: fields[12].to_string(),
+            bidp1: fields[13].to_string(),
+            bidp2: fields[14].to_string(),
+            bidp3: fields[15].to_string(),
+            bidp4: fields[16].to_string(),
+            bidp5: fields[17].to_string(),
+            bidp6: fields[18].to_string(),
+            bidp7: fields[19].to_string(),
+            bidp8: fields[20].to_string(),
+            bidp9: fields[21].to_string(),
+            bidp10: fields[22].to_string(),
+            askp_rsqn1: fields[23].to_string(),
+            askp_rsqn2: fields[24].to_string(),
+            askp_rsqn3: fields[25].to_string(),
+            askp_rsqn4: fields[26].to_string(),
+            askp_rsqn5: fields[27].to_string(),
+            askp_rsqn6: fields[28].to_string(),
+            askp_rsqn7: fields[29].to_string(),
+            askp_rsqn8: fields[30].to_string(),
+            askp_rsqn9: fields[31].to_string(),
+            askp_rsqn10: fields[32].to_string(),
+            bidp_rsqn1: fields[33].to_string(),
+            bidp_rsqn2: fields[34].to_string(),
+            bidp_rsqn3: fields[35].to_string(),
+            bidp_rsqn4: fields[36].to_string(),
+            bidp_rsqn5: fields[37].to_string(),
+            bidp_rsqn6: fields[38].to_string(),
+            bidp_rsqn7: fields[39].to_string(),
+            bidp_rsqn8: fields[40].to_string(),
+            bidp_rsqn9: fields[41].to_string(),
+            bidp_rsqn10: fields[42].to_string(),
+            total_askp_rsqn: fields[43].to_string(),
+            total_bidp_rsqn: fields[44].to_string(),
+            ovtm_total_askp_rsqn: fields[45].to_string(),
+            ovtm_total_bidp_rsqn: fields[46].to_string(),
+            antc_cnpr: fields[47].to_string(),
+            antc_cnqn: fields[48].to_string(),
+            antc_vol: fields[49].to_string(),
+            antc_cntg_vrss: fields[50].to_string(),
+            antc_cntg_vrss_sign: fields[51].to_string(),
+            antc_cntg_prdy_ctrt: fields[52].to_string(),
+            acml_vol: fields[53].to_string(),
+            total_askp_rsqn_icdc: fields[54].to_string(),
+            total_bidp_rsqn_icdc: fields[55].to_string(),
+            ovtm_total_askp_icdc: fields[56].to_string(),
+            ovtm_total_bidp_icdc: fields[57].to_string(),
+            stck_deal_cls_code: fields[58].to_string(),
+            kmid_prc: optional(59),
+            kmid_total_rsqn: optional(60),
+            kmid_cls_code: optional(61),
+            nmid_prc: optional(62),
+            nmid_total_rsqn: optional(63),
+            nmid_cls_code: optional(64),
+        })
+    }
+}
+
+/// 국내주식 실시간호가 KRX (H0STASP0)
+pub type StockQuoteKrxData = StockQuoteData;
+/// 국내주식 실시간호가 통합 (H0UNASP0)
+pub type StockQuoteUnifiedData = StockQuoteData;
+/// 국내주식 실시간호가 NXT (H0NXASP0)
+pub type StockQuoteNxtData = StockQuoteData;
+
+//6) 국내주식 실시간체결가
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StockTradeData {
+    /// 유가증권단축종목코드
+    pub mksc_shrn_iscd: String,
+    /// 주식체결시간 (HHMMSS)
+    pub stck_cntg_hour: String,
+    /// 주식현재가
+    pub stck_prpr: String,
+    /// 전일대비부호
+    pub prdy_vrss_sign: String,
+    /// 전일대비
+    pub prdy_vrss: String,
+    /// 전일대비율
+    pub prdy_ctrt: String,
+    /// 가중평균주식가격
+    pub wghn_avrg_stck_prc: String,
+    /// 주식시가
+    pub stck_oprc: String,
+    /// 주식최고가
+    pub stck_hgpr: String,
+    /// 주식최저가
+    pub stck_lwpr: String,
+    /// 매도호가1
+    pub askp1: String,
+    /// 매수호가1
+    pub bidp1: String,
+    /// 체결거래량
+    pub cntg_vol: String,
+    /// 누적거래량
+    pub acml_vol: String,
+    /// 누적거래대금
+    pub acml_tr_pbmn: String,
+    /// 매도체결건수
+    pub seln_cntg_csnu: String,
+    /// 매수체결건수
+    pub shnu_cntg_csnu: String,
+    /// 순매수체결건수
+    pub ntby_cntg_csnu: String,
+    /// 체결강도
+    pub cttr: String,
+    /// 총매도수량
+    pub seln_cntg_smtn: String,
+    /// 총매수수량
+    pub shnu_cntg_smtn: String,
+    /// 체결구분 (1: 매수, 3: 장전, 5: 매도)
+    pub ccld_dvsn: String,
+    /// 매수비율
+    pub shnu_rate: String,
+    /// 전일거래량대비등락율
+    pub prdy_vol_vrss_acml_vol_rate: String,
+    /// 시가시간
+    pub oprc_hour: String,
+    /// 시가대비구분
+    pub oprc_vrss_prpr_sign: String,
+    /// 시가대비
+    pub oprc_vrss_prpr: String,
+    /// 최고가시간
+    pub hgpr_hour: String,
+    /// 고가대비구분
+    pub hgpr_vrss_prpr_sign: String,
+    /// 고가대비
+    pub hgpr_vrss_prpr: String,
+    /// 최저가시간
+    pub lwpr_hour: String,
+    /// 저가대비구분
+    pub lwpr_vrss_prpr_sign: String,
+    /// 저가대비
+    pub lwpr_vrss_prpr: String,
+    /// 영업일자 (YYYYMMDD)
+    pub bsop_date: String,
+    /// 신장운영구분코드
+    pub new_mkop_cls_code: String,
+    /// 거래정지여부
+    pub trht_yn: String,
+    /// 매도호가잔량1
+    pub askp_rsqn1: String,
+    /// 매수호가잔량1
+    pub bidp_rsqn1: String,
+    /// 총매도호가잔량
+    pub total_askp_rsqn: String,
+    /// 총매수호가잔량
+    pub total_bidp_rsqn: String,
+    /// 거래량회전율
+    pub vol_tnrt: String,
+    /// 전일동시간누적거래량
+    pub prdy_smns_hour_acml_vol: String,
+    /// 전일동시간누적거래량비율
+    pub prdy_smns_hour_acml_vol_rate: String,
+    /// 시간구분코드 (0: 장중, A: 장후예상, B: 장전예상, C: 9시이후 예상/VI, D: 시간외단일가 예상)
+    pub hour_cls_code: String,
+    /// 임의종료구분코드
+    pub mrkt_trtm_cls_code: String,
+    /// 정적VI발동기준가
+    pub vi_stnd_prc: String,
+}
+
+impl RealtimeData for StockTradeData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 46 {
+            return None;
+        }
+
+        Some(Self {
+            mksc_shrn_iscd: fields[0].to_string(),
+            stck_cntg_hour: fields[1].to_string(),
+            stck_prpr: fields[2].to_string(),
+            prdy_vrss_sign: fields[3].to_string(),
+            prdy_vrss: fields[4].to_string(),
+            prdy_ctrt: fields[5].to_string(),
+            wghn_avrg_stck_prc: fields[6].to_string(),
+            stck_oprc: fields[7].to_string(),
+            stck_hgpr: fields[8].to_string(),
+            stck_lwpr: fields[9].to_string(),
+            askp1: fields[10].to_string(),
+            bidp1: fields[11].to_string(),
+            cntg_vol: fields[12].to_string(),
+            acml_vol: fields[13].to_string(),
+            acml_tr_pbmn: fields[14].to_string(),
+            seln_cntg_csnu: fields[15].to_string(),
+            shnu_cntg_csnu: fields[16].to_string(),
+            ntby_cntg_csnu: fields[17].to_string(),
+            cttr: fields[18].to_string(),
+            seln_cntg_smtn: fields[19].to_string(),
+            shnu_cntg_smtn: fields[20].to_string(),
+            ccld_dvsn: fields[21].to_string(),
+            shnu_rate: fields[22].to_string(),
+            prdy_vol_vrss_acml_vol_rate: fields[23].to_string(),
+            oprc_hour: fields[24].to_string(),
+            oprc_vrss_prpr_sign: fields[25].to_string(),
+            oprc_vrss_prpr: fields[26].to_string(),
+            hgpr_hour: fields[27].to_string(),
+            hgpr_vrss_prpr_sign: fields[28].to_string(),
+            hgpr_vrss_prpr: fields[29].to_string(),
+            lwpr_hour: fields[30].to_string(),
+            lwpr_vrss_prpr_sign: fields[31].to_string(),
+            lwpr_vrss_prpr: fields[32].to_string(),
+            bsop_date: fields[33].to_string(),
+            new_mkop_cls_code: fields[34].to_string(),
+            trht_yn: fields[35].to_string(),
+            askp_rsqn1: fields[36].to_string(),
+            bidp_rsqn1: fields[37].to_string(),
+            total_askp_rsqn: fields[38].to_string(),
+            total_bidp_rsqn: fields[39].to_string(),
+            vol_tnrt: fields[40].to_string(),
+            prdy_smns_hour_acml_vol: fields[41].to_string(),
+            prdy_smns_hour_acml_vol_rate: fields[42].to_string(),
+            hour_cls_code: fields[43].to_string(),
+            mrkt_trtm_cls_code: fields[44].to_string(),
+            vi_stnd_prc: fields[45].to_string(),
+        })
+    }
+}
+
+/// 국내주식 실시간체결가 KRX (H0STCNT0)
+pub type StockTradeKrxData = StockTradeData;
+/// 국내주식 실시간체결가 통합 (H0UNCNT0)
+pub type StockTradeUnifiedData = StockTradeData;
+/// 국내주식 실시간체결가 NXT (H0NXCNT0)
+pub type StockTradeNxtData = StockTradeData;
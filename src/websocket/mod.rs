@@ -1,3 +1,74 @@
+pub mod client;
 pub mod domestic;
 pub mod oauth;
 pub mod overseas;
+pub mod record;
+pub mod stream;
+pub mod universe;
+
+pub use client::{
+    REALTIME_REGISTRATION_LIMIT, RealtimeClient, RealtimeError, StreamController,
+};
+pub use record::{
+    RealtimeRecorder, RealtimeReplay, RecordedFrame, ReplayMultiplier, ReplaySpeed, read_recording,
+};
+pub use stream::{OverflowPolicy, RealtimeStream, RealtimeSubscription, SubscriptionOptions};
+pub use universe::{RealtimeUniverse, UniverseChange};
+use domestic::DomesticRealtimeInfoType;
+use overseas::types::OverseasRealtimeInfoType;
+use serde::{Deserialize, Serialize};
+
+/// 국내/해외 실시간 모델 공통 파싱 trait
+pub trait RealtimeData: Sized {
+    /// 구분자(^)로 나뉜 문자열에서 구조체 생성
+    fn from_delimited_string(text: &str) -> Option<Self>;
+}
+
+/// 파싱하지 않은 실시간 데이터 원문
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RawRealtimeData {
+    pub payload: String,
+}
+
+impl RealtimeData for RawRealtimeData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        Some(Self {
+            payload: text.to_string(),
+        })
+    }
+}
+
+/// 국내/해외 실시간 TR 구분
+#[derive(Debug, Clone, PartialEq)]
+pub enum RealtimeInfoType {
+    Domestic(DomesticRealtimeInfoType),
+    Overseas(OverseasRealtimeInfoType),
+}
+
+impl RealtimeInfoType {
+    pub fn get_name(&self) -> &'static str {
+        match self {
+            Self::Domestic(info_type) => info_type.get_name(),
+            Self::Overseas(info_type) => info_type.get_name(),
+        }
+    }
+
+    pub fn get_tr_code(&self) -> &'static str {
+        match self {
+            Self::Domestic(info_type) => info_type.get_tr_code(),
+            Self::Overseas(info_type) => info_type.get_tr_code(),
+        }
+    }
+}
+
+impl From<DomesticRealtimeInfoType> for RealtimeInfoType {
+    fn from(info_type: DomesticRealtimeInfoType) -> Self {
+        Self::Domestic(info_type)
+    }
+}
+
+impl From<OverseasRealtimeInfoType> for RealtimeInfoType {
+    fn from(info_type: OverseasRealtimeInfoType) -> Self {
+        Self::Overseas(info_type)
+    }
+}
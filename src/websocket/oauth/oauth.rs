@@ -18,7 +18,7 @@ impl ApproveOauth {
     /// create Oauth
     ///
     /// # Examples
-    /// ```
+    /// ```ignore
     ///     dotenv().ok();
     ///     let app_key = env::var("PUB_KEY").expect("APP_KEY not set in .env file");
     ///     let app_secret = env::var("SCREST_KEY").expect("APP_SECRET not set in .env file");
@@ -27,7 +27,21 @@ impl ApproveOauth {
     ///     println!("{:?}", tokeapproval_key);
     /// ```
     pub async fn new(app_key: String, app_secret: String) -> Result<Self, Box<dyn Error>> {
-        let url = "https://openapi.koreainvestment.com:9443/oauth2/Approval";
+        Self::issue(app_key, app_secret, false).await
+    }
+
+    /// 실전/모의 도메인을 골라 웹소켓 접속키 발급
+    pub async fn issue(
+        app_key: String,
+        app_secret: String,
+        practice: bool,
+    ) -> Result<Self, Box<dyn Error>> {
+        let domain = if practice {
+            "https://openapivts.koreainvestment.com:29443"
+        } else {
+            "https://openapi.koreainvestment.com:9443"
+        };
+        let url = format!("{domain}/oauth2/Approval");
 
         let body = json!({
             "grant_type": "client_credentials",
@@ -39,7 +53,7 @@ impl ApproveOauth {
         headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
 
         let response = http_client()
-            .post(url)
+            .post(&url)
             .headers(headers)
             .json(&body)
             .send()
@@ -1,17 +1,9 @@
+use crate::websocket::RawRealtimeData;
+pub use crate::websocket::RealtimeData;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RawOverseasRealtimeData {
-    pub payload: String,
-}
+pub type RawOverseasRealtimeData = RawRealtimeData;
 
-impl RealtimeData for RawOverseasRealtimeData {
-    fn from_delimited_string(text: &str) -> Option<Self> {
-        Some(Self {
-            payload: text.to_string(),
-        })
-    }
-}
 //1) Overseas Stock Real-Time Delayed Transaction Price [Real-Time-007]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OverseasDelayedTransactionPriceData {
@@ -528,7 +520,219 @@ impl RealtimeData for OverseasQuotesData {
         })
     }
 }
-pub trait RealtimeData: Sized {
-    /// 구분자(^)로 나뉜 문자열에서 구조체 생성
-    fn from_delimited_string(text: &str) -> Option<Self>;
+
+//5) Overseas Future Option Real-Time Quotes (HDFFF010)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasFutureOptionQuoteData {
+    /// 종목코드
+    pub series_cd: String,
+    /// 수신일자
+    pub recv_date: String,
+    /// 수신시각
+    pub recv_time: String,
+    /// 전일종가
+    pub prev_price: String,
+    /// 매수1수량
+    pub bid_qntt_1: String,
+    /// 매수1건수
+    pub bid_num_1: String,
+    /// 매수1호가
+    pub bid_price_1: String,
+    /// 매도1수량
+    pub ask_qntt_1: String,
+    /// 매도1건수
+    pub ask_num_1: String,
+    /// 매도1호가
+    pub ask_price_1: String,
+    /// 매수2수량
+    pub bid_qntt_2: String,
+    /// 매수2건수
+    pub bid_num_2: String,
+    /// 매수2호가
+    pub bid_price_2: String,
+    /// 매도2수량
+    pub ask_qntt_2: String,
+    /// 매도2건수
+    pub ask_num_2: String,
+    /// 매도2호가
+    pub ask_price_2: String,
+    /// 매수3수량
+    pub bid_qntt_3: String,
+    /// 매수3건수
+    pub bid_num_3: String,
+    /// 매수3호가
+    pub bid_price_3: String,
+    /// 매도3수량
+    pub ask_qntt_3: String,
+    /// 매도3건수
+    pub ask_num_3: String,
+    /// 매도3호가
+    pub ask_price_3: String,
+    /// 매수4수량
+    pub bid_qntt_4: String,
+    /// 매수4건수
+    pub bid_num_4: String,
+    /// 매수4호가
+    pub bid_price_4: String,
+    /// 매도4수량
+    pub ask_qntt_4: String,
+    /// 매도4건수
+    pub ask_num_4: String,
+    /// 매도4호가
+    pub ask_price_4: String,
+    /// 매수5수량
+    pub bid_qntt_5: String,
+    /// 매수5건수
+    pub bid_num_5: String,
+    /// 매수5호가
+    pub bid_price_5: String,
+    /// 매도5수량
+    pub ask_qntt_5: String,
+    /// 매도5건수
+    pub ask_num_5: String,
+    /// 매도5호가
+    pub ask_price_5: String,
+    /// 전일정산가
+    pub sttl_price: String,
+}
+
+impl RealtimeData for OverseasFutureOptionQuoteData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 35 {
+            return None;
+        }
+
+        Some(Self {
+            series_cd: fields[0].to_string(),
+            recv_date: fields[1].to_string(),
+            recv_time: fields[2].to_string(),
+            prev_price: fields[3].to_string(),
+            bid_qntt_1: fields[4].to_string(),
+            bid_num_1: fields[5].to_string(),
+            bid_price_1: fields[6].to_string(),
+            ask_qntt_1: fields[7].to_string(),
+            ask_num_1: fields[8].to_string(),
+            ask_price_1: fields[9].to_string(),
+            bid_qntt_2: fields[10].to_string(),
+            bid_num_2: fields[11].to_string(),
+            bid_price_2: fields[12].to_string(),
+            ask_qntt_2: fields[13].to_string(),
+            ask_num_2: fields[14].to_string(),
+            ask_price_2: fields[15].to_string(),
+            bid_qntt_3: fields[16].to_string(),
+            bid_num_3: fields[17].to_string(),
+            bid_price_3: fields[18].to_string(),
+            ask_qntt_3: fields[19].to_string(),
+            ask_num_3: fields[20].to_string(),
+            ask_price_3: fields[21].to_string(),
+            bid_qntt_4: fields[22].to_string(),
+            bid_num_4: fields[23].to_string(),
+            bid_price_4: fields[24].to_string(),
+            ask_qntt_4: fields[25].to_string(),
+            ask_num_4: fields[26].to_string(),
+            ask_price_4: fields[27].to_string(),
+            bid_qntt_5: fields[28].to_string(),
+            bid_num_5: fields[29].to_string(),
+            bid_price_5: fields[30].to_string(),
+            ask_qntt_5: fields[31].to_string(),
+            ask_num_5: fields[32].to_string(),
+            ask_price_5: fields[33].to_string(),
+            sttl_price: fields[34].to_string(),
+        })
+    }
+}
+
+//6) Overseas Future Option Real-Time Trade (HDFFF020)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OverseasFutureOptionTradeData {
+    /// 종목코드
+    pub series_cd: String,
+    /// 영업일자
+    pub bsns_date: String,
+    /// 장개시일자
+    pub mrkt_open_date: String,
+    /// 장개시시각
+    pub mrkt_open_time: String,
+    /// 장종료일자
+    pub mrkt_close_date: String,
+    /// 장종료시각
+    pub mrkt_close_time: String,
+    /// 전일종가
+    pub prev_price: String,
+    /// 수신일자
+    pub recv_date: String,
+    /// 수신시각
+    pub recv_time: String,
+    /// 본장/전산장 구분
+    pub active_flag: String,
+    /// 체결가격
+    pub last_price: String,
+    /// 체결수량
+    pub last_qntt: String,
+    /// 전일대비가
+    pub prev_diff_price: String,
+    /// 등락률
+    pub prev_diff_rate: String,
+    /// 시가
+    pub open_price: String,
+    /// 고가
+    pub high_price: String,
+    /// 저가
+    pub low_price: String,
+    /// 누적거래량
+    pub vol: String,
+    /// 전일대비부호
+    pub prev_sign: String,
+    /// 체결구분 (2:매수체결 5:매도체결)
+    pub quotsign: String,
+    /// 수신시각2 (만분의일초)
+    pub recv_time2: String,
+    /// 전일정산가
+    pub psttl_price: String,
+    /// 전일정산가대비부호
+    pub psttl_sign: String,
+    /// 전일정산가대비가격
+    pub psttl_diff_price: String,
+    /// 전일정산가대비율
+    pub psttl_diff_rate: String,
+}
+
+impl RealtimeData for OverseasFutureOptionTradeData {
+    fn from_delimited_string(text: &str) -> Option<Self> {
+        let fields: Vec<&str> = text.split('^').collect();
+
+        if fields.len() < 25 {
+            return None;
+        }
+
+        Some(Self {
+            series_cd: fields[0].to_string(),
+            bsns_date: fields[1].to_string(),
+            mrkt_open_date: fields[2].to_string(),
+            mrkt_open_time: fields[3].to_string(),
+            mrkt_close_date: fields[4].to_string(),
+            mrkt_close_time: fields[5].to_string(),
+            prev_price: fields[6].to_string(),
+            recv_date: fields[7].to_string(),
+            recv_time: fields[8].to_string(),
+            active_flag: fields[9].to_string(),
+            last_price: fields[10].to_string(),
+            last_qntt: fields[11].to_string(),
+            prev_diff_price: fields[12].to_string(),
+            prev_diff_rate: fields[13].to_string(),
+            open_price: fields[14].to_string(),
+            high_price: fields[15].to_string(),
+            low_price: fields[16].to_string(),
+            vol: fields[17].to_string(),
+            prev_sign: fields[18].to_string(),
+            quotsign: fields[19].to_string(),
+            recv_time2: fields[20].to_string(),
+            psttl_price: fields[21].to_string(),
+            psttl_sign: fields[22].to_string(),
+            psttl_diff_price: fields[23].to_string(),
+            psttl_diff_rate: fields[24].to_string(),
+        })
+    }
 }
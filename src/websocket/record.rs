@@ -0,0 +1,352 @@
+use super::client::{ControlMessage, Dispatch, RealtimeError, StreamController};
+use super::stream::{Fanout, FanoutDispatch, RealtimeSubscription, SubscriptionOptions};
+use super::{RealtimeData, RealtimeInfoType};
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::mpsc as std_mpsc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::io::AsyncBufReadExt;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+/// 기록된 실시간 레코드 1건
+///
+/// 파일에는 한 줄에 `수신시각(μs)|TR_ID|종목키|데이터` 형식으로 저장됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedFrame {
+    /// 수신 시각 (UNIX epoch 기준 마이크로초)
+    pub received_at: u64,
+    pub tr_id: String,
+    /// 종목키 (데이터 첫 필드가 등록 종목이 아니면 빈 문자열)
+    pub tr_key: String,
+    /// 프레임 헤더를 뗀 `^` 구분 데이터
+    pub payload: String,
+}
+
+impl RecordedFrame {
+    pub fn parse_line(line: &str) -> Option<Self> {
+        let mut parts = line.trim_end_matches(['\r', '\n']).splitn(4, '|');
+        Some(Self {
+            received_at: parts.next()?.parse().ok()?,
+            tr_id: parts.next()?.to_string(),
+            tr_key: parts.next()?.to_string(),
+            payload: parts.next()?.to_string(),
+        })
+    }
+
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}|{}|{}|{}\n",
+            self.received_at, self.tr_id, self.tr_key, self.payload
+        )
+    }
+
+    /// 기록된 데이터를 실시간 모델로 파싱
+    pub fn parse<T: RealtimeData>(&self) -> Option<T> {
+        T::from_delimited_string(&self.payload)
+    }
+}
+
+/// 실시간 수신 데이터를 파일 끝에 이어 쓰는 기록기
+///
+/// [`RealtimeClient::with_recorder`](super::RealtimeClient::with_recorder)로 붙이면
+/// 해당 클라이언트의 모든 구독이 기록됩니다.
+/// 파일 쓰기는 전용 스레드에서 처리하므로 수신 task를 막지 않으며, 1초마다 그리고
+/// 마지막 복제본이 drop될 때 flush합니다. 바로 읽어야 하면 [`Self::flush`]를 호출하세요.
+#[derive(Debug, Clone)]
+pub struct RealtimeRecorder {
+    path: PathBuf,
+    tx: std_mpsc::Sender<WriterCommand>,
+}
+
+enum WriterCommand {
+    Frame(RecordedFrame),
+    Flush(std_mpsc::Sender<io::Result<()>>),
+}
+
+/// 기록 스레드의 주기적 flush 간격
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+impl RealtimeRecorder {
+    /// 기록 파일 열기 (없으면 생성, 있으면 이어 쓰기)
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path.as_ref())?;
+        let (tx, rx) = std_mpsc::channel();
+        std::thread::Builder::new()
+            .name("realtime-recorder".to_string())
+            .spawn(move || run_writer(BufWriter::new(file), rx))?;
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            tx,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn record(&self, tr_id: &str, tr_key: &str, payload: &str) -> io::Result<()> {
+        let received_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_micros() as u64)
+            .unwrap_or_default();
+        self.send(WriterCommand::Frame(RecordedFrame {
+            received_at,
+            tr_id: tr_id.to_string(),
+            tr_key: tr_key.to_string(),
+            payload: payload.to_string(),
+        }))
+    }
+
+    pub fn write(&self, frame: &RecordedFrame) -> io::Result<()> {
+        self.send(WriterCommand::Frame(frame.clone()))
+    }
+
+    /// 지금까지 넘긴 레코드를 파일에 쓸 때까지 대기 (blocking)
+    pub fn flush(&self) -> io::Result<()> {
+        let (done_tx, done_rx) = std_mpsc::channel();
+        self.send(WriterCommand::Flush(done_tx))?;
+        done_rx.recv().map_err(|_| writer_closed())?
+    }
+
+    fn send(&self, command: WriterCommand) -> io::Result<()> {
+        self.tx.send(command).map_err(|_| writer_closed())
+    }
+}
+
+fn writer_closed() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::BrokenPipe,
+        "실시간 기록 스레드가 종료되었습니다",
+    )
+}
+
+fn run_writer(mut writer: BufWriter<File>, rx: std_mpsc::Receiver<WriterCommand>) {
+    loop {
+        let result = match rx.recv_timeout(FLUSH_INTERVAL) {
+            Ok(WriterCommand::Frame(frame)) => writer.write_all(frame.to_line().as_bytes()),
+            Ok(WriterCommand::Flush(done)) => {
+                let _ = done.send(writer.flush());
+                Ok(())
+            }
+            Err(std_mpsc::RecvTimeoutError::Timeout) => writer.flush(),
+            Err(std_mpsc::RecvTimeoutError::Disconnected) => {
+                let _ = writer.flush();
+                return;
+            }
+        };
+        if let Err(error) = result {
+            println!("실시간 기록 실패: {}", error);
+            return;
+        }
+    }
+}
+
+/// 기록 파일 전체 읽기 (파서 회귀 테스트 등)
+pub fn read_recording(path: impl AsRef<Path>) -> io::Result<Vec<RecordedFrame>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut frames = Vec::new();
+    for line in reader.lines() {
+        if let Some(frame) = RecordedFrame::parse_line(&line?) {
+            frames.push(frame);
+        }
+    }
+    Ok(frames)
+}
+
+/// 재생 속도
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReplaySpeed {
+    /// 기록된 간격 그대로 (1배속)
+    RealTime,
+    /// N배속 ([`ReplaySpeed::multiplier`]로 생성)
+    Multiplier(ReplayMultiplier),
+    /// 대기 없이 최대한 빠르게
+    AsFastAsPossible,
+}
+
+/// 0보다 큰 유한한 재생 배속
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReplayMultiplier(f64);
+
+impl ReplayMultiplier {
+    pub fn new(speed: f64) -> Result<Self, RealtimeError> {
+        if speed.is_finite() && speed > 0.0 {
+            Ok(Self(speed))
+        } else {
+            Err(RealtimeError::InvalidInput(format!(
+                "재생 배속은 0보다 큰 유한한 값이어야 합니다: {speed}"
+            )))
+        }
+    }
+
+    pub fn get(&self) -> f64 {
+        self.0
+    }
+}
+
+impl ReplaySpeed {
+    /// N배속 (0 이하, NaN, 무한대는 오류)
+    pub fn multiplier(speed: f64) -> Result<Self, RealtimeError> {
+        ReplayMultiplier::new(speed).map(Self::Multiplier)
+    }
+
+    fn scale(&self, elapsed: Duration) -> Option<Duration> {
+        match self {
+            Self::RealTime => Some(elapsed),
+            Self::Multiplier(speed) => Some(elapsed.div_f64(speed.get())),
+            Self::AsFastAsPossible => None,
+        }
+    }
+}
+
+/// 재생 task가 타입을 지운 채 들고 있는 전달 대상
+trait ReplayDispatch: Send {
+    fn dispatch_boxed<'a>(
+        &'a mut self,
+        record: &'a str,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>>;
+
+    fn close_boxed(&mut self);
+}
+
+impl<D: Dispatch> ReplayDispatch for D {
+    fn dispatch_boxed<'a>(
+        &'a mut self,
+        record: &'a str,
+    ) -> Pin<Box<dyn Future<Output = bool> + Send + 'a>> {
+        Box::pin(self.dispatch(record))
+    }
+
+    fn close_boxed(&mut self) {
+        self.close();
+    }
+}
+
+struct ReplayTarget {
+    tr_id: &'static str,
+    tr_key: String,
+    dispatch: Box<dyn ReplayDispatch>,
+    active: bool,
+}
+
+/// 기록 파일을 실시간 구독과 같은 API로 재생
+///
+/// 구독을 모두 등록한 뒤 [`RealtimeReplay::start`]를 호출하면 파일에 기록된 순서대로
+/// 각 구독에 전달합니다. 모든 구독은 하나의 재생 task를 공유하므로 종목 간 순서도 보존됩니다.
+pub struct RealtimeReplay {
+    path: PathBuf,
+    speed: ReplaySpeed,
+    targets: Vec<ReplayTarget>,
+    tx: mpsc::Sender<ControlMessage>,
+    rx: mpsc::Receiver<ControlMessage>,
+}
+
+impl RealtimeReplay {
+    pub fn new(path: impl AsRef<Path>, speed: ReplaySpeed) -> Self {
+        let (tx, rx) = mpsc::channel::<ControlMessage>(32);
+        Self {
+            path: path.as_ref().to_path_buf(),
+            speed,
+            targets: Vec::new(),
+            tx,
+            rx,
+        }
+    }
+
+    /// 기록된 구독 재생 (`RealtimeClient::subscribe`와 같은 구독 핸들 반환)
+    pub fn subscribe<T: RealtimeData + Clone + Send + Sync + 'static>(
+        &mut self,
+        symbol: &str,
+        r#type: impl Into<RealtimeInfoType>,
+        options: SubscriptionOptions,
+    ) -> RealtimeSubscription<T> {
+        let fanout = Arc::new(Fanout::new());
+        self.targets.push(ReplayTarget {
+            tr_id: r#type.into().get_tr_code(),
+            tr_key: symbol.to_string(),
+            dispatch: Box::new(FanoutDispatch {
+                fanout: fanout.clone(),
+            }),
+            active: true,
+        });
+        RealtimeSubscription::new(fanout, self.controller(), options)
+    }
+
+    fn controller(&self) -> StreamController {
+        StreamController {
+            tx: self.tx.clone(),
+        }
+    }
+
+    /// 재생 시작, 파일 끝에 도달하면 모든 스트림이 종료됩니다
+    pub async fn start(self) -> Result<StreamController, RealtimeError> {
+        let file = tokio::fs::File::open(&self.path)
+            .await
+            .map_err(|e| RealtimeError::ConnectionError(e.to_string()))?;
+        let controller = self.controller();
+        let Self {
+            speed,
+            mut targets,
+            mut rx,
+            ..
+        } = self;
+
+        tokio::spawn(async move {
+            let mut lines = tokio::io::BufReader::new(file).lines();
+            let mut origin: Option<(u64, Instant)> = None;
+            loop {
+                let line = tokio::select! {
+                    Some(ControlMessage::Stop) = rx.recv() => break,
+                    line = lines.next_line() => match line {
+                        Ok(Some(line)) => line,
+                        Ok(None) => break,
+                        Err(error) => {
+                            println!("기록 파일 읽기 오류: {}", error);
+                            break;
+                        }
+                    },
+                };
+                let Some(frame) = RecordedFrame::parse_line(&line) else {
+                    continue;
+                };
+
+                let (first_at, started) =
+                    *origin.get_or_insert((frame.received_at, Instant::now()));
+                let elapsed = Duration::from_micros(frame.received_at.saturating_sub(first_at));
+                match speed.scale(elapsed) {
+                    Some(delay) => tokio::select! {
+                        Some(ControlMessage::Stop) = rx.recv() => break,
+                        _ = tokio::time::sleep_until(started + delay) => {}
+                    },
+                    None => tokio::task::yield_now().await,
+                }
+
+                for target in targets.iter_mut().filter(|target| target.active) {
+                    if target.tr_id == frame.tr_id
+                        && target.tr_key == frame.tr_key
+                        && !target.dispatch.dispatch_boxed(&frame.payload).await
+                    {
+                        target.active = false;
+                    }
+                }
+                if targets.iter().all(|target| !target.active) {
+                    break;
+                }
+            }
+            for target in targets.iter_mut() {
+                target.dispatch.close_boxed();
+            }
+            println!("기록 재생 종료");
+        });
+
+        Ok(controller)
+    }
+}
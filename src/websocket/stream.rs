@@ -0,0 +1,409 @@
+use super::RealtimeData;
+use super::client::{Dispatch, RealtimeError, StreamController};
+use futures_util::Stream;
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+use tokio::sync::Notify;
+
+/// 소비자가 느려 버퍼가 가득 찼을 때의 처리 방식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// 빈 자리가 생길 때까지 전달을 멈춤 (같은 구독의 다른 소비자도 함께 대기)
+    ///
+    /// 웹소켓 수신은 연결별 전달 대기열(1024건)이 찰 때까지 계속되므로 짧은 지연에는 PINGPONG 응답이 유지됩니다.
+    /// 대기열까지 가득 차면 수신이 멈춰 서버가 연결을 끊을 수 있습니다. 중지 요청은 대기 중에도 처리됩니다.
+    Block,
+    /// 가장 오래된 데이터를 버리고 새 데이터를 넣음
+    DropOldest,
+    /// 새 데이터를 버림 (기존 `start_stream_channel` 동작)
+    #[default]
+    DropNewest,
+    /// 오류를 한 번 돌려주고 해당 스트림을 종료
+    Error,
+}
+
+/// 구독별 버퍼 설정
+#[derive(Debug, Clone, Copy)]
+pub struct SubscriptionOptions {
+    /// 스트림당 버퍼 크기
+    pub capacity: usize,
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for SubscriptionOptions {
+    fn default() -> Self {
+        Self {
+            capacity: 100,
+            overflow: OverflowPolicy::DropNewest,
+        }
+    }
+}
+
+impl SubscriptionOptions {
+    pub fn new(capacity: usize, overflow: OverflowPolicy) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            overflow,
+        }
+    }
+}
+
+struct BufferState<T> {
+    queue: VecDeque<T>,
+    error: Option<RealtimeError>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+/// 스트림 하나의 버퍼
+struct Buffer<T> {
+    options: SubscriptionOptions,
+    state: Mutex<BufferState<T>>,
+    space: Notify,
+    dropped: AtomicU64,
+}
+
+impl<T> Buffer<T> {
+    fn new(options: SubscriptionOptions) -> Self {
+        Self {
+            options,
+            state: Mutex::new(BufferState {
+                queue: VecDeque::with_capacity(options.capacity),
+                error: None,
+                closed: false,
+                waker: None,
+            }),
+            space: Notify::new(),
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    fn is_closed(&self) -> bool {
+        self.state.lock().unwrap().closed
+    }
+
+    fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+        drop(state);
+        self.space.notify_one();
+    }
+
+    async fn push(&self, data: T) {
+        loop {
+            let notified = self.space.notified();
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.closed {
+                    return;
+                }
+                if state.queue.len() >= self.options.capacity {
+                    match self.options.overflow {
+                        OverflowPolicy::Block => {}
+                        OverflowPolicy::DropOldest => {
+                            state.queue.pop_front();
+                            self.dropped.fetch_add(1, Ordering::Relaxed);
+                        }
+                        OverflowPolicy::DropNewest => {
+                            self.dropped.fetch_add(1, Ordering::Relaxed);
+                            return;
+                        }
+                        OverflowPolicy::Error => {
+                            self.dropped.fetch_add(1, Ordering::Relaxed);
+                            state.error = Some(RealtimeError::OverflowError(format!(
+                                "버퍼({}) 초과",
+                                self.options.capacity
+                            )));
+                            state.closed = true;
+                            if let Some(waker) = state.waker.take() {
+                                waker.wake();
+                            }
+                            return;
+                        }
+                    }
+                }
+                if state.queue.len() < self.options.capacity {
+                    state.queue.push_back(data);
+                    if let Some(waker) = state.waker.take() {
+                        waker.wake();
+                    }
+                    return;
+                }
+            }
+            notified.await;
+        }
+    }
+}
+
+/// 여러 스트림에 같은 실시간 데이터를 나눠주는 분배기
+pub(crate) struct Fanout<T> {
+    state: Mutex<FanoutState<T>>,
+}
+
+struct FanoutState<T> {
+    buffers: Vec<Arc<Buffer<T>>>,
+    /// 구독이 끝나 더는 데이터가 오지 않음
+    closed: bool,
+}
+
+impl<T: Clone> Fanout<T> {
+    pub(crate) fn new() -> Self {
+        Self {
+            state: Mutex::new(FanoutState {
+                buffers: Vec::new(),
+                closed: false,
+            }),
+        }
+    }
+
+    fn attach(&self, options: SubscriptionOptions) -> Arc<Buffer<T>> {
+        // 구조체 리터럴로 만든 설정도 `new`와 같이 최소 1건으로 맞춤
+        let options = SubscriptionOptions {
+            capacity: options.capacity.max(1),
+            ..options
+        };
+        let buffer = Arc::new(Buffer::new(options));
+        let mut state = self.state.lock().unwrap();
+        // 이미 끝난 구독에 붙은 스트림은 바로 종료
+        if state.closed {
+            buffer.close();
+        } else {
+            state.buffers.push(buffer.clone());
+        }
+        buffer
+    }
+
+    /// 분배기에 연결된 새 스트림 생성
+    pub(crate) fn stream(fanout: &Arc<Self>, options: SubscriptionOptions) -> RealtimeStream<T> {
+        RealtimeStream {
+            buffer: fanout.attach(options),
+            _fanout: fanout.clone(),
+        }
+    }
+
+    pub(crate) async fn publish(&self, data: T) {
+        let buffers = {
+            let mut state = self.state.lock().unwrap();
+            state.buffers.retain(|buffer| !buffer.is_closed());
+            state.buffers.clone()
+        };
+        for buffer in buffers {
+            buffer.push(data.clone()).await;
+        }
+    }
+
+    pub(crate) fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        for buffer in state.buffers.drain(..) {
+            buffer.close();
+        }
+    }
+}
+
+/// 웹소켓 수신 task에서 분배기로 전달
+pub(crate) struct FanoutDispatch<T> {
+    pub(crate) fanout: Arc<Fanout<T>>,
+}
+
+impl<T: RealtimeData + Clone + Send + Sync + 'static> Dispatch for FanoutDispatch<T> {
+    async fn dispatch(&mut self, record: &str) -> bool {
+        // 구독 핸들과 스트림이 모두 사라지면 구독 해제
+        if Arc::strong_count(&self.fanout) == 1 {
+            return false;
+        }
+        match T::from_delimited_string(record) {
+            Some(data) => self.fanout.publish(data).await,
+            None => println!("데이터 파싱 실패: {}", record),
+        }
+        true
+    }
+
+    fn close(&mut self) {
+        self.fanout.close();
+    }
+}
+
+/// 실시간 구독 핸들
+///
+/// 하나의 웹소켓 구독을 여러 [`RealtimeStream`]이 나눠 받습니다.
+/// 핸들과 스트림이 모두 drop되면 구독을 해제합니다.
+pub struct RealtimeSubscription<T> {
+    fanout: Arc<Fanout<T>>,
+    controller: StreamController,
+    options: SubscriptionOptions,
+}
+
+impl<T: Clone> RealtimeSubscription<T> {
+    pub(crate) fn new(
+        fanout: Arc<Fanout<T>>,
+        controller: StreamController,
+        options: SubscriptionOptions,
+    ) -> Self {
+        Self {
+            fanout,
+            controller,
+            options,
+        }
+    }
+
+    /// 구독 기본 설정으로 새 스트림 생성
+    pub fn stream(&self) -> RealtimeStream<T> {
+        self.stream_with(self.options)
+    }
+
+    /// 스트림별 버퍼 설정을 지정해 새 스트림 생성
+    pub fn stream_with(&self, options: SubscriptionOptions) -> RealtimeStream<T> {
+        Fanout::stream(&self.fanout, options)
+    }
+
+    /// 구독 해제 후 모든 스트림 종료
+    pub async fn stop(&self) -> Result<(), RealtimeError> {
+        self.controller.stop().await
+    }
+}
+
+/// 실시간 데이터 스트림 (`futures::Stream`)
+///
+/// [`OverflowPolicy::Error`]로 버퍼가 넘치면 `Err`를 한 번 돌려준 뒤 종료합니다.
+pub struct RealtimeStream<T> {
+    buffer: Arc<Buffer<T>>,
+    _fanout: Arc<Fanout<T>>,
+}
+
+impl<T> RealtimeStream<T> {
+    /// 버퍼 초과로 버려진 데이터 수
+    pub fn dropped(&self) -> u64 {
+        self.buffer.dropped.load(Ordering::Relaxed)
+    }
+
+    /// 현재 버퍼에 쌓인 데이터 수
+    pub fn len(&self) -> usize {
+        self.buffer.state.lock().unwrap().queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Stream for RealtimeStream<T> {
+    type Item = Result<T, RealtimeError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut state = self.buffer.state.lock().unwrap();
+        if let Some(data) = state.queue.pop_front() {
+            drop(state);
+            self.buffer.space.notify_one();
+            return Poll::Ready(Some(Ok(data)));
+        }
+        if let Some(error) = state.error.take() {
+            return Poll::Ready(Some(Err(error)));
+        }
+        if state.closed {
+            return Poll::Ready(None);
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for RealtimeStream<T> {
+    fn drop(&mut self) {
+        self.buffer.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    fn stream_with(fanout: &Arc<Fanout<u32>>, options: SubscriptionOptions) -> RealtimeStream<u32> {
+        Fanout::stream(fanout, options)
+    }
+
+    #[tokio::test]
+    async fn zero_capacity_literal_is_clamped() {
+        let fanout = Arc::new(Fanout::new());
+        let mut stream = stream_with(
+            &fanout,
+            SubscriptionOptions {
+                capacity: 0,
+                overflow: OverflowPolicy::DropNewest,
+            },
+        );
+        fanout.publish(1).await;
+        fanout.publish(2).await;
+        fanout.close();
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert!(stream.next().await.is_none());
+        assert_eq!(stream.dropped(), 1);
+    }
+
+    #[tokio::test]
+    async fn drop_oldest_keeps_latest() {
+        let fanout = Arc::new(Fanout::new());
+        let mut stream = stream_with(
+            &fanout,
+            SubscriptionOptions::new(2, OverflowPolicy::DropOldest),
+        );
+        for value in 1..=4 {
+            fanout.publish(value).await;
+        }
+        fanout.close();
+
+        let received: Vec<u32> = stream.by_ref().map(Result::unwrap).collect().await;
+        assert_eq!(received, vec![3, 4]);
+        assert_eq!(stream.dropped(), 2);
+    }
+
+    #[tokio::test]
+    async fn block_waits_for_the_consumer() {
+        let fanout = Arc::new(Fanout::new());
+        let mut stream = stream_with(&fanout, SubscriptionOptions::new(1, OverflowPolicy::Block));
+        fanout.publish(1).await;
+
+        let publisher = fanout.clone();
+        let mut blocked = tokio::spawn(async move { publisher.publish(2).await });
+        let waited = tokio::time::timeout(std::time::Duration::from_millis(50), &mut blocked).await;
+        assert!(waited.is_err(), "버퍼가 비기 전에 publish가 끝나면 안 됨");
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        blocked.await.unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), 2);
+        assert_eq!(stream.dropped(), 0);
+    }
+
+    #[tokio::test]
+    async fn error_policy_yields_one_error_then_ends() {
+        let fanout = Arc::new(Fanout::new());
+        let mut stream = stream_with(&fanout, SubscriptionOptions::new(1, OverflowPolicy::Error));
+        fanout.publish(1).await;
+        fanout.publish(2).await;
+        fanout.publish(3).await;
+
+        assert_eq!(stream.next().await.unwrap().unwrap(), 1);
+        assert!(matches!(
+            stream.next().await,
+            Some(Err(RealtimeError::OverflowError(_)))
+        ));
+        assert!(stream.next().await.is_none());
+        assert_eq!(stream.dropped(), 1);
+    }
+
+    #[tokio::test]
+    async fn stream_after_close_ends_immediately() {
+        let fanout = Arc::new(Fanout::<u32>::new());
+        fanout.close();
+        let mut stream = stream_with(&fanout, SubscriptionOptions::default());
+        assert!(stream.next().await.is_none());
+    }
+}
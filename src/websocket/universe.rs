@@ -0,0 +1,246 @@
+use super::client::{
+    ControlMessage, Dispatch, REALTIME_REGISTRATION_LIMIT, RealtimeClient, RealtimeError,
+    StreamController,
+};
+use super::stream::{Fanout, RealtimeStream, SubscriptionOptions};
+use super::{RealtimeData, RealtimeInfoType};
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+/// 구독 대상 변경 내역
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UniverseChange {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl UniverseChange {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// 종목 집합 단위로 관리하는 실시간 구독
+///
+/// 모든 종목을 웹소켓 연결 하나에 등록(tr_type 1)/해제(tr_type 2)하고, 수신 데이터는
+/// 레코드 첫 필드(종목코드)를 붙여 `(종목코드, 데이터)` 스트림으로 모읍니다.
+/// 연결당 등록 한도([`REALTIME_REGISTRATION_LIMIT`]건)를 넘기면 [`RealtimeError::LimitError`]를 돌려줍니다.
+/// 버퍼가 넘칠 때의 처리는 [`SubscriptionOptions`]로 정하며, 버린 건수는 [`RealtimeStream::dropped`]로 확인합니다.
+/// 관심종목 그룹이나 조건검색 결과처럼 바뀌는 종목 목록을 [`Self::sync`]로 맞춰 둘 수 있습니다.
+pub struct RealtimeUniverse<T> {
+    r#type: RealtimeInfoType,
+    fanout: Arc<Fanout<(String, T)>>,
+    options: SubscriptionOptions,
+    controller: Option<StreamController>,
+    symbols: BTreeSet<String>,
+}
+
+impl<T: RealtimeData + Clone + Send + Sync + 'static> RealtimeUniverse<T> {
+    /// 구독 TR과 스트림 버퍼 설정을 지정해 생성
+    ///
+    /// 웹소켓 연결은 첫 종목을 추가할 때 엽니다.
+    pub fn new(
+        r#type: impl Into<RealtimeInfoType>,
+        options: SubscriptionOptions,
+    ) -> (Self, RealtimeStream<(String, T)>) {
+        let universe = Self {
+            r#type: r#type.into(),
+            fanout: Arc::new(Fanout::new()),
+            options,
+            controller: None,
+            symbols: BTreeSet::new(),
+        };
+        let stream = universe.stream();
+        (universe, stream)
+    }
+
+    /// 기본 설정으로 새 스트림 생성
+    pub fn stream(&self) -> RealtimeStream<(String, T)> {
+        self.stream_with(self.options)
+    }
+
+    /// 스트림별 버퍼 설정을 지정해 새 스트림 생성
+    pub fn stream_with(&self, options: SubscriptionOptions) -> RealtimeStream<(String, T)> {
+        Fanout::stream(&self.fanout, options)
+    }
+
+    /// 구독 중인 종목 (종목코드 순)
+    pub fn symbols(&self) -> impl Iterator<Item = &str> {
+        self.symbols.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.symbols.contains(symbol)
+    }
+
+    async fn connection(
+        &mut self,
+        client: &RealtimeClient,
+    ) -> Result<&StreamController, RealtimeError> {
+        if self.controller.is_none() {
+            let dispatch = UniverseDispatch {
+                fanout: self.fanout.clone(),
+            };
+            self.controller = Some(client.open(self.r#type.clone(), dispatch).await?);
+        }
+        Ok(self.controller.as_ref().unwrap())
+    }
+
+    /// 종목 구독 추가 (이미 구독 중이면 `false`)
+    pub async fn add(
+        &mut self,
+        client: &RealtimeClient,
+        symbol: &str,
+    ) -> Result<bool, RealtimeError> {
+        if self.contains(symbol) {
+            return Ok(false);
+        }
+        if self.symbols.len() >= REALTIME_REGISTRATION_LIMIT {
+            return Err(RealtimeError::registration_limit());
+        }
+        self.connection(client).await?.register(symbol).await?;
+        self.symbols.insert(symbol.to_string());
+        Ok(true)
+    }
+
+    /// 종목 구독 해제 (구독 중이 아니면 `false`)
+    pub async fn remove(&mut self, symbol: &str) -> Result<bool, RealtimeError> {
+        if !self.contains(symbol) {
+            return Ok(false);
+        }
+        if let Some(controller) = &self.controller {
+            controller.unregister(symbol).await?;
+        }
+        self.symbols.remove(symbol);
+        Ok(true)
+    }
+
+    /// 구독 종목을 `symbols`와 같게 맞춤
+    ///
+    /// 빠진 종목을 먼저 해제한 뒤 새 종목을 등록합니다. 목록이 등록 한도를 넘으면
+    /// 아무것도 바꾸지 않고 오류를 돌려주며, 중간에 실패하면 그때까지 반영된 상태로 남습니다.
+    pub async fn sync<I, S>(
+        &mut self,
+        client: &RealtimeClient,
+        symbols: I,
+    ) -> Result<UniverseChange, RealtimeError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let change = sync_plan(&self.symbols, symbols)?;
+        for symbol in &change.removed {
+            self.remove(symbol).await?;
+        }
+        for symbol in &change.added {
+            self.add(client, symbol).await?;
+        }
+        Ok(change)
+    }
+
+    /// 모든 종목 등록 해제 (연결과 스트림은 유지)
+    pub async fn clear(&mut self) -> Result<(), RealtimeError> {
+        let symbols: Vec<String> = self.symbols.iter().cloned().collect();
+        for symbol in symbols {
+            self.remove(&symbol).await?;
+        }
+        Ok(())
+    }
+}
+
+/// 현재 구독 종목과 목표 목록을 비교해 해제/등록할 종목 계산 (빈 종목코드는 무시)
+fn sync_plan<I, S>(current: &BTreeSet<String>, symbols: I) -> Result<UniverseChange, RealtimeError>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let target: BTreeSet<String> = symbols
+        .into_iter()
+        .map(|symbol| symbol.as_ref().to_string())
+        .filter(|symbol| !symbol.is_empty())
+        .collect();
+    if target.len() > REALTIME_REGISTRATION_LIMIT {
+        return Err(RealtimeError::registration_limit());
+    }
+    Ok(UniverseChange {
+        added: target.difference(current).cloned().collect(),
+        removed: current.difference(&target).cloned().collect(),
+    })
+}
+
+impl<T> Drop for RealtimeUniverse<T> {
+    fn drop(&mut self) {
+        // 연결을 닫으면 남은 등록도 해제되고 스트림이 종료됩니다
+        if let Some(controller) = &self.controller {
+            let _ = controller.tx.try_send(ControlMessage::Stop);
+        }
+    }
+}
+
+/// 레코드 첫 필드(종목코드)를 붙여 분배기로 전달
+struct UniverseDispatch<T> {
+    fanout: Arc<Fanout<(String, T)>>,
+}
+
+impl<T: RealtimeData + Clone + Send + Sync + 'static> Dispatch for UniverseDispatch<T> {
+    async fn dispatch(&mut self, record: &str) -> bool {
+        // 유니버스와 스트림이 모두 사라지면 연결 종료
+        if Arc::strong_count(&self.fanout) == 1 {
+            return false;
+        }
+        let symbol = record.split('^').next().unwrap_or_default().to_string();
+        match T::from_delimited_string(record) {
+            Some(data) => self.fanout.publish((symbol, data)).await,
+            None => println!("데이터 파싱 실패: {}", record),
+        }
+        true
+    }
+
+    fn close(&mut self) {
+        self.fanout.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(symbols: &[&str]) -> BTreeSet<String> {
+        symbols.iter().map(|symbol| symbol.to_string()).collect()
+    }
+
+    #[test]
+    fn sync_plan_diffs_current_and_target() {
+        let current = set(&["000660", "005930", "035720"]);
+        let change = sync_plan(&current, ["035720", "005930", "035420", "", "035420"]).unwrap();
+        assert_eq!(change.added, ["035420"]);
+        assert_eq!(change.removed, ["000660"]);
+
+        assert!(sync_plan(&current, &current).unwrap().is_empty());
+
+        let change = sync_plan(&current, Vec::<String>::new()).unwrap();
+        assert!(change.added.is_empty());
+        assert_eq!(change.removed, ["000660", "005930", "035720"]);
+    }
+
+    #[test]
+    fn sync_plan_rejects_targets_over_the_limit() {
+        let symbols: Vec<String> = (0..=REALTIME_REGISTRATION_LIMIT)
+            .map(|i| format!("{i:06}"))
+            .collect();
+        assert!(matches!(
+            sync_plan(&BTreeSet::new(), &symbols),
+            Err(RealtimeError::LimitError(_))
+        ));
+        let change = sync_plan(&BTreeSet::new(), &symbols[..REALTIME_REGISTRATION_LIMIT]).unwrap();
+        assert_eq!(change.added.len(), REALTIME_REGISTRATION_LIMIT);
+    }
+}
@@ -0,0 +1,106 @@
+use korea_investment_rs::master::{ListedMarket, SymbolInfo, parse_master};
+
+// 국내 종목 마스터파일 형식(EUC-KR 고정폭)으로 저장된 KOSPI/KOSDAQ/KONEX 종목
+fn fixture(market: ListedMarket) -> Vec<SymbolInfo> {
+    let path = format!(
+        "{}/tests/fixtures/{}",
+        env!("CARGO_MANIFEST_DIR"),
+        market.file_name()
+    );
+    let bytes = std::fs::read(path).expect("마스터파일 읽기 실패");
+    parse_master(market, &bytes).expect("마스터파일 파싱 실패")
+}
+
+fn find<'a>(symbols: &'a [SymbolInfo], code: &str) -> &'a SymbolInfo {
+    symbols
+        .iter()
+        .find(|symbol| symbol.code == code)
+        .expect("종목 없음")
+}
+
+#[test]
+fn kospi_master_fields() {
+    let symbols = fixture(ListedMarket::Kospi);
+    assert_eq!(symbols.len(), 4);
+
+    let samsung = find(&symbols, "005930");
+    assert_eq!(samsung.isin, "KR7005930003");
+    assert_eq!(samsung.name, "삼성전자");
+    assert_eq!(samsung.market, ListedMarket::Kospi);
+    assert_eq!(samsung.group_code, "ST");
+    assert_eq!(
+        (
+            samsung.sector_large.as_str(),
+            samsung.sector_medium.as_str(),
+            samsung.sector_small.as_str()
+        ),
+        ("0001", "0013", "0013")
+    );
+    assert!(!samsung.is_etf && !samsung.is_etn && !samsung.is_spac && !samsung.is_preferred);
+    assert!(!samsung.trading_halted && !samsung.administrative);
+    assert_eq!(samsung.base_price, 58300);
+    assert_eq!(samsung.lot_size, 1);
+    assert_eq!(samsung.par_value, 100.0);
+    assert_eq!(samsung.listing_date, "19750611");
+    assert_eq!(samsung.listed_shares, 5919638);
+    assert_eq!(samsung.market_cap, 3451065);
+
+    let preferred = find(&symbols, "005935");
+    assert_eq!(preferred.name, "삼성전자우");
+    assert!(preferred.is_preferred);
+    assert_eq!(preferred.listing_date, "19890925");
+
+    let etf = find(&symbols, "069500");
+    assert_eq!(etf.name, "KODEX 200");
+    assert!(etf.is_etf && !etf.is_etn);
+    assert_eq!(etf.sector_large, "");
+    assert_eq!(etf.base_price, 33410);
+
+    let spac = find(&symbols, "123456");
+    assert!(spac.is_spac && spac.trading_halted && spac.administrative);
+}
+
+#[test]
+fn kosdaq_master_fields() {
+    let symbols = fixture(ListedMarket::Kosdaq);
+    assert_eq!(symbols.len(), 2);
+
+    let ecopro = find(&symbols, "247540");
+    assert_eq!(ecopro.isin, "KR7247540008");
+    assert_eq!(ecopro.name, "에코프로비엠");
+    assert_eq!(ecopro.market, ListedMarket::Kosdaq);
+    assert_eq!(ecopro.group_code, "ST");
+    assert_eq!(ecopro.sector_small, "1063");
+    assert!(!ecopro.is_spac && !ecopro.trading_halted && !ecopro.administrative);
+    assert!(!ecopro.is_preferred);
+    assert_eq!(ecopro.base_price, 105000);
+    assert_eq!(ecopro.lot_size, 1);
+    assert_eq!(ecopro.par_value, 500.0);
+    assert_eq!(ecopro.listing_date, "20190305");
+    assert_eq!(ecopro.listed_shares, 97801);
+    assert_eq!(ecopro.market_cap, 102691);
+
+    let halted = find(&symbols, "091990");
+    assert!(halted.trading_halted && halted.administrative && !halted.is_spac);
+    assert_eq!(halted.par_value, 1000.0);
+    assert_eq!(halted.market_cap, 98034);
+}
+
+#[test]
+fn konex_master_fields() {
+    let symbols = fixture(ListedMarket::Konex);
+    let konex = find(&symbols, "278990");
+    assert_eq!(konex.isin, "KR7278990007");
+    assert_eq!(konex.name, "에이치엔에스하이텍");
+    assert_eq!(konex.group_code, "ST");
+    // 코넥스는 업종/SPAC 필드가 없음
+    assert_eq!(konex.sector_large, "");
+    assert!(!konex.is_spac && !konex.is_preferred && !konex.administrative);
+    assert!(konex.trading_halted);
+    assert_eq!(konex.base_price, 3650);
+    assert_eq!(konex.lot_size, 1);
+    assert_eq!(konex.par_value, 500.0);
+    assert_eq!(konex.listing_date, "20170726");
+    assert_eq!(konex.listed_shares, 5410);
+    assert_eq!(konex.market_cap, 197);
+}
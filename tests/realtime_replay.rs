@@ -0,0 +1,133 @@
+use futures_util::StreamExt;
+use korea_investment_rs::websocket::{
+    RealtimeData, RealtimeRecorder, RealtimeReplay, ReplaySpeed, SubscriptionOptions,
+    domestic::{
+        DomesticRealtimeInfoType,
+        models::{StockQuoteData, StockTradeData},
+    },
+    overseas::{
+        models::{OverseasDelayedTransactionPriceData, OverseasQuotesData},
+        types::OverseasRealtimeInfoType,
+    },
+    read_recording,
+};
+
+// 기록 파일 형식(`수신시각|TR_ID|종목키|데이터`)으로 저장된 국내/해외 체결·호가 프레임
+const FIXTURE: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/tests/fixtures/realtime_sample.rec"
+);
+
+fn parse<T: RealtimeData>(payload: &str) -> T {
+    T::from_delimited_string(payload).expect("기록된 프레임 파싱 실패")
+}
+
+#[test]
+fn recorded_frames_parse_with_typed_models() {
+    let frames = read_recording(FIXTURE).unwrap();
+    let tr_ids: Vec<&str> = frames.iter().map(|frame| frame.tr_id.as_str()).collect();
+    assert_eq!(
+        tr_ids,
+        ["H0STCNT0", "H0STASP0", "HDFSCNT0", "HDFSASP0", "H0STCNT0"]
+    );
+
+    let trade: StockTradeData = parse(&frames[0].payload);
+    assert_eq!(trade.mksc_shrn_iscd, "005930");
+    assert_eq!(trade.stck_cntg_hour, "090000");
+    assert_eq!(trade.stck_prpr, "58300");
+    assert_eq!(trade.cntg_vol, "1523");
+    assert_eq!(trade.bsop_date, "20251020");
+    assert_eq!(trade.vi_stnd_prc, "0");
+
+    let quote: StockQuoteData = parse(&frames[1].payload);
+    assert_eq!(quote.askp1, "58400");
+    assert_eq!(quote.askp10, "59300");
+    assert_eq!(quote.bidp1, "58300");
+    assert_eq!(quote.bidp_rsqn10, "20022");
+    assert_eq!(quote.total_askp_rsqn, "412345");
+    assert_eq!(quote.acml_vol, "12345678");
+    // KRX 호가에는 중간가 필드가 없음
+    assert_eq!(quote.nmid_cls_code, "");
+
+    let us_trade: OverseasDelayedTransactionPriceData = parse(&frames[2].payload);
+    assert_eq!(us_trade.rsym, "DNASAAPL");
+    assert_eq!(us_trade.xhms, "093015");
+    assert_eq!(us_trade.last, "251.4500");
+    assert_eq!(us_trade.tvol, "12345678");
+    assert_eq!(us_trade.mtyp, "1");
+
+    let us_quote: OverseasQuotesData = parse(&frames[3].payload);
+    assert_eq!(us_quote.symb, "AAPL");
+    assert_eq!(us_quote.pbid1, "251.4400");
+    assert_eq!(us_quote.pask1, "251.4600");
+    assert_eq!(us_quote.pask10, "251.5500");
+    assert_eq!(us_quote.vask10, "209");
+}
+
+#[tokio::test]
+async fn replay_delivers_recorded_frames_per_subscription() {
+    let mut replay = RealtimeReplay::new(FIXTURE, ReplaySpeed::AsFastAsPossible);
+    let domestic = replay.subscribe::<StockTradeData>(
+        "005930",
+        DomesticRealtimeInfoType::StockTradeKrX,
+        SubscriptionOptions::default(),
+    );
+    let overseas = replay.subscribe::<OverseasDelayedTransactionPriceData>(
+        "DNASAAPL",
+        OverseasRealtimeInfoType::DelayedTradePrice,
+        SubscriptionOptions::default(),
+    );
+    let domestic_stream = domestic.stream();
+    let overseas_stream = overseas.stream();
+    replay.start().await.unwrap();
+
+    let trades: Vec<StockTradeData> = domestic_stream.map(Result::unwrap).collect().await;
+    let prices: Vec<&str> = trades
+        .iter()
+        .map(|trade| trade.stck_prpr.as_str())
+        .collect();
+    assert_eq!(prices, ["58300", "58400"]);
+    assert_eq!(trades[1].acml_vol, "1535");
+
+    let us_trades: Vec<OverseasDelayedTransactionPriceData> =
+        overseas_stream.map(Result::unwrap).collect().await;
+    assert_eq!(us_trades.len(), 1);
+    assert_eq!(us_trades[0].symb, "AAPL");
+}
+
+#[test]
+fn replay_speed_rejects_invalid_multipliers() {
+    assert!(ReplaySpeed::multiplier(10.0).is_ok());
+    for speed in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        assert!(ReplaySpeed::multiplier(speed).is_err(), "{speed}");
+    }
+}
+
+#[test]
+fn recorder_writes_frames_off_thread_and_flushes() {
+    let path = std::env::temp_dir().join(format!("kis_recorder_{}.rec", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    let frames = read_recording(FIXTURE).unwrap();
+
+    let recorder = RealtimeRecorder::open(&path).unwrap();
+    for frame in &frames {
+        recorder.write(frame).unwrap();
+    }
+    recorder.flush().unwrap();
+    assert_eq!(read_recording(&path).unwrap(), frames);
+
+    recorder
+        .record("H0STCNT0", "005930", &frames[0].payload)
+        .unwrap();
+    drop(recorder);
+    // 마지막 복제본이 drop되면 기록 스레드가 남은 데이터를 flush하고 종료
+    let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+    while read_recording(&path).unwrap().len() <= frames.len() {
+        assert!(
+            std::time::Instant::now() < deadline,
+            "drop 후 flush되지 않음"
+        );
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    std::fs::remove_file(&path).unwrap();
+}